@@ -19,26 +19,63 @@ mod circuits {
     }
 
     #[instruction]
-    pub fn init_account_state(owner: Shared) -> Enc<Shared, u128> {
-        // Initializes an encrypted zero balance for a CVCT account.
-        // The `owner` input is the account owner's encryption context.
-        owner.from_arcis(0u128)
+    pub fn init_account_state(
+        owner: Shared,
+        owner_locked: Shared,
+    ) -> (Enc<Shared, u128>, Enc<Shared, u128>) {
+        // Initializes encrypted zero balance and locked balance for a CVCT account.
+        // Both inputs are the account owner's encryption context, each with its own nonce.
+        (owner.from_arcis(0u128), owner_locked.from_arcis(0u128))
+    }
+
+    #[instruction]
+    pub fn init_account_state_batch(
+        owner_0: Shared,
+        owner_locked_0: Shared,
+        owner_1: Shared,
+        owner_locked_1: Shared,
+        owner_2: Shared,
+        owner_locked_2: Shared,
+        owner_3: Shared,
+        owner_locked_3: Shared,
+    ) -> (
+        Enc<Shared, u128>,
+        Enc<Shared, u128>,
+        Enc<Shared, u128>,
+        Enc<Shared, u128>,
+        Enc<Shared, u128>,
+        Enc<Shared, u128>,
+        Enc<Shared, u128>,
+        Enc<Shared, u128>,
+    ) {
+        // Same as init_account_state for four accounts in one computation.
+        (
+            owner_0.from_arcis(0u128),
+            owner_locked_0.from_arcis(0u128),
+            owner_1.from_arcis(0u128),
+            owner_locked_1.from_arcis(0u128),
+            owner_2.from_arcis(0u128),
+            owner_locked_2.from_arcis(0u128),
+            owner_3.from_arcis(0u128),
+            owner_locked_3.from_arcis(0u128),
+        )
     }
 
     #[instruction]
     pub fn deposit_and_mint(
         balance: Enc<Shared, u128>,
-        amount: u128,
+        minted: u128,
+        backing: u128,
         owner_out: Shared,
         total_supply: Enc<Shared, u128>,
         mint_out: Shared,
         total_locked: Enc<Shared, u128>,
         vault_out: Shared,
     ) -> (Enc<Shared, u128>, Enc<Shared, u128>, Enc<Shared, u128>) {
-        // Add plaintext deposit amount to encrypted balance, supply, and locked totals.
-        let new_balance = balance.to_arcis() + amount;
-        let new_total_supply = total_supply.to_arcis() + amount;
-        let new_total_locked = total_locked.to_arcis() + amount;
+        // Credit minted CVCT to balance and supply; lock the backing amount in the vault.
+        let new_balance = balance.to_arcis() + minted;
+        let new_total_supply = total_supply.to_arcis() + minted;
+        let new_total_locked = total_locked.to_arcis() + backing;
 
         (
             owner_out.from_arcis(new_balance),
@@ -50,50 +87,733 @@ mod circuits {
     #[instruction]
     pub fn burn_and_withdraw(
         balance: Enc<Shared, u128>,
+        locked: Enc<Shared, u128>,
         amount: u128,
+        backing: u128,
+        rate_num: u128,
+        rate_den: u128,
         owner_out: Shared,
         total_supply: Enc<Shared, u128>,
         mint_out: Shared,
         total_locked: Enc<Shared, u128>,
         vault_out: Shared,
-    ) -> (Enc<Shared, u128>, Enc<Shared, u128>, Enc<Shared, u128>, bool, u128) {
+    ) -> (Enc<Shared, u128>, Enc<Shared, u128>, Enc<Shared, u128>, bool, u128, bool, bool) {
         let bal = balance.to_arcis();
-        let ok = bal >= amount;
+        // Only the unlocked portion of the balance is spendable.
+        let ok = bal >= amount + locked.to_arcis();
 
         // Both branches execute in MPC, so compute and select.
         let new_balance = if ok { bal - amount } else { bal };
         let supply = total_supply.to_arcis();
         let new_supply = if ok { supply - amount } else { supply };
         let locked = total_locked.to_arcis();
-        let new_locked = if ok { locked - amount } else { locked };
+        let new_locked = if ok { locked - backing } else { locked };
+        // Post-condition on the select above; always true unless that logic regresses.
+        let no_underflow = !ok || (bal >= amount && supply >= amount && locked >= backing);
+        // The release, valued at the mint rate (CVCT = backing * num / den), must not
+        // exceed the CVCT destroyed.
+        let covered = backing * rate_num <= amount * rate_den;
 
         (
             owner_out.from_arcis(new_balance),
             mint_out.from_arcis(new_supply),
             vault_out.from_arcis(new_locked),
             ok.reveal(),
-            amount,
+            backing,
+            no_underflow.reveal(),
+            covered.reveal(),
         )
     }
 
     #[instruction]
     pub fn transfer_cvct(
         from_balance: Enc<Shared, u128>,
+        from_locked: Enc<Shared, u128>,
         amount: u128,
+        allow_partial: bool,
         from_out: Shared,
         to_balance: Enc<Shared, u128>,
         to_out: Shared,
-    ) -> (Enc<Shared, u128>, Enc<Shared, u128>, bool) {
+    ) -> (Enc<Shared, u128>, Enc<Shared, u128>, bool, bool) {
         let from = from_balance.to_arcis();
         let to = to_balance.to_arcis();
-        let ok = from >= amount;
+        let locked = from_locked.to_arcis();
+        let ok = from >= amount + locked;
+
+        // Partial mode moves whatever is spendable instead of nothing.
+        let spendable = if from >= locked { from - locked } else { 0 };
+        let moved = if ok {
+            amount
+        } else if allow_partial {
+            spendable
+        } else {
+            0
+        };
+
+        // Post-condition on the select above; always true unless that logic regresses.
+        let no_underflow = from >= moved;
+        let new_from = from - moved;
+        let new_to = to + moved;
+
+        (
+            from_out.from_arcis(new_from),
+            to_out.from_arcis(new_to),
+            ok.reveal(),
+            no_underflow.reveal(),
+        )
+    }
 
+    #[instruction]
+    pub fn check_collateral(total_locked: Enc<Shared, u128>, vault_balance: u128) -> bool {
+        // Compare encrypted locked total against the public vault balance; only the result is revealed.
+        let locked = total_locked.to_arcis();
+        (locked <= vault_balance).reveal()
+    }
+
+    #[instruction]
+    pub fn withdraw_all(
+        balance: Enc<Shared, u128>,
+        locked: Enc<Shared, u128>,
+        owner_out: Shared,
+        total_supply: Enc<Shared, u128>,
+        mint_out: Shared,
+        total_locked: Enc<Shared, u128>,
+        vault_out: Shared,
+    ) -> (Enc<Shared, u128>, Enc<Shared, u128>, Enc<Shared, u128>, u128) {
+        // Burn everything but the locked portion; reveal the burned amount to size the SPL transfer.
+        let kept = locked.to_arcis();
+        let burned = balance.to_arcis() - kept;
+        let new_supply = total_supply.to_arcis() - burned;
+        let new_locked = total_locked.to_arcis() - burned;
+
+        (
+            owner_out.from_arcis(kept),
+            mint_out.from_arcis(new_supply),
+            vault_out.from_arcis(new_locked),
+            burned.reveal(),
+        )
+    }
+
+    #[instruction]
+    pub fn transfer_with_fee(
+        from_balance: Enc<Shared, u128>,
+        from_locked: Enc<Shared, u128>,
+        amount: u128,
+        fee: u128,
+        from_out: Shared,
+        to_balance: Enc<Shared, u128>,
+        to_out: Shared,
+        collector_balance: Enc<Shared, u128>,
+        collector_out: Shared,
+    ) -> (Enc<Shared, u128>, Enc<Shared, u128>, Enc<Shared, u128>, bool) {
+        let from = from_balance.to_arcis();
+        let to = to_balance.to_arcis();
+        let collector = collector_balance.to_arcis();
+        let ok = from >= amount + from_locked.to_arcis();
+
+        // Sender pays `amount`; recipient gets the net and the collector the fee, so no value is created.
         let new_from = if ok { from - amount } else { from };
-        let new_to = if ok { to + amount } else { to };
+        let new_to = if ok { to + (amount - fee) } else { to };
+        let new_collector = if ok { collector + fee } else { collector };
 
         (
             from_out.from_arcis(new_from),
             to_out.from_arcis(new_to),
+            collector_out.from_arcis(new_collector),
+            ok.reveal(),
+        )
+    }
+
+    #[instruction]
+    pub fn credit_from_vault(
+        amount: Enc<Shared, u128>,
+        balance: Enc<Shared, u128>,
+        owner_out: Shared,
+        total_supply: Enc<Shared, u128>,
+        mint_out: Shared,
+        total_locked: Enc<Shared, u128>,
+        unallocated: Enc<Shared, u128>,
+        unallocated_fresh: bool,
+        pool_out: Shared,
+    ) -> (Enc<Shared, u128>, Enc<Shared, u128>, Enc<Shared, u128>, bool) {
+        let amt = amount.to_arcis();
+        let bal = balance.to_arcis();
+        let supply = total_supply.to_arcis();
+        let locked = total_locked.to_arcis();
+        // Vaults locked before the pool was tracked start from what supply leaves over.
+        let pool = if unallocated_fresh {
+            if locked >= supply {
+                locked - supply
+            } else {
+                0
+            }
+        } else {
+            unallocated.to_arcis()
+        };
+
+        // Only issue from the pool, so supply stays fully backed by what the vault locks.
+        let ok = amt <= pool;
+        let new_balance = if ok { bal + amt } else { bal };
+        let new_supply = if ok { supply + amt } else { supply };
+        let new_pool = if ok { pool - amt } else { pool };
+
+        (
+            owner_out.from_arcis(new_balance),
+            mint_out.from_arcis(new_supply),
+            pool_out.from_arcis(new_pool),
+            ok.reveal(),
+        )
+    }
+
+    #[instruction]
+    pub fn lock_balance(
+        balance: Enc<Shared, u128>,
+        locked: Enc<Shared, u128>,
+        amount: u128,
+        unlock_at: u128,
+        locked_out: Shared,
+    ) -> (Enc<Shared, u128>, bool, u128) {
+        // Grow the locked portion only while it stays covered by the balance.
+        let current = locked.to_arcis();
+        let ok = current + amount <= balance.to_arcis();
+        let new_locked = if ok { current + amount } else { current };
+
+        // The release time is echoed so the callback only applies it to a lock that took.
+        (locked_out.from_arcis(new_locked), ok.reveal(), unlock_at)
+    }
+
+    #[instruction]
+    pub fn unlock_balance(owner: Shared) -> Enc<Shared, u128> {
+        // Releasing the lock resets the locked portion to an encrypted zero.
+        owner.from_arcis(0u128)
+    }
+
+    #[instruction]
+    pub fn bootstrap_mint_state(
+        authority: Shared,
+        vault: Shared,
+        owner: Shared,
+        owner_locked: Shared,
+    ) -> (Enc<Shared, u128>, Enc<Shared, u128>, Enc<Shared, u128>, Enc<Shared, u128>) {
+        // init_mint_state + init_account_state for the authority in a single computation.
+        (
+            authority.from_arcis(0u128),
+            vault.from_arcis(0u128),
+            owner.from_arcis(0u128),
+            owner_locked.from_arcis(0u128),
+        )
+    }
+
+    #[instruction]
+    pub fn snapshot_balance(balance: Enc<Shared, u128>, reporter: Shared) -> Enc<Shared, u128> {
+        // Re-encrypt the balance under the reporting authority's key.
+        reporter.from_arcis(balance.to_arcis())
+    }
+
+    #[instruction]
+    pub fn run_payroll(
+        treasury_balance: Enc<Shared, u128>,
+        treasury_locked: Enc<Shared, u128>,
+        min_reserve: Enc<Shared, u128>,
+        reserve_set: bool,
+        amount: u128,
+        low_threshold: u128,
+        auto_pause: bool,
+        epsilon: u128,
+        treasury_out: Shared,
+        member_balance: Enc<Shared, u128>,
+        member_out: Shared,
+        total_disbursed: Enc<Shared, u128>,
+        disbursed_fresh: bool,
+        org_out: Shared,
+        deductions: Enc<Shared, u128>,
+        deductions_fresh: bool,
+        deductions_out: Shared,
+    ) -> (Enc<Shared, u128>, Enc<Shared, u128>, Enc<Shared, u128>, Enc<Shared, u128>, bool, bool, bool) {
+        // Treasury -> member transfer; nothing moves if the treasury can't cover it.
+        let treasury = treasury_balance.to_arcis();
+        let member = member_balance.to_arcis();
+        let reserve = if reserve_set {
+            min_reserve.to_arcis()
+        } else {
+            0
+        };
+        // Withhold outstanding deductions, flooring the payment at zero; the rest carries over.
+        let outstanding = if deductions_fresh {
+            0
+        } else {
+            deductions.to_arcis()
+        };
+        let withheld = if outstanding < amount {
+            outstanding
+        } else {
+            amount
+        };
+        let amount = amount - withheld;
+        let locked = treasury_locked.to_arcis();
+        // Payments stop before the reserve would be breached.
+        let ok = treasury >= amount + locked + reserve;
+
+        let new_treasury = if ok { treasury - amount } else { treasury };
+        let new_member = if ok { member + amount } else { member };
+        let new_deductions = if ok {
+            outstanding - withheld
+        } else {
+            outstanding
+        };
+        // Only the below-threshold flag is revealed, never the balance.
+        let low = new_treasury < low_threshold;
+        // The treasury can't cover this member, or what stays spendable is under
+        // `epsilon`; only computed for opted-in payrolls.
+        let exhausted = auto_pause && (!ok || new_treasury < locked + reserve + epsilon);
+
+        // The org accumulator has no ciphertext before its first payment; treat it as zero.
+        let disbursed = if disbursed_fresh {
+            0
+        } else {
+            total_disbursed.to_arcis()
+        };
+        let new_disbursed = if ok { disbursed + amount } else { disbursed };
+
+        (
+            treasury_out.from_arcis(new_treasury),
+            member_out.from_arcis(new_member),
+            org_out.from_arcis(new_disbursed),
+            deductions_out.from_arcis(new_deductions),
+            ok.reveal(),
+            low.reveal(),
+            exhausted.reveal(),
+        )
+    }
+
+    #[instruction]
+    pub fn is_zero(balance: Enc<Shared, u128>) -> bool {
+        (balance.to_arcis() == 0).reveal()
+    }
+
+    #[instruction]
+    pub fn can_pay_member(
+        treasury_balance: Enc<Shared, u128>,
+        treasury_locked: Enc<Shared, u128>,
+        min_reserve: Enc<Shared, u128>,
+        reserve_set: bool,
+        amount: u128,
+    ) -> bool {
+        // Same sufficiency test as run_payroll, without moving anything.
+        let reserve = if reserve_set {
+            min_reserve.to_arcis()
+        } else {
+            0
+        };
+        (treasury_balance.to_arcis() >= amount + treasury_locked.to_arcis() + reserve).reveal()
+    }
+
+    #[instruction]
+    pub fn is_equal(a: Enc<Shared, u128>, b: Enc<Shared, u128>) -> bool {
+        (a.to_arcis() == b.to_arcis()).reveal()
+    }
+
+    #[instruction]
+    pub fn backed_supply(
+        total_supply: Enc<Shared, u128>,
+        unallocated: Enc<Shared, u128>,
+        unallocated_set: bool,
+        out: Shared,
+    ) -> Enc<Shared, u128> {
+        // Locked collateral is either issued as supply or waiting in the unallocated pool.
+        let pool = if unallocated_set {
+            unallocated.to_arcis()
+        } else {
+            0
+        };
+        out.from_arcis(total_supply.to_arcis() + pool)
+    }
+
+    #[instruction]
+    pub fn circuit_breaker(total_locked: Enc<Shared, u128>, vault_balance: u128) -> bool {
+        // Same comparison as check_collateral; the callback pauses instead of failing.
+        (total_locked.to_arcis() <= vault_balance).reveal()
+    }
+
+    #[instruction]
+    pub fn accrue_payroll(
+        treasury_balance: Enc<Shared, u128>,
+        treasury_locked: Enc<Shared, u128>,
+        min_reserve: Enc<Shared, u128>,
+        reserve_set: bool,
+        amount: u128,
+        low_threshold: u128,
+        treasury_out: Shared,
+        accrued: Enc<Shared, u128>,
+        accrued_fresh: bool,
+        member_out: Shared,
+        total_disbursed: Enc<Shared, u128>,
+        disbursed_fresh: bool,
+        org_out: Shared,
+        deductions: Enc<Shared, u128>,
+        deductions_fresh: bool,
+        deductions_out: Shared,
+    ) -> (Enc<Shared, u128>, Enc<Shared, u128>, Enc<Shared, u128>, Enc<Shared, u128>, bool, bool) {
+        // Same debit as run_payroll, but credited to the member's unclaimed accrual.
+        let treasury = treasury_balance.to_arcis();
+        let reserve = if reserve_set {
+            min_reserve.to_arcis()
+        } else {
+            0
+        };
+        // Withhold outstanding deductions as run_payroll does.
+        let outstanding = if deductions_fresh {
+            0
+        } else {
+            deductions.to_arcis()
+        };
+        let withheld = if outstanding < amount {
+            outstanding
+        } else {
+            amount
+        };
+        let amount = amount - withheld;
+        // Payments stop before the reserve would be breached.
+        let ok = treasury >= amount + treasury_locked.to_arcis() + reserve;
+        let new_treasury = if ok { treasury - amount } else { treasury };
+        let new_deductions = if ok {
+            outstanding - withheld
+        } else {
+            outstanding
+        };
+        let low = new_treasury < low_threshold;
+
+        let prev_accrued = if accrued_fresh { 0 } else { accrued.to_arcis() };
+        let new_accrued = if ok {
+            prev_accrued + amount
+        } else {
+            prev_accrued
+        };
+        let disbursed = if disbursed_fresh {
+            0
+        } else {
+            total_disbursed.to_arcis()
+        };
+        let new_disbursed = if ok { disbursed + amount } else { disbursed };
+
+        (
+            treasury_out.from_arcis(new_treasury),
+            member_out.from_arcis(new_accrued),
+            org_out.from_arcis(new_disbursed),
+            deductions_out.from_arcis(new_deductions),
+            ok.reveal(),
+            low.reveal(),
+        )
+    }
+
+    #[instruction]
+    pub fn claim_accrued(
+        accrued: Enc<Shared, u128>,
+        accrued_out: Shared,
+        member_balance: Enc<Shared, u128>,
+        member_out: Shared,
+    ) -> (Enc<Shared, u128>, Enc<Shared, u128>) {
+        // Move the whole accrual into the wallet and zero it.
+        let new_balance = member_balance.to_arcis() + accrued.to_arcis();
+        (
+            accrued_out.from_arcis(0u128),
+            member_out.from_arcis(new_balance),
+        )
+    }
+
+    #[instruction]
+    pub fn onboard_member(
+        member_out: Shared,
+        member_locked_out: Shared,
+        treasury_balance: Enc<Shared, u128>,
+        treasury_locked: Enc<Shared, u128>,
+        min_reserve: Enc<Shared, u128>,
+        reserve_set: bool,
+        bonus: u128,
+        treasury_out: Shared,
+    ) -> (Enc<Shared, u128>, Enc<Shared, u128>, Enc<Shared, u128>, bool) {
+        // New account starts at the bonus when the treasury can cover it, otherwise zero.
+        let treasury = treasury_balance.to_arcis();
+        let reserve = if reserve_set {
+            min_reserve.to_arcis()
+        } else {
+            0
+        };
+        let ok = treasury >= bonus + treasury_locked.to_arcis() + reserve;
+        let new_treasury = if ok { treasury - bonus } else { treasury };
+        let balance = if ok { bonus } else { 0 };
+
+        (
+            member_out.from_arcis(balance),
+            member_locked_out.from_arcis(0u128),
+            treasury_out.from_arcis(new_treasury),
+            ok.reveal(),
+        )
+    }
+
+    #[instruction]
+    pub fn run_hourly_payroll(
+        treasury_balance: Enc<Shared, u128>,
+        treasury_locked: Enc<Shared, u128>,
+        min_reserve: Enc<Shared, u128>,
+        reserve_set: bool,
+        rate: u128,
+        hours: Enc<Shared, u128>,
+        low_threshold: u128,
+        treasury_out: Shared,
+        member_balance: Enc<Shared, u128>,
+        member_out: Shared,
+        total_disbursed: Enc<Shared, u128>,
+        disbursed_fresh: bool,
+        org_out: Shared,
+        deductions: Enc<Shared, u128>,
+        deductions_fresh: bool,
+        deductions_out: Shared,
+    ) -> (Enc<Shared, u128>, Enc<Shared, u128>, Enc<Shared, u128>, Enc<Shared, u128>, bool, bool) {
+        // run_payroll with the amount priced from encrypted hours; neither is revealed.
+        let gross = rate * hours.to_arcis();
+        let treasury = treasury_balance.to_arcis();
+        let member = member_balance.to_arcis();
+        let reserve = if reserve_set {
+            min_reserve.to_arcis()
+        } else {
+            0
+        };
+        // Withhold outstanding deductions as run_payroll does.
+        let outstanding = if deductions_fresh {
+            0
+        } else {
+            deductions.to_arcis()
+        };
+        let withheld = if outstanding < gross {
+            outstanding
+        } else {
+            gross
+        };
+        let amount = gross - withheld;
+        let ok = treasury >= amount + treasury_locked.to_arcis() + reserve;
+
+        let new_treasury = if ok { treasury - amount } else { treasury };
+        let new_member = if ok { member + amount } else { member };
+        let new_deductions = if ok {
+            outstanding - withheld
+        } else {
+            outstanding
+        };
+        let low = new_treasury < low_threshold;
+
+        let disbursed = if disbursed_fresh {
+            0
+        } else {
+            total_disbursed.to_arcis()
+        };
+        let new_disbursed = if ok { disbursed + amount } else { disbursed };
+
+        (
+            treasury_out.from_arcis(new_treasury),
+            member_out.from_arcis(new_member),
+            org_out.from_arcis(new_disbursed),
+            deductions_out.from_arcis(new_deductions),
+            ok.reveal(),
+            low.reveal(),
+        )
+    }
+
+    #[instruction]
+    pub fn lock_collateral(
+        total_locked: Enc<Shared, u128>,
+        amount: u128,
+        vault_out: Shared,
+        total_supply: Enc<Shared, u128>,
+        unallocated: Enc<Shared, u128>,
+        unallocated_fresh: bool,
+        pool_out: Shared,
+    ) -> (Enc<Shared, u128>, Enc<Shared, u128>) {
+        // Collateral only; supply grows later through credit_from_vault, out of the pool.
+        let locked = total_locked.to_arcis();
+        let supply = total_supply.to_arcis();
+        // Same start as credit_from_vault for vaults locked before the pool was tracked.
+        let pool = if unallocated_fresh {
+            if locked >= supply {
+                locked - supply
+            } else {
+                0
+            }
+        } else {
+            unallocated.to_arcis()
+        };
+        (
+            vault_out.from_arcis(locked + amount),
+            pool_out.from_arcis(pool + amount),
+        )
+    }
+
+    #[instruction]
+    pub fn transfer_with_receipt(
+        from_balance: Enc<Shared, u128>,
+        from_locked: Enc<Shared, u128>,
+        amount: u128,
+        allow_partial: bool,
+        from_out: Shared,
+        to_balance: Enc<Shared, u128>,
+        to_out: Shared,
+    ) -> (Enc<Shared, u128>, Enc<Shared, u128>, bool) {
+        let from = from_balance.to_arcis();
+        let to = to_balance.to_arcis();
+        let locked = from_locked.to_arcis();
+        let ok = from >= amount + locked;
+
+        let spendable = if from >= locked { from - locked } else { 0 };
+        let moved = if ok {
+            amount
+        } else if allow_partial {
+            spendable
+        } else {
+            0
+        };
+
+        // Reveals only whether anything moved, not how much.
+        (
+            from_out.from_arcis(from - moved),
+            to_out.from_arcis(to + moved),
+            (moved > 0).reveal(),
+        )
+    }
+
+    #[instruction]
+    pub fn reveal_to(balance: Enc<Shared, u128>, viewer: Shared) -> Enc<Shared, u128> {
+        // One-off copy for a viewer key; the stored balance keeps its own encryption.
+        viewer.from_arcis(balance.to_arcis())
+    }
+
+    #[instruction]
+    pub fn apply_deduction(
+        amount: Enc<Shared, u128>,
+        deductions: Enc<Shared, u128>,
+        deductions_fresh: bool,
+        deductions_out: Shared,
+    ) -> Enc<Shared, u128> {
+        // The member's accumulator has no ciphertext before its first deduction.
+        let outstanding = if deductions_fresh {
+            0
+        } else {
+            deductions.to_arcis()
+        };
+        deductions_out.from_arcis(outstanding + amount.to_arcis())
+    }
+
+    #[instruction]
+    pub fn simulate_payroll_run(
+        treasury_balance: Enc<Shared, u128>,
+        treasury_locked: Enc<Shared, u128>,
+        min_reserve: Enc<Shared, u128>,
+        reserve_set: bool,
+        amount: u128,
+        periods: u128,
+        deductions: Enc<Shared, u128>,
+        deductions_fresh: bool,
+    ) -> (bool, u128, u128) {
+        // run_payroll's withholding and sufficiency test, without moving anything.
+        let reserve = if reserve_set {
+            min_reserve.to_arcis()
+        } else {
+            0
+        };
+        let outstanding = if deductions_fresh {
+            0
+        } else {
+            deductions.to_arcis()
+        };
+        let withheld = if outstanding < amount {
+            outstanding
+        } else {
+            amount
+        };
+        let payable = amount - withheld;
+        let ok = treasury_balance.to_arcis() >= payable + treasury_locked.to_arcis() + reserve;
+        // The plaintext inputs are echoed so the event reports exactly what was checked.
+        (ok.reveal(), periods, amount)
+    }
+
+    #[instruction]
+    pub fn clawback(
+        member_balance: Enc<Shared, u128>,
+        member_locked: Enc<Shared, u128>,
+        amount: u128,
+        member_out: Shared,
+        treasury_balance: Enc<Shared, u128>,
+        treasury_out: Shared,
+    ) -> (Enc<Shared, u128>, Enc<Shared, u128>, bool, u128) {
+        let member = member_balance.to_arcis();
+        let treasury = treasury_balance.to_arcis();
+        // All or nothing, and never out of the locked portion.
+        let ok = member >= amount + member_locked.to_arcis();
+        let moved = if ok { amount } else { 0 };
+
+        // The amount is echoed so a refused clawback can be made clawable again.
+        (
+            member_out.from_arcis(member - moved),
+            treasury_out.from_arcis(treasury + moved),
+            ok.reveal(),
+            amount,
+        )
+    }
+
+    #[instruction]
+    pub fn accrue_bonus(
+        amount: Enc<Shared, u128>,
+        bonus: Enc<Shared, u128>,
+        bonus_fresh: bool,
+        bonus_out: Shared,
+    ) -> Enc<Shared, u128> {
+        // The member's bonus has no ciphertext before the first one lands.
+        let outstanding = if bonus_fresh { 0 } else { bonus.to_arcis() };
+        bonus_out.from_arcis(outstanding + amount.to_arcis())
+    }
+
+    #[instruction]
+    pub fn claim_bonus(
+        treasury_balance: Enc<Shared, u128>,
+        treasury_locked: Enc<Shared, u128>,
+        min_reserve: Enc<Shared, u128>,
+        reserve_set: bool,
+        treasury_out: Shared,
+        bonus: Enc<Shared, u128>,
+        bonus_out: Shared,
+        member_balance: Enc<Shared, u128>,
+        member_out: Shared,
+        total_disbursed: Enc<Shared, u128>,
+        disbursed_fresh: bool,
+        org_out: Shared,
+    ) -> (
+        Enc<Shared, u128>,
+        Enc<Shared, u128>,
+        Enc<Shared, u128>,
+        Enc<Shared, u128>,
+        bool,
+    ) {
+        // All or nothing, and never below the treasury's locked funds and reserve.
+        let treasury = treasury_balance.to_arcis();
+        let reserve = if reserve_set {
+            min_reserve.to_arcis()
+        } else {
+            0
+        };
+        let owed = bonus.to_arcis();
+        let ok = treasury >= owed + treasury_locked.to_arcis() + reserve;
+        let paid = if ok { owed } else { 0 };
+
+        let disbursed = if disbursed_fresh {
+            0
+        } else {
+            total_disbursed.to_arcis()
+        };
+
+        (
+            treasury_out.from_arcis(treasury - paid),
+            bonus_out.from_arcis(owed - paid),
+            member_out.from_arcis(member_balance.to_arcis() + paid),
+            org_out.from_arcis(disbursed + paid),
             ok.reveal(),
         )
     }
@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{transfer, Mint, Token, TokenAccount, Transfer},
+    associated_token::{get_associated_token_address, AssociatedToken},
+    token::{
+        close_account, set_authority, spl_token, transfer, CloseAccount, Mint, SetAuthority, Token,
+        TokenAccount, Transfer,
+    },
 };
 use arcium_anchor::prelude::*;
 use arcium_client::idl::arcium::types::CallbackAccount;
@@ -11,7 +15,72 @@ const COMP_DEF_OFFSET_INIT_ACCOUNT_STATE: u32 = comp_def_offset("init_account_st
 const COMP_DEF_OFFSET_DEPOSIT_AND_MINT: u32 = comp_def_offset("deposit_and_mint");
 const COMP_DEF_OFFSET_BURN_AND_WITHDRAW: u32 = comp_def_offset("burn_and_withdraw");
 const COMP_DEF_OFFSET_TRANSFER_CVCT: u32 = comp_def_offset("transfer_cvct");
+const COMP_DEF_OFFSET_CHECK_COLLATERAL: u32 = comp_def_offset("check_collateral");
+const COMP_DEF_OFFSET_WITHDRAW_ALL: u32 = comp_def_offset("withdraw_all");
+const COMP_DEF_OFFSET_TRANSFER_WITH_FEE: u32 = comp_def_offset("transfer_with_fee");
+const COMP_DEF_OFFSET_CREDIT_FROM_VAULT: u32 = comp_def_offset("credit_from_vault");
+const COMP_DEF_OFFSET_LOCK_BALANCE: u32 = comp_def_offset("lock_balance");
+const COMP_DEF_OFFSET_UNLOCK_BALANCE: u32 = comp_def_offset("unlock_balance");
+const COMP_DEF_OFFSET_BOOTSTRAP_MINT_STATE: u32 = comp_def_offset("bootstrap_mint_state");
+const COMP_DEF_OFFSET_SNAPSHOT_BALANCE: u32 = comp_def_offset("snapshot_balance");
+const COMP_DEF_OFFSET_RUN_PAYROLL: u32 = comp_def_offset("run_payroll");
+const COMP_DEF_OFFSET_IS_ZERO: u32 = comp_def_offset("is_zero");
+const COMP_DEF_OFFSET_INIT_ACCOUNT_STATE_BATCH: u32 = comp_def_offset("init_account_state_batch");
+const COMP_DEF_OFFSET_CAN_PAY_MEMBER: u32 = comp_def_offset("can_pay_member");
+const COMP_DEF_OFFSET_IS_EQUAL: u32 = comp_def_offset("is_equal");
+const COMP_DEF_OFFSET_BACKED_SUPPLY: u32 = comp_def_offset("backed_supply");
+const COMP_DEF_OFFSET_CIRCUIT_BREAKER: u32 = comp_def_offset("circuit_breaker");
+/// Layout version stamped on every program account at init.
+const ACCOUNT_VERSION: u8 = 2;
+const COMP_DEF_OFFSET_ACCRUE_PAYROLL: u32 = comp_def_offset("accrue_payroll");
+const COMP_DEF_OFFSET_CLAIM_ACCRUED: u32 = comp_def_offset("claim_accrued");
+const COMP_DEF_OFFSET_ONBOARD_MEMBER: u32 = comp_def_offset("onboard_member");
+const COMP_DEF_OFFSET_RUN_HOURLY_PAYROLL: u32 = comp_def_offset("run_hourly_payroll");
+const COMP_DEF_OFFSET_LOCK_COLLATERAL: u32 = comp_def_offset("lock_collateral");
+const COMP_DEF_OFFSET_TRANSFER_WITH_RECEIPT: u32 = comp_def_offset("transfer_with_receipt");
+const COMP_DEF_OFFSET_REVEAL_TO: u32 = comp_def_offset("reveal_to");
+const COMP_DEF_OFFSET_APPLY_DEDUCTION: u32 = comp_def_offset("apply_deduction");
+const COMP_DEF_OFFSET_SIMULATE_PAYROLL_RUN: u32 = comp_def_offset("simulate_payroll_run");
+const COMP_DEF_OFFSET_CLAWBACK: u32 = comp_def_offset("clawback");
+const COMP_DEF_OFFSET_ACCRUE_BONUS: u32 = comp_def_offset("accrue_bonus");
+const COMP_DEF_OFFSET_CLAIM_BONUS: u32 = comp_def_offset("claim_bonus");
 const ENCRYPTED_U128_CIPHERTEXTS: usize = 1;
+/// Bytes taken by one encrypted u128 field in account data.
+const ENCRYPTED_U128_LEN: u32 = (32 * ENCRYPTED_U128_CIPHERTEXTS) as u32;
+/// Maximum number of retained balance snapshots per account.
+const MAX_OPEN_SNAPSHOTS: u16 = 24;
+/// Accounts initialized per `batch_init_accounts` computation.
+const BATCH_INIT_SIZE: usize = 4;
+/// Members updated per `update_payroll_members_batch` call; keeps the walk within compute.
+const MEMBER_UPDATE_BATCH_SIZE: usize = 16;
+/// Members created per `import_members` call; each one is an account creation.
+const MEMBER_IMPORT_BATCH_SIZE: usize = 8;
+/// Member cap for a payroll created without an explicit one.
+const DEFAULT_MAX_MEMBERS: u32 = 256;
+const SECONDS_PER_DAY: u128 = 24 * 60 * 60;
+/// How long a deposit idempotency key stays reserved.
+const DEPOSIT_IDEMPOTENCY_WINDOW: i64 = 24 * 60 * 60;
+/// Minimum delay between proposing and executing an emergency withdrawal.
+const EMERGENCY_WITHDRAW_DELAY: i64 = 7 * 24 * 60 * 60;
+/// Most approvers an org can require for `close_payroll`.
+const MAX_CLOSE_APPROVERS: usize = 5;
+/// Most extra keys an org can authorize to spend from its treasuries.
+const MAX_AUTHORIZED_SPENDERS: usize = 8;
+/// Most CVCT mints the platform registry can approve for orgs.
+const MAX_REGISTERED_MINTS: usize = 64;
+/// Longest per-member pay interval `set_member_interval` accepts.
+const MAX_MEMBER_INTERVAL: i64 = 366 * 24 * 60 * 60;
+/// Longest time `lock_balance` may hold funds from now.
+const MAX_LOCK_DURATION: i64 = 4 * 366 * 24 * 60 * 60;
+/// Spendable treasury balance, in base units, below which an auto-pause payroll counts
+/// as exhausted.
+const AUTO_PAUSE_EPSILON: u128 = 1;
+/// Default `CvctMint::computation_timeout`: how long a computation may stay pending
+/// before it counts as stale.
+const STALE_COMPUTATION_TIMEOUT: i64 = 24 * 60 * 60;
+/// How many of a counter's most recent offsets are searched when naming an aborted
+/// computation.
+const ABORTED_OFFSET_LOOKBACK: u64 = 16;
 
 declare_id!("B4rLKdnQsFH2e4CBefgWsBXZ7xsX4ewb7QUiMim4Nbvj");
 
@@ -51,13 +120,199 @@ pub mod cvct {
         Ok(())
     }
 
+    pub fn init_check_collateral_comp_def(ctx: Context<InitCheckCollateralCompDef>) -> Result<()> {
+        // Registers the confidential circuit interface for collateralization checks.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_withdraw_all_comp_def(ctx: Context<InitWithdrawAllCompDef>) -> Result<()> {
+        // Registers the confidential circuit interface for full-balance withdrawals.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_transfer_with_fee_comp_def(ctx: Context<InitTransferWithFeeCompDef>) -> Result<()> {
+        // Registers the confidential circuit interface for fee-bearing transfers.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_credit_from_vault_comp_def(ctx: Context<InitCreditFromVaultCompDef>) -> Result<()> {
+        // Registers the confidential circuit interface for crediting against locked collateral.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_lock_balance_comp_def(ctx: Context<InitLockBalanceCompDef>) -> Result<()> {
+        // Registers the confidential circuit interface for locking part of a balance.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_unlock_balance_comp_def(ctx: Context<InitUnlockBalanceCompDef>) -> Result<()> {
+        // Registers the confidential circuit interface for releasing a locked balance.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_bootstrap_mint_state_comp_def(
+        ctx: Context<InitBootstrapMintStateCompDef>,
+    ) -> Result<()> {
+        // Registers the confidential circuit interface for one-shot mint + account setup.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_snapshot_balance_comp_def(ctx: Context<InitSnapshotBalanceCompDef>) -> Result<()> {
+        // Registers the confidential circuit interface for balance snapshots.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_run_payroll_comp_def(ctx: Context<InitRunPayrollCompDef>) -> Result<()> {
+        // Registers the confidential circuit interface for payroll runs.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_is_zero_comp_def(ctx: Context<InitIsZeroCompDef>) -> Result<()> {
+        // Registers the confidential circuit interface for zero-balance checks.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_account_state_batch_comp_def(
+        ctx: Context<InitAccountStateBatchCompDef>,
+    ) -> Result<()> {
+        // Registers the confidential circuit interface for batched account init.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_can_pay_member_comp_def(ctx: Context<InitCanPayMemberCompDef>) -> Result<()> {
+        // Registers the confidential circuit interface for payroll pre-checks.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_is_equal_comp_def(ctx: Context<InitIsEqualCompDef>) -> Result<()> {
+        // Registers the confidential circuit interface for encrypted equality.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_backed_supply_comp_def(ctx: Context<InitBackedSupplyCompDef>) -> Result<()> {
+        // Registers the confidential circuit interface for summing supply and the pool.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_circuit_breaker_comp_def(ctx: Context<InitCircuitBreakerCompDef>) -> Result<()> {
+        // Registers the confidential circuit interface for the collateral circuit breaker.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_accrue_payroll_comp_def(ctx: Context<InitAccruePayrollCompDef>) -> Result<()> {
+        // Register the accrue_payroll circuit for accrue_for_member.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_claim_accrued_comp_def(ctx: Context<InitClaimAccruedCompDef>) -> Result<()> {
+        // Register the claim_accrued circuit for claim_pay.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_onboard_member_comp_def(ctx: Context<InitOnboardMemberCompDef>) -> Result<()> {
+        // Register the onboard_member circuit for onboard_member.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_run_hourly_payroll_comp_def(
+        ctx: Context<InitRunHourlyPayrollCompDef>,
+    ) -> Result<()> {
+        // Register the run_hourly_payroll circuit for run_hourly_payroll_for_member.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_lock_collateral_comp_def(ctx: Context<InitLockCollateralCompDef>) -> Result<()> {
+        // Register the lock_collateral circuit for lock_collateral.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_transfer_with_receipt_comp_def(
+        ctx: Context<InitTransferWithReceiptCompDef>,
+    ) -> Result<()> {
+        // Register the transfer_with_receipt circuit for transfer_with_receipt.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_reveal_to_comp_def(ctx: Context<InitRevealToCompDef>) -> Result<()> {
+        // Register the reveal_to circuit for reveal_to.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_apply_deduction_comp_def(ctx: Context<InitApplyDeductionCompDef>) -> Result<()> {
+        // Register the apply_deduction circuit for apply_deduction.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_simulate_payroll_run_comp_def(
+        ctx: Context<InitSimulatePayrollRunCompDef>,
+    ) -> Result<()> {
+        // Registers the confidential circuit interface for payroll dry runs.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_clawback_comp_def(ctx: Context<InitClawbackCompDef>) -> Result<()> {
+        // Register the clawback circuit for clawback.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_accrue_bonus_comp_def(ctx: Context<InitAccrueBonusCompDef>) -> Result<()> {
+        // Register the accrue_bonus circuit for accrue_bonus.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    pub fn init_claim_bonus_comp_def(ctx: Context<InitClaimBonusCompDef>) -> Result<()> {
+        // Register the claim_bonus circuit for claim_bonus.
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
     pub fn initialize_cvct_mint(
         ctx: Context<InitializeCvctMint>,
         computation_offset: u64,
         authority_enc_pubkey: [u8; 32],
         authority_nonce: u128,
         vault_nonce: u128,
+        exchange_rate_num: u64,
+        exchange_rate_den: u64,
     ) -> Result<()> {
+        require!(
+            exchange_rate_num > 0 && exchange_rate_den > 0,
+            ErrorCode::InvalidExchangeRate
+        );
+        // One canonical CVCT mint per backing asset.
+        require!(
+            ctx.accounts.backing_record.cvct_mint == Pubkey::default(),
+            ErrorCode::MintAlreadyExistsForBacking
+        );
+        ctx.accounts.backing_record.cvct_mint = ctx.accounts.cvct_mint.key();
+        ctx.accounts.backing_record.version = ACCOUNT_VERSION;
+
         // Cache keys needed after we mutably borrow accounts.
         let cvct_mint_key = ctx.accounts.cvct_mint.key();
         let vault_key = ctx.accounts.vault.key();
@@ -71,21 +326,40 @@ pub mod cvct {
 
             // Initialize public metadata immediately; encrypted fields are placeholders until callback.
             cvct_mint.set_inner(CvctMint {
+                version: ACCOUNT_VERSION,
                 authority: authority_key,
                 backing_mint: backing_mint_key,
                 authority_enc_pubkey,
                 total_supply: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
                 total_supply_nonce: 0,
                 decimals,
+                transfer_fee_bps: 0,
+                fee_collector: Pubkey::default(),
+                mode: MintMode::Normal,
+                exchange_rate_num,
+                exchange_rate_den,
+                reveal_threshold: 0,
+                max_transfer: None,
+                op_counter: 0,
+                open_pending_ops: 0,
+                computation_timeout: STALE_COMPUTATION_TIMEOUT,
             });
 
             // Vault holds backing SPL tokens; encrypted total_locked updated in callback.
             vault.set_inner(Vault {
+                version: ACCOUNT_VERSION,
                 cvct_mint: cvct_mint_key,
                 backing_mint: backing_mint_key,
                 backing_token_account: vault_token_account_key,
                 total_locked: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
                 total_locked_nonce: 0,
+                unallocated: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+                unallocated_nonce: 0,
+                backed_supply: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+                backed_supply_nonce: 0,
+                backed_supply_sources: [0; 2],
+                backed_supply_in_flight: false,
+                backed_supply_queued_at: 0,
             });
         }
 
@@ -99,6 +373,11 @@ pub mod cvct {
 
         // Required by Arcium signer PDA; macro expects bump set on the account.
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.cvct_mint.key(),
+            &mut ctx.accounts.cvct_mint.op_counter,
+            computation_offset,
+        )?;
 
         // Queue confidential computation and register callback to write encrypted outputs.
         queue_computation(
@@ -124,6 +403,12 @@ pub mod cvct {
             0,
         )?;
 
+        emit!(ComputationQueued {
+            computation_offset,
+            kind: ComputationKind::InitMintState,
+            accounts: vec![ctx.accounts.cvct_mint.key(), ctx.accounts.vault.key()],
+        });
+
         Ok(())
     }
 
@@ -144,7 +429,18 @@ pub mod cvct {
                         field_1: total_locked,
                     },
             }) => (total_supply, total_locked),
-            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_mint.key(),
+                        ctx.accounts.cvct_mint.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::InitMintState,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
         };
 
         let cvct_mint = &mut ctx.accounts.cvct_mint;
@@ -164,6 +460,7 @@ pub mod cvct {
         computation_offset: u64,
         owner_enc_pubkey: [u8; 32],
         owner_nonce: u128,
+        owner_locked_nonce: u128,
     ) -> Result<()> {
         let cvct_account_key = ctx.accounts.cvct_account.key();
         let cvct_mint_key = ctx.accounts.cvct_mint.key();
@@ -172,21 +469,36 @@ pub mod cvct {
         {
             let cvct_account = &mut ctx.accounts.cvct_account;
             cvct_account.set_inner(CvctAccount {
+                version: ACCOUNT_VERSION,
                 owner: owner_key,
                 cvct_mint: cvct_mint_key,
                 owner_enc_pubkey,
                 balance: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
                 balance_nonce: 0,
+                locked_balance: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+                locked_balance_nonce: 0,
+                unlock_at: 0,
+                open_snapshots: 0,
+                op_counter: 0,
+                update_in_flight: true,
+                update_queued_at: Clock::get()?.unix_timestamp,
             });
         }
 
-        // Build Arcium args to create an encrypted zero balance.
+        // Build Arcium args to create encrypted zero balance and locked balance.
         let args = ArgBuilder::new()
             .x25519_pubkey(owner_enc_pubkey)
             .plaintext_u128(owner_nonce)
+            .x25519_pubkey(owner_enc_pubkey)
+            .plaintext_u128(owner_locked_nonce)
             .build();
 
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.cvct_account.key(),
+            &mut ctx.accounts.cvct_account.op_counter,
+            computation_offset,
+        )?;
 
         queue_computation(
             ctx.accounts,
@@ -205,6 +517,12 @@ pub mod cvct {
             0,
         )?;
 
+        emit!(ComputationQueued {
+            computation_offset,
+            kind: ComputationKind::InitAccountState,
+            accounts: vec![cvct_account_key],
+        });
+
         Ok(())
     }
 
@@ -213,17 +531,37 @@ pub mod cvct {
         ctx: Context<InitAccountStateCallback>,
         output: SignedComputationOutputs<InitAccountStateOutput>,
     ) -> Result<()> {
-        let balance = match output.verify_output(
+        let (balance, locked_balance) = match output.verify_output(
             &ctx.accounts.cluster_account,
             &ctx.accounts.computation_account,
         ) {
-            Ok(InitAccountStateOutput { field_0 }) => field_0,
-            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
+            Ok(InitAccountStateOutput {
+                field_0:
+                    InitAccountStateOutputStruct0 {
+                        field_0: balance,
+                        field_1: locked_balance,
+                    },
+            }) => (balance, locked_balance),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_account.key(),
+                        ctx.accounts.cvct_account.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::InitAccountState,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
         };
 
         let cvct_account = &mut ctx.accounts.cvct_account;
         cvct_account.balance = balance.ciphertexts;
         cvct_account.balance_nonce = balance.nonce;
+        cvct_account.locked_balance = locked_balance.ciphertexts;
+        cvct_account.locked_balance_nonce = locked_balance.nonce;
+        cvct_account.update_in_flight = false;
 
         Ok(())
     }
@@ -231,6 +569,7 @@ pub mod cvct {
         ctx: Context<DepositAndMint>,
         computation_offset: u64,
         amount: u64,
+        idempotency_key: [u8; 32],
         owner_enc_pubkey: [u8; 32],
         owner_balance_nonce: u128,
         owner_new_balance_nonce: u128,
@@ -242,6 +581,37 @@ pub mod cvct {
         vault_new_total_locked_nonce: u128,
     ) -> Result<()> {
         require!(amount > 0, ErrorCode::ZeroAmount);
+        // Output nonces must strictly increase so a key is never reused with the same nonce.
+        require!(
+            owner_new_balance_nonce > ctx.accounts.cvct_account.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            mint_new_total_supply_nonce > ctx.accounts.cvct_mint.total_supply_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            vault_new_total_locked_nonce > ctx.accounts.vault.total_locked_nonce,
+            ErrorCode::NonceReused
+        );
+
+        // Optional retry guard: the same key can't be replayed within the window.
+        if let Some(receipt) = ctx.accounts.deposit_receipt.as_mut() {
+            let now = Clock::get()?.unix_timestamp;
+            require!(
+                receipt.created_at == 0
+                    || now.saturating_sub(receipt.created_at) >= DEPOSIT_IDEMPOTENCY_WINDOW,
+                ErrorCode::DuplicateDeposit
+            );
+            receipt.version = ACCOUNT_VERSION;
+            receipt.user = ctx.accounts.user.key();
+            receipt.idempotency_key = idempotency_key;
+            receipt.created_at = now;
+        }
+
+        // Balance and supply are in CVCT units; total_locked stays in backing units.
+        let minted = ctx.accounts.cvct_mint.to_cvct(amount)?;
+        require!(minted > 0, ErrorCode::ZeroAmount);
 
         // 1) Transfer backing tokens into the vault.
         transfer(
@@ -263,10 +633,11 @@ pub mod cvct {
             .plaintext_u128(owner_balance_nonce)
             .account(
                 ctx.accounts.cvct_account.key(),
-                8 + 32 + 32 + 32,
-                (32 * ENCRYPTED_U128_CIPHERTEXTS) as u32,
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
             )
-            // Plaintext amount.
+            // Plaintext CVCT minted and backing locked.
+            .plaintext_u128(minted)
             .plaintext_u128(amount as u128)
             // Output encryption context for balance.
             .x25519_pubkey(owner_enc_pubkey)
@@ -276,8 +647,8 @@ pub mod cvct {
             .plaintext_u128(mint_total_supply_nonce)
             .account(
                 ctx.accounts.cvct_mint.key(),
-                8 + 32 + 32 + 32,
-                (32 * ENCRYPTED_U128_CIPHERTEXTS) as u32,
+                CvctMint::TOTAL_SUPPLY_OFFSET,
+                ENCRYPTED_U128_LEN,
             )
             // Output encryption context for total supply.
             .x25519_pubkey(mint_enc_pubkey)
@@ -287,8 +658,8 @@ pub mod cvct {
             .plaintext_u128(vault_total_locked_nonce)
             .account(
                 ctx.accounts.vault.key(),
-                8 + 32 + 32 + 32,
-                (32 * ENCRYPTED_U128_CIPHERTEXTS) as u32,
+                Vault::TOTAL_LOCKED_OFFSET,
+                ENCRYPTED_U128_LEN,
             )
             // Output encryption context for total locked.
             .x25519_pubkey(vault_enc_pubkey)
@@ -296,6 +667,28 @@ pub mod cvct {
             .build();
 
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        ctx.accounts.cvct_account.begin_update(now, stale_timeout)?;
+        consume_computation_offset(
+            &ctx.accounts.cvct_account.key(),
+            &mut ctx.accounts.cvct_account.op_counter,
+            computation_offset,
+        )?;
+
+        // Status surface for clients; closed by the callback once it lands.
+        ctx.accounts.pending_op.set_inner(PendingOp {
+            version: ACCOUNT_VERSION,
+            computation_offset,
+            kind: ComputationKind::DepositAndMint,
+            initiator: ctx.accounts.user.key(),
+            rent_payer: ctx.accounts.user.key(),
+            created_at: Clock::get()?.unix_timestamp,
+            cvct_mint: ctx.accounts.cvct_mint.key(),
+            refund_amount: amount,
+            failed: false,
+        });
+        ctx.accounts.cvct_mint.open_pending_ops += 1;
 
         queue_computation(
             ctx.accounts,
@@ -318,12 +711,30 @@ pub mod cvct {
                         pubkey: ctx.accounts.vault.key(),
                         is_writable: true,
                     },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.pending_op.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.user.key(),
+                        is_writable: true,
+                    },
                 ],
             )?],
             1,
             0,
         )?;
 
+        emit!(ComputationQueued {
+            computation_offset,
+            kind: ComputationKind::DepositAndMint,
+            accounts: vec![
+                ctx.accounts.cvct_account.key(),
+                ctx.accounts.cvct_mint.key(),
+                ctx.accounts.vault.key(),
+            ],
+        });
+
         Ok(())
     }
 
@@ -344,7 +755,18 @@ pub mod cvct {
                         field_2: total_locked,
                     },
             }) => (balance, total_supply, total_locked),
-            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_account.key(),
+                        ctx.accounts.cvct_account.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::DepositAndMint,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
         };
 
         let cvct_account = &mut ctx.accounts.cvct_account;
@@ -353,9 +775,11 @@ pub mod cvct {
 
         cvct_account.balance = balance.ciphertexts;
         cvct_account.balance_nonce = balance.nonce;
+        cvct_account.update_in_flight = false;
 
         cvct_mint.total_supply = total_supply.ciphertexts;
         cvct_mint.total_supply_nonce = total_supply.nonce;
+        cvct_mint.pending_op_closed();
 
         vault.total_locked = total_locked.ciphertexts;
         vault.total_locked_nonce = total_locked.nonce;
@@ -367,8 +791,10 @@ pub mod cvct {
         ctx: Context<BurnAndWithdraw>,
         computation_offset: u64,
         amount: u64,
+        unwrap: bool,
         owner_enc_pubkey: [u8; 32],
         owner_balance_nonce: u128,
+        owner_locked_nonce: u128,
         owner_new_balance_nonce: u128,
         mint_enc_pubkey: [u8; 32],
         mint_total_supply_nonce: u128,
@@ -378,6 +804,45 @@ pub mod cvct {
         vault_new_total_locked_nonce: u128,
     ) -> Result<()> {
         require!(amount > 0, ErrorCode::ZeroAmount);
+        let backing = ctx.accounts.cvct_mint.to_backing(amount)?;
+        require!(backing > 0, ErrorCode::ZeroAmount);
+        // Fail before burning rather than in the callback's SPL transfer.
+        spl_amount(backing)?;
+        require!(
+            owner_new_balance_nonce > ctx.accounts.cvct_account.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            mint_new_total_supply_nonce > ctx.accounts.cvct_mint.total_supply_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            vault_new_total_locked_nonce > ctx.accounts.vault.total_locked_nonce,
+            ErrorCode::NonceReused
+        );
+
+        if unwrap {
+            require!(
+                ctx.accounts.cvct_mint.backing_mint == spl_token::native_mint::ID,
+                ErrorCode::InvalidUnwrap
+            );
+            require!(
+                ctx.accounts.recipient_token_account.is_none(),
+                ErrorCode::InvalidUnwrap
+            );
+            // Hand close authority to the vault so the callback can unwrap to the user.
+            set_authority(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    SetAuthority {
+                        current_authority: ctx.accounts.user.to_account_info(),
+                        account_or_mint: ctx.accounts.user_token_account.to_account_info(),
+                    },
+                ),
+                spl_token::instruction::AuthorityType::CloseAccount,
+                Some(ctx.accounts.vault.key()),
+            )?;
+        }
 
         let args = ArgBuilder::new()
             // Balance input from account data.
@@ -385,11 +850,22 @@ pub mod cvct {
             .plaintext_u128(owner_balance_nonce)
             .account(
                 ctx.accounts.cvct_account.key(),
-                8 + 32 + 32 + 32,
-                (32 * ENCRYPTED_U128_CIPHERTEXTS) as u32,
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Locked portion of the balance.
+            .x25519_pubkey(owner_enc_pubkey)
+            .plaintext_u128(owner_locked_nonce)
+            .account(
+                ctx.accounts.cvct_account.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
             )
-            // Plaintext burn amount.
+            // Plaintext CVCT burned, backing released and the mint rate between them.
             .plaintext_u128(amount as u128)
+            .plaintext_u128(backing)
+            .plaintext_u128(ctx.accounts.cvct_mint.exchange_rate_num as u128)
+            .plaintext_u128(ctx.accounts.cvct_mint.exchange_rate_den as u128)
             // Output encryption context for balance.
             .x25519_pubkey(owner_enc_pubkey)
             .plaintext_u128(owner_new_balance_nonce)
@@ -398,8 +874,8 @@ pub mod cvct {
             .plaintext_u128(mint_total_supply_nonce)
             .account(
                 ctx.accounts.cvct_mint.key(),
-                8 + 32 + 32 + 32,
-                (32 * ENCRYPTED_U128_CIPHERTEXTS) as u32,
+                CvctMint::TOTAL_SUPPLY_OFFSET,
+                ENCRYPTED_U128_LEN,
             )
             // Output encryption context for total supply.
             .x25519_pubkey(mint_enc_pubkey)
@@ -409,8 +885,8 @@ pub mod cvct {
             .plaintext_u128(vault_total_locked_nonce)
             .account(
                 ctx.accounts.vault.key(),
-                8 + 32 + 32 + 32,
-                (32 * ENCRYPTED_U128_CIPHERTEXTS) as u32,
+                Vault::TOTAL_LOCKED_OFFSET,
+                ENCRYPTED_U128_LEN,
             )
             // Output encryption context for total locked.
             .x25519_pubkey(vault_enc_pubkey)
@@ -418,6 +894,36 @@ pub mod cvct {
             .build();
 
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        ctx.accounts.cvct_account.begin_update(now, stale_timeout)?;
+        consume_computation_offset(
+            &ctx.accounts.cvct_account.key(),
+            &mut ctx.accounts.cvct_account.op_counter,
+            computation_offset,
+        )?;
+
+        // Status surface for clients; closed by the callback once it lands.
+        ctx.accounts.pending_op.set_inner(PendingOp {
+            version: ACCOUNT_VERSION,
+            computation_offset,
+            kind: ComputationKind::BurnAndWithdraw,
+            initiator: ctx.accounts.user.key(),
+            rent_payer: ctx.accounts.fee_payer.key(),
+            created_at: Clock::get()?.unix_timestamp,
+            cvct_mint: ctx.accounts.cvct_mint.key(),
+            refund_amount: 0,
+            failed: false,
+        });
+        ctx.accounts.cvct_mint.open_pending_ops += 1;
+
+        // Backing tokens go to the explicit recipient if given, otherwise back to the user.
+        let recipient_token_account = ctx
+            .accounts
+            .recipient_token_account
+            .as_ref()
+            .map(|account| account.key())
+            .unwrap_or_else(|| ctx.accounts.user_token_account.key());
 
         queue_computation(
             ctx.accounts,
@@ -445,19 +951,49 @@ pub mod cvct {
                         is_writable: true,
                     },
                     CallbackAccount {
-                        pubkey: ctx.accounts.user_token_account.key(),
+                        pubkey: recipient_token_account,
                         is_writable: true,
                     },
                     CallbackAccount {
                         pubkey: ctx.accounts.token_program.key(),
                         is_writable: false,
                     },
+                    // Optional unwrap destination; the program ID stands in for `None`.
+                    if unwrap {
+                        CallbackAccount {
+                            pubkey: ctx.accounts.user.key(),
+                            is_writable: true,
+                        }
+                    } else {
+                        CallbackAccount {
+                            pubkey: crate::ID,
+                            is_writable: false,
+                        }
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.pending_op.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.fee_payer.key(),
+                        is_writable: true,
+                    },
                 ],
             )?],
             1,
             0,
         )?;
 
+        emit!(ComputationQueued {
+            computation_offset,
+            kind: ComputationKind::BurnAndWithdraw,
+            accounts: vec![
+                ctx.accounts.cvct_account.key(),
+                ctx.accounts.cvct_mint.key(),
+                ctx.accounts.vault.key(),
+            ],
+        });
+
         Ok(())
     }
 
@@ -466,10 +1002,11 @@ pub mod cvct {
         ctx: Context<BurnAndWithdrawCallback>,
         output: SignedComputationOutputs<BurnAndWithdrawOutput>,
     ) -> Result<()> {
-        let (balance, total_supply, total_locked, ok, amount) = match output.verify_output(
-            &ctx.accounts.cluster_account,
-            &ctx.accounts.computation_account,
-        ) {
+        let (balance, total_supply, total_locked, ok, amount, no_underflow, covered) = match output
+            .verify_output(
+                &ctx.accounts.cluster_account,
+                &ctx.accounts.computation_account,
+            ) {
             Ok(BurnAndWithdrawOutput {
                 field_0:
                     BurnAndWithdrawOutputStruct0 {
@@ -478,10 +1015,35 @@ pub mod cvct {
                         field_2: total_locked,
                         field_3: ok,
                         field_4: amount,
+                        field_5: no_underflow,
+                        field_6: covered,
                     },
-            }) => (balance, total_supply, total_locked, ok, amount),
-            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
+            }) => (
+                balance,
+                total_supply,
+                total_locked,
+                ok,
+                amount,
+                no_underflow,
+                covered,
+            ),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_account.key(),
+                        ctx.accounts.cvct_account.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::BurnAndWithdraw,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
         };
+        // Keep the old ciphertexts rather than store a wrapped-around balance.
+        require!(no_underflow, ErrorCode::BalanceUnderflow);
+        // Never release more collateral than the burned CVCT is worth.
+        require!(covered, ErrorCode::CollateralLeak);
 
         let cvct_account = &mut ctx.accounts.cvct_account;
         let cvct_mint = &mut ctx.accounts.cvct_mint;
@@ -489,35 +1051,74 @@ pub mod cvct {
 
         cvct_account.balance = balance.ciphertexts;
         cvct_account.balance_nonce = balance.nonce;
+        cvct_account.update_in_flight = false;
 
         cvct_mint.total_supply = total_supply.ciphertexts;
         cvct_mint.total_supply_nonce = total_supply.nonce;
+        cvct_mint.pending_op_closed();
 
         vault.total_locked = total_locked.ciphertexts;
         vault.total_locked_nonce = total_locked.nonce;
 
+        let cvct_mint_key = cvct_mint.key();
+        let vault_seeds = &[
+            b"vault".as_ref(),
+            cvct_mint_key.as_ref(),
+            &[ctx.bumps.vault],
+        ];
+        let signer_seeds = &[&vault_seeds[..]];
+
         if ok {
-            let amount_u64: u64 = amount.try_into().map_err(|_| ErrorCode::InvalidAmount)?;
-            let cvct_mint_key = cvct_mint.key();
-            let vault_seeds = &[
-                b"vault".as_ref(),
-                cvct_mint_key.as_ref(),
-                &[ctx.bumps.vault],
-            ];
-            let signer_seeds = &[&vault_seeds[..]];
+            let amount_u64 = spl_amount(amount)?;
 
             transfer(
                 CpiContext::new_with_signer(
                     ctx.accounts.token_program.to_account_info(),
                     Transfer {
                         from: ctx.accounts.vault_token_account.to_account_info(),
-                        to: ctx.accounts.user_token_account.to_account_info(),
+                        to: ctx.accounts.recipient_token_account.to_account_info(),
                         authority: vault.to_account_info(),
                     },
                     signer_seeds,
                 ),
                 amount_u64,
             )?;
+
+            emit!(Withdrawn {
+                cvct_account: cvct_account.key(),
+                recipient_token_account: ctx.accounts.recipient_token_account.key(),
+                amount: amount_u64,
+            });
+            cvct_mint.flag_large_withdrawal(cvct_account.key(), cvct_account.owner, amount_u64);
+        }
+
+        // Unwrap requested: close the wSOL account so its lamports land in the user's wallet,
+        // or hand its close authority back to the owner when nothing was withdrawn.
+        if let Some(unwrap_destination) = &ctx.accounts.unwrap_destination {
+            if ok {
+                close_account(CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    CloseAccount {
+                        account: ctx.accounts.recipient_token_account.to_account_info(),
+                        destination: unwrap_destination.to_account_info(),
+                        authority: vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ))?;
+            } else {
+                set_authority(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        SetAuthority {
+                            current_authority: vault.to_account_info(),
+                            account_or_mint: ctx.accounts.recipient_token_account.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    spl_token::instruction::AuthorityType::CloseAccount,
+                    None,
+                )?;
+            }
         }
 
         Ok(())
@@ -527,14 +1128,30 @@ pub mod cvct {
         ctx: Context<TransferCvct>,
         computation_offset: u64,
         amount: u64,
+        allow_partial: bool,
         from_enc_pubkey: [u8; 32],
         from_balance_nonce: u128,
+        from_locked_nonce: u128,
         from_new_balance_nonce: u128,
         to_enc_pubkey: [u8; 32],
         to_balance_nonce: u128,
         to_new_balance_nonce: u128,
     ) -> Result<()> {
         require!(amount > 0, ErrorCode::ZeroAmount);
+        ctx.accounts.cvct_mint.check_transfer_limit(amount)?;
+        // Fee-bearing mints must go through transfer_cvct_with_fee.
+        require!(
+            ctx.accounts.cvct_mint.transfer_fee_bps == 0,
+            ErrorCode::TransferFeeRequired
+        );
+        require!(
+            from_new_balance_nonce > ctx.accounts.from_cvct_account.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            to_new_balance_nonce > ctx.accounts.to_cvct_account.balance_nonce,
+            ErrorCode::NonceReused
+        );
 
         let args = ArgBuilder::new()
             // Sender balance.
@@ -542,11 +1159,21 @@ pub mod cvct {
             .plaintext_u128(from_balance_nonce)
             .account(
                 ctx.accounts.from_cvct_account.key(),
-                8 + 32 + 32 + 32,
-                (32 * ENCRYPTED_U128_CIPHERTEXTS) as u32,
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Locked portion of the balance.
+            .x25519_pubkey(from_enc_pubkey)
+            .plaintext_u128(from_locked_nonce)
+            .account(
+                ctx.accounts.from_cvct_account.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
             )
             // Plaintext transfer amount.
             .plaintext_u128(amount as u128)
+            // Move the full spendable balance when `amount` is not covered.
+            .plaintext_bool(allow_partial)
             // Output context for sender.
             .x25519_pubkey(from_enc_pubkey)
             .plaintext_u128(from_new_balance_nonce)
@@ -555,8 +1182,8 @@ pub mod cvct {
             .plaintext_u128(to_balance_nonce)
             .account(
                 ctx.accounts.to_cvct_account.key(),
-                8 + 32 + 32 + 32,
-                (32 * ENCRYPTED_U128_CIPHERTEXTS) as u32,
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
             )
             // Output context for recipient.
             .x25519_pubkey(to_enc_pubkey)
@@ -564,6 +1191,33 @@ pub mod cvct {
             .build();
 
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        ctx.accounts
+            .from_cvct_account
+            .begin_update(now, stale_timeout)?;
+        ctx.accounts
+            .to_cvct_account
+            .begin_update(now, stale_timeout)?;
+        consume_computation_offset(
+            &ctx.accounts.from_cvct_account.key(),
+            &mut ctx.accounts.from_cvct_account.op_counter,
+            computation_offset,
+        )?;
+
+        // Status surface for clients; closed by the callback once it lands.
+        ctx.accounts.pending_op.set_inner(PendingOp {
+            version: ACCOUNT_VERSION,
+            computation_offset,
+            kind: ComputationKind::TransferCvct,
+            initiator: ctx.accounts.user.key(),
+            rent_payer: ctx.accounts.fee_payer.key(),
+            created_at: Clock::get()?.unix_timestamp,
+            cvct_mint: ctx.accounts.cvct_mint.key(),
+            refund_amount: 0,
+            failed: false,
+        });
+        ctx.accounts.cvct_mint.open_pending_ops += 1;
 
         queue_computation(
             ctx.accounts,
@@ -582,12 +1236,33 @@ pub mod cvct {
                         pubkey: ctx.accounts.to_cvct_account.key(),
                         is_writable: true,
                     },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.pending_op.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.fee_payer.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.cvct_mint.key(),
+                        is_writable: true,
+                    },
                 ],
             )?],
             1,
             0,
         )?;
 
+        emit!(ComputationQueued {
+            computation_offset,
+            kind: ComputationKind::TransferCvct,
+            accounts: vec![
+                ctx.accounts.from_cvct_account.key(),
+                ctx.accounts.to_cvct_account.key(),
+            ],
+        });
+
         Ok(())
     }
 
@@ -596,7 +1271,7 @@ pub mod cvct {
         ctx: Context<TransferCvctCallback>,
         output: SignedComputationOutputs<TransferCvctOutput>,
     ) -> Result<()> {
-        let (from_balance, to_balance, _ok) = match output.verify_output(
+        let (from_balance, to_balance, _ok, no_underflow) = match output.verify_output(
             &ctx.accounts.cluster_account,
             &ctx.accounts.computation_account,
         ) {
@@ -606,619 +1281,13346 @@ pub mod cvct {
                         field_0: from_balance,
                         field_1: to_balance,
                         field_2: ok,
+                        field_3: no_underflow,
                     },
-            }) => (from_balance, to_balance, ok),
-            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
+            }) => (from_balance, to_balance, ok, no_underflow),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.from_cvct_account.key(),
+                        ctx.accounts.from_cvct_account.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::TransferCvct,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
         };
+        // Keep the old ciphertexts rather than store a wrapped-around balance.
+        require!(no_underflow, ErrorCode::BalanceUnderflow);
 
         let from_cvct_account = &mut ctx.accounts.from_cvct_account;
         let to_cvct_account = &mut ctx.accounts.to_cvct_account;
 
         from_cvct_account.balance = from_balance.ciphertexts;
         from_cvct_account.balance_nonce = from_balance.nonce;
+        from_cvct_account.update_in_flight = false;
 
         to_cvct_account.balance = to_balance.ciphertexts;
         to_cvct_account.balance_nonce = to_balance.nonce;
+        to_cvct_account.update_in_flight = false;
+
+        ctx.accounts.cvct_mint.pending_op_closed();
 
         Ok(())
     }
-}
-
-#[account]
-pub struct CvctMint {
-    pub authority: Pubkey,
-    pub backing_mint: Pubkey,
-    /// X25519 pubkey used to encrypt/decrypt mint totals off-chain.
-    pub authority_enc_pubkey: [u8; 32],
-    /// Encrypted total supply (1 ciphertext for u128).
-    pub total_supply: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
-    /// Nonce used with the encrypted total supply.
-    pub total_supply_nonce: u128,
-    pub decimals: u8,
-}
 
-impl CvctMint {
-    pub const LEN: usize = 32 + 32 + 32 + (32 * ENCRYPTED_U128_CIPHERTEXTS) + 16 + 1;
-}
+    pub fn assert_collateralized(
+        ctx: Context<AssertCollateralized>,
+        computation_offset: u64,
+        vault_enc_pubkey: [u8; 32],
+        vault_total_locked_nonce: u128,
+    ) -> Result<()> {
+        // Live SPL balance is public; only the comparison result leaves MPC.
+        let vault_balance = ctx.accounts.vault_token_account.amount;
 
-#[account]
-pub struct Vault {
-    pub cvct_mint: Pubkey,
-    pub backing_mint: Pubkey,
-    /// SPL token account holding backing assets.
-    pub backing_token_account: Pubkey,
-    /// Encrypted total locked in the vault (1 ciphertext for u128).
-    pub total_locked: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
-    /// Nonce used with the encrypted total locked.
-    pub total_locked_nonce: u128,
-}
+        let args = ArgBuilder::new()
+            // Total locked input from vault.
+            .x25519_pubkey(vault_enc_pubkey)
+            .plaintext_u128(vault_total_locked_nonce)
+            .account(
+                ctx.accounts.vault.key(),
+                Vault::TOTAL_LOCKED_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Plaintext backing balance held by the vault ATA.
+            .plaintext_u128(vault_balance as u128)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.cvct_mint.key(),
+            &mut ctx.accounts.cvct_mint.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![CheckCollateralCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[CallbackAccount {
+                    pubkey: ctx.accounts.cvct_mint.key(),
+                    is_writable: false,
+                }],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "check_collateral")]
+    pub fn check_collateral_callback(
+        ctx: Context<CheckCollateralCallback>,
+        output: SignedComputationOutputs<CheckCollateralOutput>,
+    ) -> Result<()> {
+        let collateralized = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(CheckCollateralOutput { field_0 }) => field_0,
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_mint.key(),
+                        ctx.accounts.cvct_mint.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::CheckCollateral,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        // Encrypted accounting claims more than the vault actually holds.
+        require!(collateralized, ErrorCode::InvariantViolation);
+
+        Ok(())
+    }
+
+    pub fn withdraw_all(
+        ctx: Context<WithdrawAll>,
+        computation_offset: u64,
+        owner_enc_pubkey: [u8; 32],
+        owner_balance_nonce: u128,
+        owner_locked_nonce: u128,
+        owner_new_balance_nonce: u128,
+        mint_enc_pubkey: [u8; 32],
+        mint_total_supply_nonce: u128,
+        mint_new_total_supply_nonce: u128,
+        vault_enc_pubkey: [u8; 32],
+        vault_total_locked_nonce: u128,
+        vault_new_total_locked_nonce: u128,
+    ) -> Result<()> {
+        // The burned amount is only known inside MPC, so only 1:1 mints can size the payout.
+        require!(
+            ctx.accounts.cvct_mint.exchange_rate_num == ctx.accounts.cvct_mint.exchange_rate_den,
+            ErrorCode::InvalidExchangeRate
+        );
+        // Same inputs as burn_and_withdraw, minus the amount: MPC burns whatever the balance holds.
+        require!(
+            owner_new_balance_nonce > ctx.accounts.cvct_account.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            mint_new_total_supply_nonce > ctx.accounts.cvct_mint.total_supply_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            vault_new_total_locked_nonce > ctx.accounts.vault.total_locked_nonce,
+            ErrorCode::NonceReused
+        );
+
+        let args = ArgBuilder::new()
+            // Balance input from account data.
+            .x25519_pubkey(owner_enc_pubkey)
+            .plaintext_u128(owner_balance_nonce)
+            .account(
+                ctx.accounts.cvct_account.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Locked portion of the balance.
+            .x25519_pubkey(owner_enc_pubkey)
+            .plaintext_u128(owner_locked_nonce)
+            .account(
+                ctx.accounts.cvct_account.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Output encryption context for balance.
+            .x25519_pubkey(owner_enc_pubkey)
+            .plaintext_u128(owner_new_balance_nonce)
+            // Total supply input from mint.
+            .x25519_pubkey(mint_enc_pubkey)
+            .plaintext_u128(mint_total_supply_nonce)
+            .account(
+                ctx.accounts.cvct_mint.key(),
+                CvctMint::TOTAL_SUPPLY_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Output encryption context for total supply.
+            .x25519_pubkey(mint_enc_pubkey)
+            .plaintext_u128(mint_new_total_supply_nonce)
+            // Total locked input from vault.
+            .x25519_pubkey(vault_enc_pubkey)
+            .plaintext_u128(vault_total_locked_nonce)
+            .account(
+                ctx.accounts.vault.key(),
+                Vault::TOTAL_LOCKED_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Output encryption context for total locked.
+            .x25519_pubkey(vault_enc_pubkey)
+            .plaintext_u128(vault_new_total_locked_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        ctx.accounts.cvct_account.begin_update(now, stale_timeout)?;
+        consume_computation_offset(
+            &ctx.accounts.cvct_account.key(),
+            &mut ctx.accounts.cvct_account.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![WithdrawAllCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.cvct_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.cvct_mint.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.vault.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.vault_token_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.user_token_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.token_program.key(),
+                        is_writable: false,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "withdraw_all")]
+    pub fn withdraw_all_callback(
+        ctx: Context<WithdrawAllCallback>,
+        output: SignedComputationOutputs<WithdrawAllOutput>,
+    ) -> Result<()> {
+        let (balance, total_supply, total_locked, amount) = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(WithdrawAllOutput {
+                field_0:
+                    WithdrawAllOutputStruct0 {
+                        field_0: balance,
+                        field_1: total_supply,
+                        field_2: total_locked,
+                        field_3: amount,
+                    },
+            }) => (balance, total_supply, total_locked, amount),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_account.key(),
+                        ctx.accounts.cvct_account.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::WithdrawAll,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let cvct_account = &mut ctx.accounts.cvct_account;
+        let cvct_mint = &mut ctx.accounts.cvct_mint;
+        let vault = &mut ctx.accounts.vault;
+
+        cvct_account.balance = balance.ciphertexts;
+        cvct_account.balance_nonce = balance.nonce;
+        cvct_account.update_in_flight = false;
+
+        cvct_mint.total_supply = total_supply.ciphertexts;
+        cvct_mint.total_supply_nonce = total_supply.nonce;
+
+        vault.total_locked = total_locked.ciphertexts;
+        vault.total_locked_nonce = total_locked.nonce;
+
+        // Revealed amount is the full burned balance; an empty account has nothing to release.
+        if amount > 0 {
+            let amount_u64 = spl_amount(amount)?;
+            let cvct_mint_key = cvct_mint.key();
+            let vault_seeds = &[
+                b"vault".as_ref(),
+                cvct_mint_key.as_ref(),
+                &[ctx.bumps.vault],
+            ];
+            let signer_seeds = &[&vault_seeds[..]];
+
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault_token_account.to_account_info(),
+                        to: ctx.accounts.user_token_account.to_account_info(),
+                        authority: vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount_u64,
+            )?;
+            cvct_mint.flag_large_withdrawal(cvct_account.key(), cvct_account.owner, amount_u64);
+        }
+
+        Ok(())
+    }
+
+    pub fn propose_emergency(ctx: Context<ProposeEmergency>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::ZeroAmount);
+
+        // Break-glass path: record intent now, funds can only move after the delay.
+        let emergency = &mut ctx.accounts.emergency;
+        emergency.set_inner(EmergencyWithdrawal {
+            version: ACCOUNT_VERSION,
+            cvct_mint: ctx.accounts.cvct_mint.key(),
+            recovery_token_account: ctx.accounts.recovery_token_account.key(),
+            amount,
+            proposed_at: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn execute_emergency(ctx: Context<ExecuteEmergency>) -> Result<()> {
+        let emergency = &ctx.accounts.emergency;
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now >= emergency.proposed_at + EMERGENCY_WITHDRAW_DELAY,
+            ErrorCode::EmergencyTimelockActive
+        );
+
+        // Moves backing tokens independently of encrypted balances; accounting is not updated.
+        let cvct_mint_key = ctx.accounts.cvct_mint.key();
+        let vault_seeds = &[
+            b"vault".as_ref(),
+            cvct_mint_key.as_ref(),
+            &[ctx.bumps.vault],
+        ];
+        let signer_seeds = &[&vault_seeds[..]];
+
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault_token_account.to_account_info(),
+                    to: ctx.accounts.recovery_token_account.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            emergency.amount,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn cancel_emergency(_ctx: Context<CancelEmergency>) -> Result<()> {
+        // Closing the proposal PDA is the whole cancellation.
+        Ok(())
+    }
+
+    pub fn set_transfer_fee(ctx: Context<SetTransferFee>, transfer_fee_bps: u16) -> Result<()> {
+        require!(transfer_fee_bps <= 10_000, ErrorCode::InvalidFeeBps);
+
+        let cvct_mint = &mut ctx.accounts.cvct_mint;
+        cvct_mint.transfer_fee_bps = transfer_fee_bps;
+        cvct_mint.fee_collector = ctx.accounts.fee_collector.key();
+
+        Ok(())
+    }
+
+    pub fn repair_vault_token_account(ctx: Context<RepairVaultTokenAccount>) -> Result<()> {
+        // Re-point the vault at its recreated ATA; the constraints pin it to the derived address.
+        ctx.accounts.vault.backing_token_account = ctx.accounts.vault_token_account.key();
+        Ok(())
+    }
+
+    pub fn transfer_cvct_with_fee(
+        ctx: Context<TransferCvctWithFee>,
+        computation_offset: u64,
+        amount: u64,
+        from_enc_pubkey: [u8; 32],
+        from_balance_nonce: u128,
+        from_locked_nonce: u128,
+        from_new_balance_nonce: u128,
+        to_enc_pubkey: [u8; 32],
+        to_balance_nonce: u128,
+        to_new_balance_nonce: u128,
+        collector_enc_pubkey: [u8; 32],
+        collector_balance_nonce: u128,
+        collector_new_balance_nonce: u128,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::ZeroAmount);
+        ctx.accounts.cvct_mint.check_transfer_limit(amount)?;
+        require!(
+            from_new_balance_nonce > ctx.accounts.from_cvct_account.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            to_new_balance_nonce > ctx.accounts.to_cvct_account.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            collector_new_balance_nonce > ctx.accounts.fee_collector.balance_nonce,
+            ErrorCode::NonceReused
+        );
+
+        // Amount is plaintext, so the fee is floored here; the circuit credits
+        // `amount - fee` and `fee`, which always sum to the debited amount.
+        let fee = (amount as u128)
+            .checked_mul(ctx.accounts.cvct_mint.transfer_fee_bps as u128)
+            .ok_or(ErrorCode::InvalidAmount)?
+            / 10_000;
+
+        let args = ArgBuilder::new()
+            // Sender balance.
+            .x25519_pubkey(from_enc_pubkey)
+            .plaintext_u128(from_balance_nonce)
+            .account(
+                ctx.accounts.from_cvct_account.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Locked portion of the balance.
+            .x25519_pubkey(from_enc_pubkey)
+            .plaintext_u128(from_locked_nonce)
+            .account(
+                ctx.accounts.from_cvct_account.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Plaintext transfer amount and fee portion.
+            .plaintext_u128(amount as u128)
+            .plaintext_u128(fee)
+            // Output context for sender.
+            .x25519_pubkey(from_enc_pubkey)
+            .plaintext_u128(from_new_balance_nonce)
+            // Recipient balance.
+            .x25519_pubkey(to_enc_pubkey)
+            .plaintext_u128(to_balance_nonce)
+            .account(
+                ctx.accounts.to_cvct_account.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Output context for recipient.
+            .x25519_pubkey(to_enc_pubkey)
+            .plaintext_u128(to_new_balance_nonce)
+            // Fee collector balance.
+            .x25519_pubkey(collector_enc_pubkey)
+            .plaintext_u128(collector_balance_nonce)
+            .account(
+                ctx.accounts.fee_collector.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Output context for fee collector.
+            .x25519_pubkey(collector_enc_pubkey)
+            .plaintext_u128(collector_new_balance_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        ctx.accounts
+            .from_cvct_account
+            .begin_update(now, stale_timeout)?;
+        ctx.accounts
+            .to_cvct_account
+            .begin_update(now, stale_timeout)?;
+        ctx.accounts
+            .fee_collector
+            .begin_update(now, stale_timeout)?;
+        consume_computation_offset(
+            &ctx.accounts.from_cvct_account.key(),
+            &mut ctx.accounts.from_cvct_account.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![TransferWithFeeCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.from_cvct_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.to_cvct_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.fee_collector.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "transfer_with_fee")]
+    pub fn transfer_with_fee_callback(
+        ctx: Context<TransferWithFeeCallback>,
+        output: SignedComputationOutputs<TransferWithFeeOutput>,
+    ) -> Result<()> {
+        let (from_balance, to_balance, collector_balance, _ok) = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(TransferWithFeeOutput {
+                field_0:
+                    TransferWithFeeOutputStruct0 {
+                        field_0: from_balance,
+                        field_1: to_balance,
+                        field_2: collector_balance,
+                        field_3: ok,
+                    },
+            }) => (from_balance, to_balance, collector_balance, ok),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.from_cvct_account.key(),
+                        ctx.accounts.from_cvct_account.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::TransferWithFee,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let from_cvct_account = &mut ctx.accounts.from_cvct_account;
+        from_cvct_account.balance = from_balance.ciphertexts;
+        from_cvct_account.balance_nonce = from_balance.nonce;
+        from_cvct_account.update_in_flight = false;
+
+        let to_cvct_account = &mut ctx.accounts.to_cvct_account;
+        to_cvct_account.balance = to_balance.ciphertexts;
+        to_cvct_account.balance_nonce = to_balance.nonce;
+        to_cvct_account.update_in_flight = false;
+
+        let fee_collector = &mut ctx.accounts.fee_collector;
+        fee_collector.balance = collector_balance.ciphertexts;
+        fee_collector.balance_nonce = collector_balance.nonce;
+        fee_collector.update_in_flight = false;
+
+        Ok(())
+    }
+
+    pub fn credit_from_vault(
+        ctx: Context<CreditFromVault>,
+        computation_offset: u64,
+        amount_ciphertext: [u8; 32],
+        amount_enc_pubkey: [u8; 32],
+        amount_nonce: u128,
+        owner_enc_pubkey: [u8; 32],
+        owner_balance_nonce: u128,
+        owner_new_balance_nonce: u128,
+        mint_enc_pubkey: [u8; 32],
+        mint_total_supply_nonce: u128,
+        mint_new_total_supply_nonce: u128,
+        vault_enc_pubkey: [u8; 32],
+        vault_total_locked_nonce: u128,
+        vault_new_unallocated_nonce: u128,
+    ) -> Result<()> {
+        // The circuit bounds supply by locked backing unit for unit, so only 1:1 mints.
+        require!(
+            ctx.accounts.cvct_mint.exchange_rate_num == ctx.accounts.cvct_mint.exchange_rate_den,
+            ErrorCode::InvalidExchangeRate
+        );
+        require!(
+            owner_new_balance_nonce > ctx.accounts.cvct_account.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            mint_new_total_supply_nonce > ctx.accounts.cvct_mint.total_supply_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            vault_new_unallocated_nonce > ctx.accounts.vault.unallocated_nonce,
+            ErrorCode::NonceReused
+        );
+
+        // No SPL transfer: the credit is backed by collateral already in the vault.
+        let args = ArgBuilder::new()
+            // Client-encrypted credit amount.
+            .x25519_pubkey(amount_enc_pubkey)
+            .plaintext_u128(amount_nonce)
+            .encrypted_u128(amount_ciphertext)
+            // Balance input from account data.
+            .x25519_pubkey(owner_enc_pubkey)
+            .plaintext_u128(owner_balance_nonce)
+            .account(
+                ctx.accounts.cvct_account.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Output encryption context for balance.
+            .x25519_pubkey(owner_enc_pubkey)
+            .plaintext_u128(owner_new_balance_nonce)
+            // Total supply input from mint.
+            .x25519_pubkey(mint_enc_pubkey)
+            .plaintext_u128(mint_total_supply_nonce)
+            .account(
+                ctx.accounts.cvct_mint.key(),
+                CvctMint::TOTAL_SUPPLY_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Output encryption context for total supply.
+            .x25519_pubkey(mint_enc_pubkey)
+            .plaintext_u128(mint_new_total_supply_nonce)
+            // Total locked input from vault (read-only; seeds a pool not yet tracked).
+            .x25519_pubkey(vault_enc_pubkey)
+            .plaintext_u128(vault_total_locked_nonce)
+            .account(
+                ctx.accounts.vault.key(),
+                Vault::TOTAL_LOCKED_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Unallocated pool the credit is drawn from.
+            .x25519_pubkey(vault_enc_pubkey)
+            .plaintext_u128(ctx.accounts.vault.unallocated_nonce)
+            .account(
+                ctx.accounts.vault.key(),
+                Vault::UNALLOCATED_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(ctx.accounts.vault.unallocated_nonce == 0)
+            .x25519_pubkey(vault_enc_pubkey)
+            .plaintext_u128(vault_new_unallocated_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        ctx.accounts.cvct_account.begin_update(now, stale_timeout)?;
+        consume_computation_offset(
+            &ctx.accounts.cvct_account.key(),
+            &mut ctx.accounts.cvct_account.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![CreditFromVaultCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.cvct_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.cvct_mint.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.vault.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "credit_from_vault")]
+    pub fn credit_from_vault_callback(
+        ctx: Context<CreditFromVaultCallback>,
+        output: SignedComputationOutputs<CreditFromVaultOutput>,
+    ) -> Result<()> {
+        let (balance, total_supply, unallocated, _ok) = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(CreditFromVaultOutput {
+                field_0:
+                    CreditFromVaultOutputStruct0 {
+                        field_0: balance,
+                        field_1: total_supply,
+                        field_2: unallocated,
+                        field_3: ok,
+                    },
+            }) => (balance, total_supply, unallocated, ok),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_account.key(),
+                        ctx.accounts.cvct_account.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::CreditFromVault,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        // When `ok` is false the circuit re-encrypts unchanged values.
+        let cvct_account = &mut ctx.accounts.cvct_account;
+        cvct_account.balance = balance.ciphertexts;
+        cvct_account.balance_nonce = balance.nonce;
+        cvct_account.update_in_flight = false;
+
+        let cvct_mint = &mut ctx.accounts.cvct_mint;
+        cvct_mint.total_supply = total_supply.ciphertexts;
+        cvct_mint.total_supply_nonce = total_supply.nonce;
+
+        let vault = &mut ctx.accounts.vault;
+        vault.unallocated = unallocated.ciphertexts;
+        vault.unallocated_nonce = unallocated.nonce;
+
+        Ok(())
+    }
+
+    pub fn lock_balance(
+        ctx: Context<LockBalance>,
+        computation_offset: u64,
+        amount: u64,
+        unlock_at: i64,
+        owner_enc_pubkey: [u8; 32],
+        owner_balance_nonce: u128,
+        owner_locked_nonce: u128,
+        owner_new_locked_nonce: u128,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::ZeroAmount);
+        require!(
+            owner_new_locked_nonce > ctx.accounts.cvct_account.locked_balance_nonce,
+            ErrorCode::NonceReused
+        );
+
+        // Bounded so a lock can't become a confiscation; applied only if the lock takes.
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            unlock_at > now && unlock_at - now <= MAX_LOCK_DURATION,
+            ErrorCode::InvalidLockDuration
+        );
+
+        let args = ArgBuilder::new()
+            // Balance input from account data.
+            .x25519_pubkey(owner_enc_pubkey)
+            .plaintext_u128(owner_balance_nonce)
+            .account(
+                ctx.accounts.cvct_account.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Locked portion of the balance.
+            .x25519_pubkey(owner_enc_pubkey)
+            .plaintext_u128(owner_locked_nonce)
+            .account(
+                ctx.accounts.cvct_account.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Plaintext amount to lock.
+            .plaintext_u128(amount as u128)
+            // Release time, handed back to the callback.
+            .plaintext_u128(unlock_at as u128)
+            // Output encryption context for locked balance.
+            .x25519_pubkey(owner_enc_pubkey)
+            .plaintext_u128(owner_new_locked_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        ctx.accounts.cvct_account.begin_update(now, stale_timeout)?;
+        consume_computation_offset(
+            &ctx.accounts.cvct_account.key(),
+            &mut ctx.accounts.cvct_account.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![LockBalanceCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[CallbackAccount {
+                    pubkey: ctx.accounts.cvct_account.key(),
+                    is_writable: true,
+                }],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "lock_balance")]
+    pub fn lock_balance_callback(
+        ctx: Context<LockBalanceCallback>,
+        output: SignedComputationOutputs<LockBalanceOutput>,
+    ) -> Result<()> {
+        let (locked_balance, ok, unlock_at) = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(LockBalanceOutput {
+                field_0:
+                    LockBalanceOutputStruct0 {
+                        field_0: locked_balance,
+                        field_1: ok,
+                        field_2: unlock_at,
+                    },
+            }) => (locked_balance, ok, unlock_at),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_account.key(),
+                        ctx.accounts.cvct_account.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::LockBalance,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let cvct_account = &mut ctx.accounts.cvct_account;
+        cvct_account.locked_balance = locked_balance.ciphertexts;
+        cvct_account.locked_balance_nonce = locked_balance.nonce;
+        cvct_account.update_in_flight = false;
+        // A new lock can only extend the release time of what is already locked.
+        if ok {
+            cvct_account.unlock_at = cvct_account.unlock_at.max(unlock_at as i64);
+        }
+
+        Ok(())
+    }
+
+    pub fn unlock_balance(
+        ctx: Context<UnlockBalance>,
+        computation_offset: u64,
+        owner_enc_pubkey: [u8; 32],
+        owner_new_locked_nonce: u128,
+    ) -> Result<()> {
+        require!(
+            Clock::get()?.unix_timestamp >= ctx.accounts.cvct_account.unlock_at,
+            ErrorCode::BalanceStillLocked
+        );
+        require!(
+            owner_new_locked_nonce > ctx.accounts.cvct_account.locked_balance_nonce,
+            ErrorCode::NonceReused
+        );
+
+        // Release is a fresh encrypted zero for the locked portion.
+        let args = ArgBuilder::new()
+            .x25519_pubkey(owner_enc_pubkey)
+            .plaintext_u128(owner_new_locked_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        ctx.accounts.cvct_account.begin_update(now, stale_timeout)?;
+        consume_computation_offset(
+            &ctx.accounts.cvct_account.key(),
+            &mut ctx.accounts.cvct_account.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![UnlockBalanceCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[CallbackAccount {
+                    pubkey: ctx.accounts.cvct_account.key(),
+                    is_writable: true,
+                }],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "unlock_balance")]
+    pub fn unlock_balance_callback(
+        ctx: Context<UnlockBalanceCallback>,
+        output: SignedComputationOutputs<UnlockBalanceOutput>,
+    ) -> Result<()> {
+        let locked_balance = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(UnlockBalanceOutput { field_0 }) => field_0,
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_account.key(),
+                        ctx.accounts.cvct_account.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::UnlockBalance,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let cvct_account = &mut ctx.accounts.cvct_account;
+        cvct_account.locked_balance = locked_balance.ciphertexts;
+        cvct_account.locked_balance_nonce = locked_balance.nonce;
+        cvct_account.update_in_flight = false;
+
+        Ok(())
+    }
+
+    pub fn bootstrap_mint(
+        ctx: Context<BootstrapMint>,
+        computation_offset: u64,
+        authority_enc_pubkey: [u8; 32],
+        authority_nonce: u128,
+        vault_nonce: u128,
+        owner_enc_pubkey: [u8; 32],
+        owner_nonce: u128,
+        owner_locked_nonce: u128,
+    ) -> Result<()> {
+        // One canonical CVCT mint per backing asset.
+        require!(
+            ctx.accounts.backing_record.cvct_mint == Pubkey::default(),
+            ErrorCode::MintAlreadyExistsForBacking
+        );
+        ctx.accounts.backing_record.cvct_mint = ctx.accounts.cvct_mint.key();
+        ctx.accounts.backing_record.version = ACCOUNT_VERSION;
+
+        // Same setup as initialize_cvct_mint + initialize_cvct_account for the authority,
+        // but a single queued computation produces every encrypted zero.
+        let cvct_mint_key = ctx.accounts.cvct_mint.key();
+        let vault_key = ctx.accounts.vault.key();
+        let cvct_account_key = ctx.accounts.cvct_account.key();
+        let backing_mint_key = ctx.accounts.backing_mint.key();
+        let vault_token_account_key = ctx.accounts.vault_token_account.key();
+        let authority_key = ctx.accounts.authority.key();
+        let decimals = ctx.accounts.backing_mint.decimals;
+        {
+            ctx.accounts.cvct_mint.set_inner(CvctMint {
+                version: ACCOUNT_VERSION,
+                authority: authority_key,
+                backing_mint: backing_mint_key,
+                authority_enc_pubkey,
+                total_supply: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+                total_supply_nonce: 0,
+                decimals,
+                transfer_fee_bps: 0,
+                fee_collector: Pubkey::default(),
+                mode: MintMode::Normal,
+                exchange_rate_num: 1,
+                exchange_rate_den: 1,
+                reveal_threshold: 0,
+                max_transfer: None,
+                op_counter: 0,
+                open_pending_ops: 0,
+                computation_timeout: STALE_COMPUTATION_TIMEOUT,
+            });
+
+            ctx.accounts.vault.set_inner(Vault {
+                version: ACCOUNT_VERSION,
+                cvct_mint: cvct_mint_key,
+                backing_mint: backing_mint_key,
+                backing_token_account: vault_token_account_key,
+                total_locked: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+                total_locked_nonce: 0,
+                unallocated: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+                unallocated_nonce: 0,
+                backed_supply: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+                backed_supply_nonce: 0,
+                backed_supply_sources: [0; 2],
+                backed_supply_in_flight: false,
+                backed_supply_queued_at: 0,
+            });
+
+            ctx.accounts.cvct_account.set_inner(CvctAccount {
+                version: ACCOUNT_VERSION,
+                owner: authority_key,
+                cvct_mint: cvct_mint_key,
+                owner_enc_pubkey,
+                balance: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+                balance_nonce: 0,
+                locked_balance: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+                locked_balance_nonce: 0,
+                unlock_at: 0,
+                open_snapshots: 0,
+                op_counter: 0,
+                update_in_flight: true,
+                update_queued_at: Clock::get()?.unix_timestamp,
+            });
+        }
+
+        // Four Shared encryptions of 0: supply, locked, balance, locked balance.
+        let args = ArgBuilder::new()
+            .x25519_pubkey(authority_enc_pubkey)
+            .plaintext_u128(authority_nonce)
+            .x25519_pubkey(authority_enc_pubkey)
+            .plaintext_u128(vault_nonce)
+            .x25519_pubkey(owner_enc_pubkey)
+            .plaintext_u128(owner_nonce)
+            .x25519_pubkey(owner_enc_pubkey)
+            .plaintext_u128(owner_locked_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.cvct_mint.key(),
+            &mut ctx.accounts.cvct_mint.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![BootstrapMintStateCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: cvct_mint_key,
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: vault_key,
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: cvct_account_key,
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "bootstrap_mint_state")]
+    pub fn bootstrap_mint_state_callback(
+        ctx: Context<BootstrapMintStateCallback>,
+        output: SignedComputationOutputs<BootstrapMintStateOutput>,
+    ) -> Result<()> {
+        let (total_supply, total_locked, balance, locked_balance) = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(BootstrapMintStateOutput {
+                field_0:
+                    BootstrapMintStateOutputStruct0 {
+                        field_0: total_supply,
+                        field_1: total_locked,
+                        field_2: balance,
+                        field_3: locked_balance,
+                    },
+            }) => (total_supply, total_locked, balance, locked_balance),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_mint.key(),
+                        ctx.accounts.cvct_mint.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::BootstrapMintState,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let cvct_mint = &mut ctx.accounts.cvct_mint;
+        cvct_mint.total_supply = total_supply.ciphertexts;
+        cvct_mint.total_supply_nonce = total_supply.nonce;
+
+        let vault = &mut ctx.accounts.vault;
+        vault.total_locked = total_locked.ciphertexts;
+        vault.total_locked_nonce = total_locked.nonce;
+
+        let cvct_account = &mut ctx.accounts.cvct_account;
+        cvct_account.balance = balance.ciphertexts;
+        cvct_account.balance_nonce = balance.nonce;
+        cvct_account.locked_balance = locked_balance.ciphertexts;
+        cvct_account.locked_balance_nonce = locked_balance.nonce;
+        cvct_account.update_in_flight = false;
+
+        Ok(())
+    }
+
+    pub fn snapshot_balance(
+        ctx: Context<SnapshotBalance>,
+        computation_offset: u64,
+        period_index: u64,
+        owner_enc_pubkey: [u8; 32],
+        owner_balance_nonce: u128,
+        snapshot_nonce: u128,
+    ) -> Result<()> {
+        let cvct_account = &mut ctx.accounts.cvct_account;
+        require!(
+            cvct_account.open_snapshots < MAX_OPEN_SNAPSHOTS,
+            ErrorCode::SnapshotLimitReached
+        );
+        cvct_account.open_snapshots += 1;
+
+        let snapshot = &mut ctx.accounts.snapshot;
+        snapshot.set_inner(BalanceSnapshot {
+            version: ACCOUNT_VERSION,
+            cvct_account: cvct_account.key(),
+            period_index,
+            balance: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            balance_nonce: 0,
+        });
+
+        // Re-encrypt the live balance for the reporting authority; the account itself is untouched.
+        let args = ArgBuilder::new()
+            // Balance input from account data.
+            .x25519_pubkey(owner_enc_pubkey)
+            .plaintext_u128(owner_balance_nonce)
+            .account(
+                ctx.accounts.cvct_account.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Output encryption context for the reporting authority.
+            .x25519_pubkey(ctx.accounts.cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(snapshot_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.cvct_account.key(),
+            &mut ctx.accounts.cvct_account.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SnapshotBalanceCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.snapshot.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.cvct_account.key(),
+                        is_writable: false,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "snapshot_balance")]
+    pub fn snapshot_balance_callback(
+        ctx: Context<SnapshotBalanceCallback>,
+        output: SignedComputationOutputs<SnapshotBalanceOutput>,
+    ) -> Result<()> {
+        let balance = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(SnapshotBalanceOutput { field_0 }) => field_0,
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_account.key(),
+                        ctx.accounts.cvct_account.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::SnapshotBalance,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let snapshot = &mut ctx.accounts.snapshot;
+        snapshot.balance = balance.ciphertexts;
+        snapshot.balance_nonce = balance.nonce;
+
+        Ok(())
+    }
+
+    pub fn close_balance_snapshot(ctx: Context<CloseBalanceSnapshot>) -> Result<()> {
+        // Snapshot rent goes back to the authority; free a slot on the account.
+        let cvct_account = &mut ctx.accounts.cvct_account;
+        cvct_account.open_snapshots = cvct_account.open_snapshots.saturating_sub(1);
+        Ok(())
+    }
+
+    pub fn migrate_account_size(ctx: Context<MigrateAccountSize>) -> Result<()> {
+        // Grow a program account created under an older layout; new trailing fields read as zero.
+        let account = &ctx.accounts.account;
+        require_keys_eq!(*account.owner, crate::ID, ErrorCode::InvalidAccountSize);
+
+        // Current size and the size written before the version byte existed.
+        let (target, legacy_len) = {
+            let data = account.try_borrow_data()?;
+            require!(data.len() > 8, ErrorCode::InvalidAccountSize);
+            let discriminator = &data[..8];
+            if discriminator == CvctMint::DISCRIMINATOR {
+                (8 + CvctMint::INIT_SPACE, 204)
+            } else if discriminator == Vault::DISCRIMINATOR {
+                (8 + Vault::INIT_SPACE, 152)
+            } else if discriminator == CvctAccount::DISCRIMINATOR {
+                (8 + CvctAccount::INIT_SPACE, 210)
+            } else if discriminator == EmergencyWithdrawal::DISCRIMINATOR {
+                (8 + EmergencyWithdrawal::INIT_SPACE, 88)
+            } else if discriminator == BackingMintRecord::DISCRIMINATOR {
+                (8 + BackingMintRecord::INIT_SPACE, 40)
+            } else if discriminator == BalanceSnapshot::DISCRIMINATOR {
+                (8 + BalanceSnapshot::INIT_SPACE, 96)
+            } else if discriminator == Organization::DISCRIMINATOR {
+                (8 + Organization::INIT_SPACE, 122)
+            } else if discriminator == Payroll::DISCRIMINATOR {
+                (8 + Payroll::INIT_SPACE, 128)
+            } else if discriminator == PayrollMember::DISCRIMINATOR {
+                (8 + PayrollMember::INIT_SPACE, 130)
+            } else if discriminator == DepositReceipt::DISCRIMINATOR {
+                (8 + DepositReceipt::INIT_SPACE, 80)
+            } else if discriminator == PendingOp::DISCRIMINATOR {
+                (8 + PendingOp::INIT_SPACE, 89)
+            } else {
+                return err!(ErrorCode::InvalidAccountSize);
+            }
+        };
+
+        // Unversioned (v0) accounts: shift the body right one byte and stamp version 1.
+        if account.data_len() == legacy_len {
+            grow_program_account(
+                account,
+                &ctx.accounts.payer,
+                &ctx.accounts.system_program,
+                target,
+            )?;
+            let mut data = account.try_borrow_mut_data()?;
+            data.copy_within(8..legacy_len, 9);
+            data[8] = 1;
+        }
+
+        // Each layout change bumps ACCOUNT_VERSION and adds an in-place upgrade arm here
+        // (realloc, rewrite moved fields, then stamp the new version).
+        let version = account.try_borrow_data()?[8];
+        match version {
+            1 => upgrade_v1_account(
+                account,
+                &ctx.accounts.payer,
+                &ctx.accounts.system_program,
+                target,
+            )?,
+            ACCOUNT_VERSION => {}
+            _ => return err!(ErrorCode::UnsupportedAccountVersion),
+        }
+
+        if account.data_len() >= target {
+            return Ok(());
+        }
+
+        grow_program_account(
+            account,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            target,
+        )
+    }
+
+    /// Creates the platform mint allowlist; only the program's upgrade authority may.
+    pub fn init_mint_registry(ctx: Context<InitMintRegistry>, authority: Pubkey) -> Result<()> {
+        ctx.accounts.mint_registry.set_inner(MintRegistry {
+            version: ACCOUNT_VERSION,
+            authority,
+            mints: Vec::new(),
+            bump: ctx.bumps.mint_registry,
+        });
+        Ok(())
+    }
+
+    pub fn register_mint(ctx: Context<RegisterMint>) -> Result<()> {
+        let cvct_mint = ctx.accounts.cvct_mint.key();
+        let registry = &mut ctx.accounts.mint_registry;
+        require!(
+            !registry.is_approved(&cvct_mint),
+            ErrorCode::MintAlreadyApproved
+        );
+        require!(
+            registry.mints.len() < MAX_REGISTERED_MINTS,
+            ErrorCode::MintRegistryFull
+        );
+        registry.mints.push(cvct_mint);
+        Ok(())
+    }
+
+    /// Existing orgs keep working; only new orgs and migrations are refused the mint.
+    pub fn deregister_mint(ctx: Context<DeregisterMint>, cvct_mint: Pubkey) -> Result<()> {
+        let registry = &mut ctx.accounts.mint_registry;
+        let index = registry
+            .mints
+            .iter()
+            .position(|key| *key == cvct_mint)
+            .ok_or(ErrorCode::MintNotApproved)?;
+        registry.mints.swap_remove(index);
+        Ok(())
+    }
+
+    pub fn init_org(ctx: Context<InitOrg>, authority_enc_pubkey: [u8; 32]) -> Result<()> {
+        let org = &mut ctx.accounts.org;
+        org.set_inner(Organization {
+            version: ACCOUNT_VERSION,
+            authority: ctx.accounts.authority.key(),
+            cvct_mint: ctx.accounts.cvct_mint.key(),
+            cvct_treasury_vault: Pubkey::default(),
+            authority_enc_pubkey,
+            min_reserve: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            min_reserve_nonce: 0,
+            total_disbursed: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            total_disbursed_nonce: 0,
+            disbursement_in_flight: false,
+            disbursement_queued_at: 0,
+            payroll_count: 0,
+            treasury_low_threshold: None,
+            approval_threshold: None,
+            close_threshold: 0,
+            close_approvers: Vec::new(),
+            authorized_spenders: Vec::new(),
+            bump: ctx.bumps.org,
+        });
+        Ok(())
+    }
+
+    pub fn init_org_treasury(
+        ctx: Context<InitOrgTreasury>,
+        computation_offset: u64,
+        treasury_enc_pubkey: [u8; 32],
+        treasury_nonce: u128,
+        treasury_locked_nonce: u128,
+    ) -> Result<()> {
+        let org_key = ctx.accounts.org.key();
+        let cvct_mint_key = ctx.accounts.cvct_mint.key();
+        let treasury_key = ctx.accounts.treasury.key();
+
+        // Treasury is a regular CvctAccount owned by the org PDA, encrypted to the authority.
+        ctx.accounts.treasury.set_inner(CvctAccount {
+            version: ACCOUNT_VERSION,
+            owner: org_key,
+            cvct_mint: cvct_mint_key,
+            owner_enc_pubkey: treasury_enc_pubkey,
+            balance: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            balance_nonce: 0,
+            locked_balance: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            locked_balance_nonce: 0,
+            unlock_at: 0,
+            open_snapshots: 0,
+            op_counter: 0,
+            update_in_flight: true,
+            update_queued_at: Clock::get()?.unix_timestamp,
+        });
+        // A treasury for another mint is staged for `migrate_org_mint` instead of bound.
+        if cvct_mint_key == ctx.accounts.org.cvct_mint {
+            ctx.accounts.org.cvct_treasury_vault = treasury_key;
+        }
+
+        let args = ArgBuilder::new()
+            .x25519_pubkey(treasury_enc_pubkey)
+            .plaintext_u128(treasury_nonce)
+            .x25519_pubkey(treasury_enc_pubkey)
+            .plaintext_u128(treasury_locked_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.treasury.key(),
+            &mut ctx.accounts.treasury.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitAccountStateCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[CallbackAccount {
+                    pubkey: treasury_key,
+                    is_writable: true,
+                }],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Stores a reserve the org authority encrypted client-side for `authority_enc_pubkey`.
+    pub fn set_treasury_reserve(
+        ctx: Context<SetTreasuryReserve>,
+        min_reserve: [u8; 32],
+        min_reserve_nonce: u128,
+    ) -> Result<()> {
+        let org = &mut ctx.accounts.org;
+        // A fresh nonce per ciphertext; 0 is reserved for "no reserve".
+        require!(
+            min_reserve_nonce > org.min_reserve_nonce,
+            ErrorCode::NonceReused
+        );
+        org.min_reserve = [min_reserve; ENCRYPTED_U128_CIPHERTEXTS];
+        org.min_reserve_nonce = min_reserve_nonce;
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::SetTreasuryReserve,
+            ctx.accounts.authority.key(),
+        )?;
+        Ok(())
+    }
+
+    pub fn create_payroll(
+        ctx: Context<CreatePayroll>,
+        interval: i64,
+        rate_unit: RateUnit,
+        max_periods_per_run: Option<u32>,
+        max_members: Option<u32>,
+    ) -> Result<()> {
+        require!(interval > 0, ErrorCode::InvalidInterval);
+        require!(
+            max_periods_per_run.map_or(true, |cap| cap > 0),
+            ErrorCode::InvalidInterval
+        );
+        let max_members = max_members.unwrap_or(DEFAULT_MAX_MEMBERS);
+        require!(max_members > 0, ErrorCode::MemberLimitReached);
+
+        ctx.accounts.org.payroll_count += 1;
+
+        let payroll = &mut ctx.accounts.payroll;
+        payroll.set_inner(Payroll {
+            version: ACCOUNT_VERSION,
+            org: ctx.accounts.org.key(),
+            admin: ctx.accounts.admin.key(),
+            interval,
+            interval_changed_at: 0,
+            rate_unit,
+            rounding_mode: RoundingMode::Floor,
+            max_periods_per_run,
+            max_members,
+            member_count: 0,
+            last_run: 0,
+            clawback_window: 0,
+            treasury: Pubkey::default(),
+            timekeeper: Pubkey::default(),
+            auto_pause: false,
+            active: true,
+            bump: ctx.bumps.payroll,
+        });
+        Ok(())
+    }
+
+    pub fn init_payroll_treasury(
+        ctx: Context<InitPayrollTreasury>,
+        computation_offset: u64,
+        treasury_enc_pubkey: [u8; 32],
+        treasury_nonce: u128,
+        treasury_locked_nonce: u128,
+    ) -> Result<()> {
+        // A payroll keeps one treasury, replaced only once a mint migration strands it.
+        require!(
+            ctx.accounts.payroll.treasury == Pubkey::default()
+                || ctx.accounts.previous_treasury.is_some(),
+            ErrorCode::InvalidVault
+        );
+        let payroll_key = ctx.accounts.payroll.key();
+        let cvct_mint_key = ctx.accounts.cvct_mint.key();
+        let treasury_key = ctx.accounts.treasury.key();
+
+        // Same shape as the org treasury, but owned by the payroll PDA to ring-fence its budget.
+        ctx.accounts.treasury.set_inner(CvctAccount {
+            version: ACCOUNT_VERSION,
+            owner: payroll_key,
+            cvct_mint: cvct_mint_key,
+            owner_enc_pubkey: treasury_enc_pubkey,
+            balance: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            balance_nonce: 0,
+            locked_balance: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            locked_balance_nonce: 0,
+            unlock_at: 0,
+            open_snapshots: 0,
+            op_counter: 0,
+            update_in_flight: true,
+            update_queued_at: Clock::get()?.unix_timestamp,
+        });
+        ctx.accounts.payroll.treasury = treasury_key;
+
+        let args = ArgBuilder::new()
+            .x25519_pubkey(treasury_enc_pubkey)
+            .plaintext_u128(treasury_nonce)
+            .x25519_pubkey(treasury_enc_pubkey)
+            .plaintext_u128(treasury_locked_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.treasury.key(),
+            &mut ctx.accounts.treasury.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitAccountStateCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[CallbackAccount {
+                    pubkey: treasury_key,
+                    is_writable: true,
+                }],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn add_payroll_member(ctx: Context<AddPayrollMember>, rate: u64) -> Result<()> {
+        ctx.accounts.payroll.admit_members(1)?;
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        payroll_member.set_inner(PayrollMember {
+            version: ACCOUNT_VERSION,
+            payroll: ctx.accounts.payroll.key(),
+            recipient: ctx.accounts.recipient.key(),
+            cvct_wallet: ctx.accounts.recipient_cvct_account.key(),
+            accrued: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            accrued_nonce: 0,
+            hours: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            hours_nonce: 0,
+            hours_enc_pubkey: [0u8; 32],
+            deductions: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            deductions_nonce: 0,
+            bonus_accrued: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            bonus_accrued_nonce: 0,
+            rate,
+            interval_override: None,
+            cycle_interval: ctx.accounts.payroll.interval,
+            interval_changed_at: 0,
+            last_paid: 0,
+            previous_last_paid: 0,
+            rounding_remainder: 0,
+            previous_rounding_remainder: 0,
+            previous_rate: 0,
+            clawable: 0,
+            last_payment_at: 0,
+            run_in_flight: false,
+            run_charge: 0,
+            op_counter: 0,
+            deduction_in_flight: false,
+            deduction_queued_at: 0,
+            created_at: Clock::get()?.unix_timestamp,
+            active: true,
+            bump: ctx.bumps.payroll_member,
+        });
+        Ok(())
+    }
+
+    /// Sets `rates[i]` and `active[i]` on the i-th `PayrollMember` in `remaining_accounts`.
+    ///
+    /// Every member must be writable and belong to `payroll`; up to `MEMBER_UPDATE_BATCH_SIZE`.
+    pub fn update_payroll_members_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdatePayrollMembersBatch<'info>>,
+        rates: Vec<u64>,
+        active: Vec<bool>,
+    ) -> Result<()> {
+        let count = rates.len();
+        require!(
+            count > 0
+                && count <= MEMBER_UPDATE_BATCH_SIZE
+                && active.len() == count
+                && ctx.remaining_accounts.len() == count,
+            ErrorCode::InvalidBatch
+        );
+
+        let payroll_key = ctx.accounts.payroll.key();
+        for (i, info) in ctx.remaining_accounts.iter().enumerate() {
+            require!(info.is_writable, ErrorCode::InvalidBatch);
+            let mut payroll_member = Account::<PayrollMember>::try_from(info)?;
+            require_keys_eq!(payroll_member.payroll, payroll_key, ErrorCode::InvalidBatch);
+            payroll_member.rate = rates[i];
+            // Kept if an in-flight run reverts.
+            payroll_member.previous_rate = rates[i];
+            payroll_member.active = active[i];
+            payroll_member.exit(&crate::ID)?;
+        }
+        log_authority_action(
+            ctx.accounts.payroll.org,
+            AuthorityActionKind::UpdatePayrollMembers,
+            ctx.accounts.admin.key(),
+        )?;
+        Ok(())
+    }
+
+    pub fn pause_payroll(ctx: Context<UpdatePayrollStatus>) -> Result<()> {
+        ctx.accounts.payroll.active = false;
+        log_authority_action(
+            ctx.accounts.payroll.org,
+            AuthorityActionKind::PausePayroll,
+            ctx.accounts.admin.key(),
+        )?;
+        Ok(())
+    }
+
+    pub fn resume_payroll(ctx: Context<UpdatePayrollStatus>) -> Result<()> {
+        ctx.accounts.payroll.active = true;
+        log_authority_action(
+            ctx.accounts.payroll.org,
+            AuthorityActionKind::ResumePayroll,
+            ctx.accounts.admin.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Opts the payroll in or out of pausing itself when a run finds its treasury unable
+    /// to cover it, or empties it.
+    pub fn set_payroll_auto_pause(ctx: Context<UpdatePayrollStatus>, enabled: bool) -> Result<()> {
+        ctx.accounts.payroll.auto_pause = enabled;
+        log_authority_action(
+            ctx.accounts.payroll.org,
+            AuthorityActionKind::SetAutoPause,
+            ctx.accounts.admin.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Takes effect from the next payment; remainders already carried are kept.
+    pub fn set_rounding_mode(
+        ctx: Context<UpdatePayrollStatus>,
+        rounding_mode: RoundingMode,
+    ) -> Result<()> {
+        ctx.accounts.payroll.rounding_mode = rounding_mode;
+        log_authority_action(
+            ctx.accounts.payroll.org,
+            AuthorityActionKind::SetRoundingMode,
+            ctx.accounts.admin.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Applies to payments queued before and after the change alike.
+    pub fn set_clawback_window(
+        ctx: Context<UpdatePayrollStatus>,
+        clawback_window: i64,
+    ) -> Result<()> {
+        require!(clawback_window >= 0, ErrorCode::InvalidInterval);
+        ctx.accounts.payroll.clawback_window = clawback_window;
+        log_authority_action(
+            ctx.accounts.payroll.org,
+            AuthorityActionKind::SetClawbackWindow,
+            ctx.accounts.admin.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Approver signatures beyond the admin are passed as signer `remaining_accounts`.
+    pub fn close_payroll(ctx: Context<ClosePayroll>) -> Result<()> {
+        require!(!ctx.accounts.payroll.active, ErrorCode::PayrollStillActive);
+        ctx.accounts
+            .org
+            .check_close_approvals(&ctx.accounts.admin.key(), ctx.remaining_accounts)?;
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::ClosePayroll,
+            ctx.accounts.admin.key(),
+        )?;
+        Ok(())
+    }
+
+    pub fn run_payroll_for_member(
+        ctx: Context<RunPayrollForMember>,
+        computation_offset: u64,
+        treasury_new_balance_nonce: u128,
+        member_new_balance_nonce: u128,
+        org_new_disbursed_nonce: u128,
+        member_new_deductions_nonce: u128,
+    ) -> Result<()> {
+        require!(
+            treasury_new_balance_nonce > ctx.accounts.treasury.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            member_new_balance_nonce > ctx.accounts.member_wallet.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            org_new_disbursed_nonce > ctx.accounts.org.total_disbursed_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            member_new_deductions_nonce > ctx.accounts.payroll_member.deductions_nonce,
+            ErrorCode::NonceReused
+        );
+
+        let capped = RunnerAllowance::check_runner(
+            &ctx.accounts.runner_allowance,
+            &ctx.accounts.org,
+            &ctx.accounts.payroll.admin,
+            &ctx.accounts.payer.key(),
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        payroll_member.ready_for_run(now, stale_timeout)?;
+        payroll_member.roll_cycle_interval(&ctx.accounts.payroll);
+        let (amount, paid_through, remainder) =
+            payroll_member.amount_owed(&ctx.accounts.payroll, now)?;
+
+        // A zero rate (or a fraction that rounds away) pays nothing; leave last_paid
+        // alone so the periods stay owed.
+        if amount == 0 {
+            emit!(ZeroPaymentSkipped {
+                payroll_member: payroll_member.key(),
+                last_paid: payroll_member.last_paid,
+            });
+            return Ok(());
+        }
+
+        require!(
+            !ctx.accounts.org.requires_approval(amount),
+            ErrorCode::ApprovalRequired
+        );
+
+        // Advance at queue time so a second run can't be queued for the same periods.
+        payroll_member.begin_run(paid_through, remainder, amount, now);
+        ctx.accounts.payroll.last_run = now;
+        ctx.accounts.org.begin_disbursement(now, stale_timeout)?;
+        ctx.accounts.treasury.begin_update(now, stale_timeout)?;
+        ctx.accounts
+            .member_wallet
+            .begin_update(now, stale_timeout)?;
+
+        if capped {
+            RunnerAllowance::charge_at(&ctx.accounts.runner_allowance, amount)?;
+            ctx.accounts.payroll_member.run_charge = amount;
+        }
+
+        let org = &ctx.accounts.org;
+        let treasury = &ctx.accounts.treasury;
+        let member_wallet = &ctx.accounts.member_wallet;
+        let payroll_member = &ctx.accounts.payroll_member;
+        let args = ArgBuilder::new()
+            // Treasury balance.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Locked portion of the treasury.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.locked_balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Org reserve the treasury must keep; ignored until one is set.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.min_reserve_nonce)
+            .account(
+                org.key(),
+                Organization::MIN_RESERVE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.min_reserve_nonce != 0)
+            // Plaintext amount owed.
+            .plaintext_u128(amount)
+            // Low-balance alert level (0 never triggers).
+            .plaintext_u128(ctx.accounts.org.treasury_low_threshold.unwrap_or(0))
+            // Reveal treasury exhaustion only when the payroll opted into auto-pause.
+            .plaintext_bool(ctx.accounts.payroll.auto_pause)
+            .plaintext_u128(AUTO_PAUSE_EPSILON)
+            // Output context for treasury.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury_new_balance_nonce)
+            // Member balance.
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_wallet.balance_nonce)
+            .account(
+                member_wallet.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Output context for member.
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_new_balance_nonce)
+            // Org disbursed total; starts from zero before the first payment lands.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.total_disbursed_nonce)
+            .account(
+                org.key(),
+                Organization::TOTAL_DISBURSED_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.total_disbursed_nonce == 0)
+            // Output context for the org total.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org_new_disbursed_nonce)
+            // Outstanding deductions; start from zero before the first one lands.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(payroll_member.deductions_nonce)
+            .account(
+                payroll_member.key(),
+                PayrollMember::DEDUCTIONS_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(payroll_member.deductions_nonce == 0)
+            // Output context for deductions.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(member_new_deductions_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.treasury.key(),
+            &mut ctx.accounts.treasury.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RunPayrollCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.treasury.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.member_wallet.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payroll_member.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payroll.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.org.key(),
+                        is_writable: true,
+                    },
+                    // Refunded if the run moves nothing; the program ID stands in for `None`
+                    // when the runner wasn't charged.
+                    if capped {
+                        CallbackAccount {
+                            pubkey: ctx.accounts.runner_allowance.key(),
+                            is_writable: true,
+                        }
+                    } else {
+                        CallbackAccount {
+                            pubkey: crate::ID,
+                            is_writable: false,
+                        }
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "run_payroll")]
+    pub fn run_payroll_callback(
+        ctx: Context<RunPayrollCallback>,
+        output: SignedComputationOutputs<RunPayrollOutput>,
+    ) -> Result<()> {
+        let (treasury_balance, member_balance, total_disbursed, deductions, ok, low, exhausted) =
+            match output.verify_output(
+                &ctx.accounts.cluster_account,
+                &ctx.accounts.computation_account,
+            ) {
+                Ok(RunPayrollOutput {
+                    field_0:
+                        RunPayrollOutputStruct0 {
+                            field_0: treasury_balance,
+                            field_1: member_balance,
+                            field_2: total_disbursed,
+                            field_3: deductions,
+                            field_4: ok,
+                            field_5: low,
+                            field_6: exhausted,
+                        },
+                }) => (
+                    treasury_balance,
+                    member_balance,
+                    total_disbursed,
+                    deductions,
+                    ok,
+                    low,
+                    exhausted,
+                ),
+                Err(_) => {
+                    emit!(ComputationAborted {
+                        computation_offset: aborted_computation_offset(
+                            &ctx.accounts.treasury.key(),
+                            ctx.accounts.treasury.op_counter,
+                            &ctx.accounts.mxe_account,
+                            ctx.accounts.computation_account.key,
+                        )?,
+                        instruction_kind: ComputationKind::RunPayroll,
+                    });
+                    return Err(ErrorCode::AbortedComputation.into());
+                }
+            };
+
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.balance = treasury_balance.ciphertexts;
+        treasury.balance_nonce = treasury_balance.nonce;
+        treasury.update_in_flight = false;
+
+        let member_wallet = &mut ctx.accounts.member_wallet;
+        member_wallet.balance = member_balance.ciphertexts;
+        member_wallet.balance_nonce = member_balance.nonce;
+        member_wallet.update_in_flight = false;
+
+        let org = &mut ctx.accounts.org;
+        org.total_disbursed = total_disbursed.ciphertexts;
+        org.total_disbursed_nonce = total_disbursed.nonce;
+        org.disbursement_in_flight = false;
+
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        payroll_member.deductions = deductions.ciphertexts;
+        payroll_member.deductions_nonce = deductions.nonce;
+        // Nothing moved on `!ok`, so the periods are still owed and the runner is refunded.
+        let refund = if ok { 0 } else { payroll_member.run_charge };
+        payroll_member.finish_run(ok);
+        if let Some(allowance) = ctx.accounts.runner_allowance.as_mut() {
+            allowance.refund(refund);
+        }
+
+        emit!(PayrollRunCompleted {
+            payroll_member: ctx.accounts.payroll_member.key(),
+            paid: ok,
+        });
+
+        if !ok {
+            let payroll_member = &ctx.accounts.payroll_member;
+            emit!(ZeroPaymentSkipped {
+                payroll_member: payroll_member.key(),
+                last_paid: payroll_member.last_paid,
+            });
+        }
+
+        if low {
+            emit!(TreasuryLow {
+                owner: treasury.owner,
+                treasury: treasury.key(),
+            });
+        }
+
+        // Stop further runs instead of letting them queue zero-payment no-ops.
+        let payroll = &mut ctx.accounts.payroll;
+        if exhausted && payroll.active {
+            payroll.active = false;
+            emit!(PayrollAutoPaused {
+                payroll: payroll.key(),
+                treasury: treasury.key(),
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn close_deposit_receipt(ctx: Context<CloseDepositReceipt>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now.saturating_sub(ctx.accounts.deposit_receipt.created_at)
+                >= DEPOSIT_IDEMPOTENCY_WINDOW,
+            ErrorCode::DuplicateDeposit
+        );
+        Ok(())
+    }
+
+    pub fn set_approval_threshold(
+        ctx: Context<SetTreasuryLowThreshold>,
+        threshold: Option<u128>,
+    ) -> Result<()> {
+        ctx.accounts.org.approval_threshold = threshold;
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::SetApprovalThreshold,
+            ctx.accounts.authority.key(),
+        )?;
+        Ok(())
+    }
+
+    pub fn set_treasury_low_threshold(
+        ctx: Context<SetTreasuryLowThreshold>,
+        threshold: Option<u128>,
+    ) -> Result<()> {
+        ctx.accounts.org.treasury_low_threshold = threshold;
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::SetTreasuryLowThreshold,
+            ctx.accounts.authority.key(),
+        )?;
+        Ok(())
+    }
+
+    pub fn close_account(ctx: Context<CloseCvctAccount>, computation_offset: u64) -> Result<()> {
+        // Only the zero/non-zero flag is revealed; the callback closes on zero.
+        let cvct_account = &ctx.accounts.cvct_account;
+        let args = ArgBuilder::new()
+            .x25519_pubkey(cvct_account.owner_enc_pubkey)
+            .plaintext_u128(cvct_account.balance_nonce)
+            .account(
+                cvct_account.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        // Nothing else may rewrite the balance between the zero check and the close.
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        ctx.accounts.cvct_account.begin_update(now, stale_timeout)?;
+        consume_computation_offset(
+            &ctx.accounts.cvct_account.key(),
+            &mut ctx.accounts.cvct_account.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![IsZeroCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.cvct_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.owner.key(),
+                        is_writable: true,
+                    },
+                    // No migration; the program ID stands in for each `None`.
+                    CallbackAccount {
+                        pubkey: crate::ID,
+                        is_writable: false,
+                    },
+                    CallbackAccount {
+                        pubkey: crate::ID,
+                        is_writable: false,
+                    },
+                    CallbackAccount {
+                        pubkey: crate::ID,
+                        is_writable: false,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "is_zero")]
+    pub fn is_zero_callback(
+        ctx: Context<IsZeroCallback>,
+        output: SignedComputationOutputs<IsZeroOutput>,
+    ) -> Result<()> {
+        let is_zero = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(IsZeroOutput { field_0 }) => field_0,
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_account.key(),
+                        ctx.accounts.cvct_account.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::IsZero,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        if let (Some(org), Some(new_cvct_mint), Some(new_treasury)) = (
+            ctx.accounts.org.as_mut(),
+            ctx.accounts.new_cvct_mint.as_ref(),
+            ctx.accounts.new_treasury.as_ref(),
+        ) {
+            require!(is_zero, ErrorCode::NonZeroBalance);
+
+            let old_cvct_mint = org.cvct_mint;
+            org.cvct_mint = new_cvct_mint.key();
+            org.cvct_treasury_vault = new_treasury.key();
+
+            emit!(OrgMigrated {
+                org: org.key(),
+                old_cvct_mint,
+                new_cvct_mint: org.cvct_mint,
+                new_treasury: org.cvct_treasury_vault,
+            });
+            return Ok(());
+        }
+
+        // A non-zero balance, or a snapshot queued after the close that would otherwise
+        // lose its rent, leaves the account open and usable again.
+        let owner = ctx.accounts.owner.as_ref().ok_or(ErrorCode::Unauthorized)?;
+        let cvct_account = &mut ctx.accounts.cvct_account;
+        require_keys_eq!(owner.key(), cvct_account.owner, ErrorCode::Unauthorized);
+        cvct_account.update_in_flight = false;
+        if !is_zero || cvct_account.open_snapshots > 0 {
+            return Ok(());
+        }
+        cvct_account.close(owner.to_account_info())
+    }
+
+    /// Creates up to `BATCH_INIT_SIZE` accounts with one MPC computation.
+    ///
+    /// `remaining_accounts` holds, per entry of `owners` and in the same order, the
+    /// writable, uninitialized `cvct_account` PDA followed by the owner as a signer
+    /// (stride 2), so nobody can claim another wallet's account.
+    pub fn batch_init_accounts<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchInitAccounts<'info>>,
+        computation_offset: u64,
+        owners: Vec<Pubkey>,
+        owner_enc_pubkeys: Vec<[u8; 32]>,
+        owner_nonces: Vec<u128>,
+        owner_locked_nonces: Vec<u128>,
+    ) -> Result<()> {
+        let count = owners.len();
+        require!(
+            count > 0
+                && count <= BATCH_INIT_SIZE
+                && owner_enc_pubkeys.len() == count
+                && owner_nonces.len() == count
+                && owner_locked_nonces.len() == count
+                && ctx.remaining_accounts.len() == count * 2,
+            ErrorCode::InvalidBatch
+        );
+
+        let cvct_mint_key = ctx.accounts.cvct_mint.key();
+        let mut callback_accounts = Vec::with_capacity(count + 1);
+        callback_accounts.push(CallbackAccount {
+            pubkey: cvct_mint_key,
+            is_writable: false,
+        });
+
+        for (i, pair) in ctx.remaining_accounts.chunks(2).enumerate() {
+            let (cvct_account, owner) = (&pair[0], &pair[1]);
+            require_keys_eq!(owner.key(), owners[i], ErrorCode::InvalidBatch);
+            require!(owner.is_signer, ErrorCode::Unauthorized);
+            let (expected, bump) = Pubkey::find_program_address(
+                &[b"cvct_account", cvct_mint_key.as_ref(), owners[i].as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(cvct_account.key(), expected, ErrorCode::InvalidBatch);
+            require!(cvct_account.data_is_empty(), ErrorCode::InvalidBatch);
+
+            create_program_account(
+                cvct_account,
+                &ctx.accounts.authority,
+                &ctx.accounts.system_program,
+                8 + CvctAccount::INIT_SPACE,
+                &[
+                    b"cvct_account",
+                    cvct_mint_key.as_ref(),
+                    owners[i].as_ref(),
+                    &[bump],
+                ],
+            )?;
+
+            let state = CvctAccount {
+                version: ACCOUNT_VERSION,
+                owner: owners[i],
+                cvct_mint: cvct_mint_key,
+                owner_enc_pubkey: owner_enc_pubkeys[i],
+                balance: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+                balance_nonce: 0,
+                locked_balance: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+                locked_balance_nonce: 0,
+                unlock_at: 0,
+                open_snapshots: 0,
+                op_counter: 0,
+                update_in_flight: true,
+                update_queued_at: Clock::get()?.unix_timestamp,
+            };
+            let mut data = cvct_account.try_borrow_mut_data()?;
+            state.try_serialize(&mut &mut data[..])?;
+
+            callback_accounts.push(CallbackAccount {
+                pubkey: cvct_account.key(),
+                is_writable: true,
+            });
+        }
+
+        // The circuit always produces BATCH_INIT_SIZE pairs; unused slots reuse the
+        // first context and their outputs are dropped by the callback.
+        let mut args = ArgBuilder::new();
+        for i in 0..BATCH_INIT_SIZE {
+            let slot = if i < count { i } else { 0 };
+            args = args
+                .x25519_pubkey(owner_enc_pubkeys[slot])
+                .plaintext_u128(owner_nonces[slot])
+                .x25519_pubkey(owner_enc_pubkeys[slot])
+                .plaintext_u128(owner_locked_nonces[slot]);
+        }
+        let args = args.build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.cvct_mint.key(),
+            &mut ctx.accounts.cvct_mint.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![InitAccountStateBatchCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &callback_accounts,
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "init_account_state_batch")]
+    pub fn init_account_state_batch_callback<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitAccountStateBatchCallback<'info>>,
+        output: SignedComputationOutputs<InitAccountStateBatchOutput>,
+    ) -> Result<()> {
+        let outputs = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(InitAccountStateBatchOutput {
+                field_0:
+                    InitAccountStateBatchOutputStruct0 {
+                        field_0,
+                        field_1,
+                        field_2,
+                        field_3,
+                        field_4,
+                        field_5,
+                        field_6,
+                        field_7,
+                    },
+            }) => [
+                (field_0, field_1),
+                (field_2, field_3),
+                (field_4, field_5),
+                (field_6, field_7),
+            ],
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_mint.key(),
+                        ctx.accounts.cvct_mint.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::InitAccountStateBatch,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        // Accounts arrive in queue order; extra output slots are padding.
+        for (info, (balance, locked_balance)) in ctx.remaining_accounts.iter().zip(outputs) {
+            let mut cvct_account = Account::<CvctAccount>::try_from(info)?;
+            cvct_account.balance = balance.ciphertexts;
+            cvct_account.balance_nonce = balance.nonce;
+            cvct_account.locked_balance = locked_balance.ciphertexts;
+            cvct_account.locked_balance_nonce = locked_balance.nonce;
+            cvct_account.update_in_flight = false;
+            cvct_account.exit(&crate::ID)?;
+        }
+
+        Ok(())
+    }
+
+    /// Rebinds the org to a new mint once its current treasury is revealed empty.
+    ///
+    /// The new treasury must already be staged via `init_org_treasury` for the new mint.
+    /// Members' `cvct_wallet`s still point at old-mint accounts and must be re-pointed.
+    pub fn migrate_org_mint(ctx: Context<MigrateOrgMint>, computation_offset: u64) -> Result<()> {
+        let treasury = &ctx.accounts.treasury;
+        let args = ArgBuilder::new()
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.treasury.key(),
+            &mut ctx.accounts.treasury.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![IsZeroCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.treasury.key(),
+                        is_writable: true,
+                    },
+                    // No rent destination; the program ID stands in for `None`.
+                    CallbackAccount {
+                        pubkey: crate::ID,
+                        is_writable: false,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.org.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.new_cvct_mint.key(),
+                        is_writable: false,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.new_treasury.key(),
+                        is_writable: false,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::MigrateOrgMint,
+            ctx.accounts.authority.key(),
+        )?;
+        Ok(())
+    }
+
+    pub fn mint_units(ctx: Context<MintUnits>) -> Result<()> {
+        // Single source of truth for how clients scale base units for display.
+        let cvct_mint = &ctx.accounts.cvct_mint;
+        emit!(MintUnitsReported {
+            cvct_mint: cvct_mint.key(),
+            backing_mint: cvct_mint.backing_mint,
+            decimals: cvct_mint.decimals,
+        });
+        Ok(())
+    }
+
+    pub fn can_pay_member(ctx: Context<CanPayMember>, computation_offset: u64) -> Result<()> {
+        // Same amount the run path would charge right now; only the yes/no is revealed.
+        let now = Clock::get()?.unix_timestamp;
+        let (amount, _, _) = ctx
+            .accounts
+            .payroll_member
+            .amount_owed(&ctx.accounts.payroll, now)?;
+        require!(amount > 0, ErrorCode::ZeroAmount);
+
+        let org = &ctx.accounts.org;
+        let treasury = &ctx.accounts.treasury;
+        let args = ArgBuilder::new()
+            // Treasury balance.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Locked portion of the treasury.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.locked_balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Org reserve the treasury must keep; ignored until one is set.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.min_reserve_nonce)
+            .account(
+                org.key(),
+                Organization::MIN_RESERVE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.min_reserve_nonce != 0)
+            .plaintext_u128(amount)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.treasury.key(),
+            &mut ctx.accounts.treasury.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![CanPayMemberCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payroll_member.key(),
+                        is_writable: false,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.treasury.key(),
+                        is_writable: false,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "can_pay_member")]
+    pub fn can_pay_member_callback(
+        ctx: Context<CanPayMemberCallback>,
+        output: SignedComputationOutputs<CanPayMemberOutput>,
+    ) -> Result<()> {
+        let can_pay = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(CanPayMemberOutput { field_0 }) => field_0,
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.treasury.key(),
+                        ctx.accounts.treasury.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::CanPayMember,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        emit!(MemberPayable {
+            payroll_member: ctx.accounts.payroll_member.key(),
+            can_pay,
+        });
+
+        Ok(())
+    }
+
+    pub fn check_equal(ctx: Context<CheckEqual>, computation_offset: u64) -> Result<()> {
+        // Supply plus the unallocated pool == locked is the core 1:1 backing invariant;
+        // only the flag is revealed.
+        let cvct_mint = &ctx.accounts.cvct_mint;
+        require!(
+            cvct_mint.exchange_rate_num == cvct_mint.exchange_rate_den,
+            ErrorCode::InvalidExchangeRate
+        );
+        let vault = &ctx.accounts.vault;
+        let (backed_key, backed_offset, backed_nonce) =
+            vault.backed_supply_operand(&vault.key(), cvct_mint)?;
+        let args = ArgBuilder::new()
+            // Supply, summed with the unallocated pool once there is one.
+            .x25519_pubkey(cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(backed_nonce)
+            .account(backed_key, backed_offset, ENCRYPTED_U128_LEN)
+            // Total locked input from vault.
+            .x25519_pubkey(cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(vault.total_locked_nonce)
+            .account(vault.key(), Vault::TOTAL_LOCKED_OFFSET, ENCRYPTED_U128_LEN)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.cvct_mint.key(),
+            &mut ctx.accounts.cvct_mint.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![IsEqualCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.cvct_mint.key(),
+                        is_writable: false,
+                    },
+                    // No health report; the program ID stands in for each `None`.
+                    CallbackAccount {
+                        pubkey: crate::ID,
+                        is_writable: false,
+                    },
+                    CallbackAccount {
+                        pubkey: crate::ID,
+                        is_writable: false,
+                    },
+                    CallbackAccount {
+                        pubkey: crate::ID,
+                        is_writable: false,
+                    },
+                    CallbackAccount {
+                        pubkey: crate::ID,
+                        is_writable: false,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "is_equal")]
+    pub fn is_equal_callback(
+        ctx: Context<IsEqualCallback>,
+        output: SignedComputationOutputs<IsEqualOutput>,
+    ) -> Result<()> {
+        let equal = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(IsEqualOutput { field_0 }) => field_0,
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_mint.key(),
+                        ctx.accounts.cvct_mint.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::IsEqual,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let cvct_mint_key = ctx.accounts.cvct_mint.key();
+        // `check_equal` passes no vault; only `verify_system_health` wants a HealthReport.
+        let (Some(vault), Some(backing_token_account)) = (
+            ctx.accounts.vault.as_ref(),
+            ctx.accounts.backing_token_account.as_ref(),
+        ) else {
+            emit!(EqualityChecked {
+                cvct_mint: cvct_mint_key,
+                equal,
+            });
+            return Ok(());
+        };
+        let cvct_mint = &ctx.accounts.cvct_mint;
+
+        let (vault_pda, _) =
+            Pubkey::find_program_address(&[b"vault", cvct_mint_key.as_ref()], &crate::ID);
+        let vault_matches_mint = vault.key() == vault_pda
+            && vault.cvct_mint == cvct_mint_key
+            && vault.backing_mint == cvct_mint.backing_mint;
+        let backing_account_matches = backing_token_account.key() == vault.backing_token_account
+            && backing_token_account.mint == cvct_mint.backing_mint
+            && backing_token_account.owner == vault.key();
+        let org_treasury = ctx.accounts.org_treasury.as_ref();
+        let org_treasury_matches = ctx.accounts.org.as_ref().map(|org| {
+            org.cvct_mint == cvct_mint_key
+                && org_treasury.is_some_and(|treasury| {
+                    treasury.key() == org.cvct_treasury_vault
+                        && treasury.cvct_mint == cvct_mint_key
+                        && treasury.owner == org.key()
+                })
+        });
+
+        emit!(HealthReport {
+            cvct_mint: cvct_mint_key,
+            vault_matches_mint,
+            backing_account_matches,
+            org_treasury_matches,
+            supply_equals_locked: equal,
+        });
+
+        Ok(())
+    }
+
+    /// Sums the mint's supply and the vault's unallocated pool into `backed_supply`,
+    /// which `check_equal` compares against total locked once a pool exists.
+    pub fn refresh_backed_supply(
+        ctx: Context<RefreshBackedSupply>,
+        computation_offset: u64,
+        backed_supply_new_nonce: u128,
+    ) -> Result<()> {
+        require!(
+            backed_supply_new_nonce > ctx.accounts.vault.backed_supply_nonce,
+            ErrorCode::NonceReused
+        );
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        let cvct_mint = &ctx.accounts.cvct_mint;
+        let vault = &mut ctx.accounts.vault;
+        require!(
+            !vault.backed_supply_in_flight
+                || now.saturating_sub(vault.backed_supply_queued_at) >= stale_timeout,
+            ErrorCode::AccountUpdateInFlight
+        );
+        vault.backed_supply_in_flight = true;
+        vault.backed_supply_queued_at = now;
+        // Recorded now so a supply or pool change before the check makes the sum stale.
+        vault.backed_supply_sources = [cvct_mint.total_supply_nonce, vault.unallocated_nonce];
+
+        let args = ArgBuilder::new()
+            // Total supply input from mint.
+            .x25519_pubkey(cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(cvct_mint.total_supply_nonce)
+            .account(
+                cvct_mint.key(),
+                CvctMint::TOTAL_SUPPLY_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Collateral locked but not yet credited, which supply doesn't cover.
+            .x25519_pubkey(cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(vault.unallocated_nonce)
+            .account(vault.key(), Vault::UNALLOCATED_OFFSET, ENCRYPTED_U128_LEN)
+            .plaintext_bool(vault.unallocated_nonce != 0)
+            // Output context for the sum.
+            .x25519_pubkey(cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(backed_supply_new_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.cvct_mint.key(),
+            &mut ctx.accounts.cvct_mint.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![BackedSupplyCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.vault.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.cvct_mint.key(),
+                        is_writable: false,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "backed_supply")]
+    pub fn backed_supply_callback(
+        ctx: Context<BackedSupplyCallback>,
+        output: SignedComputationOutputs<BackedSupplyOutput>,
+    ) -> Result<()> {
+        let backed_supply = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(BackedSupplyOutput { field_0 }) => field_0,
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_mint.key(),
+                        ctx.accounts.cvct_mint.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::BackedSupply,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let vault = &mut ctx.accounts.vault;
+        vault.backed_supply = backed_supply.ciphertexts;
+        vault.backed_supply_nonce = backed_supply.nonce;
+        vault.backed_supply_in_flight = false;
+
+        Ok(())
+    }
+
+    pub fn set_mint_mode(ctx: Context<SetMintMode>, mode: MintMode) -> Result<()> {
+        ctx.accounts.cvct_mint.mode = mode;
+        Ok(())
+    }
+
+    pub fn set_computation_timeout(
+        ctx: Context<SetComputationTimeout>,
+        computation_timeout: i64,
+    ) -> Result<()> {
+        require!(computation_timeout > 0, ErrorCode::InvalidTimeout);
+        ctx.accounts.cvct_mint.computation_timeout = computation_timeout;
+        Ok(())
+    }
+
+    pub fn set_max_transfer(
+        ctx: Context<SetMaxTransfer>,
+        max_transfer: Option<u128>,
+    ) -> Result<()> {
+        ctx.accounts.cvct_mint.max_transfer = max_transfer;
+        Ok(())
+    }
+
+    pub fn set_reveal_threshold(
+        ctx: Context<SetRevealThreshold>,
+        reveal_threshold: u64,
+    ) -> Result<()> {
+        ctx.accounts.cvct_mint.reveal_threshold = reveal_threshold;
+        Ok(())
+    }
+
+    pub fn circuit_breaker(ctx: Context<CircuitBreaker>, computation_offset: u64) -> Result<()> {
+        // Authority-only: a deposit landing between queue and execution skews the comparison,
+        // so an open trigger would let anyone pause the mint.
+        let vault = &ctx.accounts.vault;
+        let args = ArgBuilder::new()
+            // Total locked input from vault.
+            .x25519_pubkey(ctx.accounts.cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(vault.total_locked_nonce)
+            .account(vault.key(), Vault::TOTAL_LOCKED_OFFSET, ENCRYPTED_U128_LEN)
+            // Plaintext backing balance held by the vault ATA.
+            .plaintext_u128(ctx.accounts.vault_token_account.amount as u128)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.cvct_mint.key(),
+            &mut ctx.accounts.cvct_mint.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![CircuitBreakerCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[CallbackAccount {
+                    pubkey: ctx.accounts.cvct_mint.key(),
+                    is_writable: true,
+                }],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "circuit_breaker")]
+    pub fn circuit_breaker_callback(
+        ctx: Context<CircuitBreakerCallback>,
+        output: SignedComputationOutputs<CircuitBreakerOutput>,
+    ) -> Result<()> {
+        let collateralized = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(CircuitBreakerOutput { field_0 }) => field_0,
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_mint.key(),
+                        ctx.accounts.cvct_mint.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::CircuitBreaker,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        if !collateralized {
+            let cvct_mint = &mut ctx.accounts.cvct_mint;
+            cvct_mint.mode = MintMode::Paused;
+            emit!(CircuitBreakerTripped {
+                cvct_mint: cvct_mint.key(),
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn close_pending_op(ctx: Context<ClosePendingOp>) -> Result<()> {
+        // Reclaims the rent of a marker the sweep already failed and refunded; an
+        // in-flight one would make its callback fail, so it has to be swept first.
+        require!(
+            ctx.accounts.pending_op.failed,
+            ErrorCode::ComputationNotFailed
+        );
+        Ok(())
+    }
+
+    /// Queues the supply + pool == locked reveal; structural checks are evaluated in the callback
+    /// against the same accounts so a single `HealthReport` carries every result.
+    ///
+    /// Pass the program ID for `org` and `org_treasury` to skip the org checks.
+    pub fn verify_system_health(
+        ctx: Context<VerifySystemHealth>,
+        computation_offset: u64,
+    ) -> Result<()> {
+        // Supply and locked are compared unit for unit, which only holds at 1:1.
+        let cvct_mint = &ctx.accounts.cvct_mint;
+        require!(
+            cvct_mint.exchange_rate_num == cvct_mint.exchange_rate_den,
+            ErrorCode::InvalidExchangeRate
+        );
+        // Same comparison as `check_equal`.
+        let vault = &ctx.accounts.vault;
+        let (backed_key, backed_offset, backed_nonce) =
+            vault.backed_supply_operand(&vault.key(), cvct_mint)?;
+        let args = ArgBuilder::new()
+            // Supply, summed with the unallocated pool once there is one.
+            .x25519_pubkey(cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(backed_nonce)
+            .account(backed_key, backed_offset, ENCRYPTED_U128_LEN)
+            // Total locked input from vault.
+            .x25519_pubkey(cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(vault.total_locked_nonce)
+            .account(vault.key(), Vault::TOTAL_LOCKED_OFFSET, ENCRYPTED_U128_LEN)
+            .build();
+
+        let org_key = ctx.accounts.org.as_ref().map_or(crate::ID, |org| org.key());
+        let org_treasury_key = ctx
+            .accounts
+            .org_treasury
+            .as_ref()
+            .map_or(crate::ID, |treasury| treasury.key());
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.cvct_mint.key(),
+            &mut ctx.accounts.cvct_mint.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![IsEqualCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.cvct_mint.key(),
+                        is_writable: false,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.vault.key(),
+                        is_writable: false,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.backing_token_account.key(),
+                        is_writable: false,
+                    },
+                    // Optional org and treasury; the program ID stands in for `None`.
+                    CallbackAccount {
+                        pubkey: org_key,
+                        is_writable: false,
+                    },
+                    CallbackAccount {
+                        pubkey: org_treasury_key,
+                        is_writable: false,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Pull-model run: debits the treasury like `run_payroll_for_member` but credits the
+    /// member's encrypted `accrued` instead of their wallet; the member later `claim_pay`s.
+    /// Deductions are withheld here, so what accrues is already net of them.
+    pub fn accrue_for_member(
+        ctx: Context<AccrueForMember>,
+        computation_offset: u64,
+        treasury_new_balance_nonce: u128,
+        accrued_new_nonce: u128,
+        org_new_disbursed_nonce: u128,
+        member_new_deductions_nonce: u128,
+    ) -> Result<()> {
+        require!(
+            treasury_new_balance_nonce > ctx.accounts.treasury.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            accrued_new_nonce > ctx.accounts.payroll_member.accrued_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            org_new_disbursed_nonce > ctx.accounts.org.total_disbursed_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            member_new_deductions_nonce > ctx.accounts.payroll_member.deductions_nonce,
+            ErrorCode::NonceReused
+        );
+
+        let capped = RunnerAllowance::check_runner(
+            &ctx.accounts.runner_allowance,
+            &ctx.accounts.org,
+            &ctx.accounts.payroll.admin,
+            &ctx.accounts.payer.key(),
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        payroll_member.ready_for_run(now, stale_timeout)?;
+        payroll_member.roll_cycle_interval(&ctx.accounts.payroll);
+        let (amount, paid_through, remainder) =
+            payroll_member.amount_owed(&ctx.accounts.payroll, now)?;
+
+        if amount == 0 {
+            emit!(ZeroPaymentSkipped {
+                payroll_member: payroll_member.key(),
+                last_paid: payroll_member.last_paid,
+            });
+            return Ok(());
+        }
+
+        // Accrued pay stays on the member account, so there is no wallet payment to claw.
+        payroll_member.begin_run(paid_through, remainder, 0, now);
+        ctx.accounts.payroll.last_run = now;
+        ctx.accounts.org.begin_disbursement(now, stale_timeout)?;
+        ctx.accounts.treasury.begin_update(now, stale_timeout)?;
+        // A claim in flight would overwrite this accrual's `accrued` when it lands.
+        require!(
+            ctx.accounts.member_wallet.is_idle(now, stale_timeout),
+            ErrorCode::AccountUpdateInFlight
+        );
+        if capped {
+            RunnerAllowance::charge_at(&ctx.accounts.runner_allowance, amount)?;
+            ctx.accounts.payroll_member.run_charge = amount;
+        }
+
+        let org = &ctx.accounts.org;
+        let treasury = &ctx.accounts.treasury;
+        let payroll_member = &ctx.accounts.payroll_member;
+        let member_enc_pubkey = ctx.accounts.member_wallet.owner_enc_pubkey;
+        let args = ArgBuilder::new()
+            // Treasury balance.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Locked portion of the treasury.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.locked_balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Org reserve the treasury must keep; ignored until one is set.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.min_reserve_nonce)
+            .account(
+                org.key(),
+                Organization::MIN_RESERVE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.min_reserve_nonce != 0)
+            // Plaintext amount owed.
+            .plaintext_u128(amount)
+            // Low-balance alert level (0 never triggers).
+            .plaintext_u128(org.treasury_low_threshold.unwrap_or(0))
+            // Output context for treasury.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury_new_balance_nonce)
+            // Member accrual, encrypted to the member's wallet key.
+            .x25519_pubkey(member_enc_pubkey)
+            .plaintext_u128(payroll_member.accrued_nonce)
+            .account(
+                payroll_member.key(),
+                PayrollMember::ACCRUED_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(payroll_member.accrued_nonce == 0)
+            // Output context for the accrual.
+            .x25519_pubkey(member_enc_pubkey)
+            .plaintext_u128(accrued_new_nonce)
+            // Org disbursed total.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.total_disbursed_nonce)
+            .account(
+                org.key(),
+                Organization::TOTAL_DISBURSED_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.total_disbursed_nonce == 0)
+            // Output context for the org total.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org_new_disbursed_nonce)
+            // Outstanding deductions; start from zero before the first one lands.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(payroll_member.deductions_nonce)
+            .account(
+                payroll_member.key(),
+                PayrollMember::DEDUCTIONS_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(payroll_member.deductions_nonce == 0)
+            // Output context for deductions.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(member_new_deductions_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.treasury.key(),
+            &mut ctx.accounts.treasury.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![AccruePayrollCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.treasury.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payroll_member.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.org.key(),
+                        is_writable: true,
+                    },
+                    // Refunded if the run moves nothing; the program ID stands in for `None`
+                    // when the runner wasn't charged.
+                    if capped {
+                        CallbackAccount {
+                            pubkey: ctx.accounts.runner_allowance.key(),
+                            is_writable: true,
+                        }
+                    } else {
+                        CallbackAccount {
+                            pubkey: crate::ID,
+                            is_writable: false,
+                        }
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "accrue_payroll")]
+    pub fn accrue_payroll_callback(
+        ctx: Context<AccruePayrollCallback>,
+        output: SignedComputationOutputs<AccruePayrollOutput>,
+    ) -> Result<()> {
+        let (treasury_balance, accrued, total_disbursed, deductions, ok, low) = match output
+            .verify_output(
+                &ctx.accounts.cluster_account,
+                &ctx.accounts.computation_account,
+            ) {
+            Ok(AccruePayrollOutput {
+                field_0:
+                    AccruePayrollOutputStruct0 {
+                        field_0: treasury_balance,
+                        field_1: accrued,
+                        field_2: total_disbursed,
+                        field_3: deductions,
+                        field_4: ok,
+                        field_5: low,
+                    },
+            }) => (
+                treasury_balance,
+                accrued,
+                total_disbursed,
+                deductions,
+                ok,
+                low,
+            ),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.treasury.key(),
+                        ctx.accounts.treasury.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::AccruePayroll,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.balance = treasury_balance.ciphertexts;
+        treasury.balance_nonce = treasury_balance.nonce;
+        treasury.update_in_flight = false;
+
+        let org = &mut ctx.accounts.org;
+        org.total_disbursed = total_disbursed.ciphertexts;
+        org.total_disbursed_nonce = total_disbursed.nonce;
+        org.disbursement_in_flight = false;
+
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        payroll_member.accrued = accrued.ciphertexts;
+        payroll_member.accrued_nonce = accrued.nonce;
+        payroll_member.deductions = deductions.ciphertexts;
+        payroll_member.deductions_nonce = deductions.nonce;
+        // Nothing accrued on `!ok`, so the periods are still owed and the runner is refunded.
+        let refund = if ok { 0 } else { payroll_member.run_charge };
+        payroll_member.finish_run(ok);
+        if let Some(allowance) = ctx.accounts.runner_allowance.as_mut() {
+            allowance.refund(refund);
+        }
+
+        emit!(PayrollRunCompleted {
+            payroll_member: payroll_member.key(),
+            paid: ok,
+        });
+
+        if !ok {
+            emit!(ZeroPaymentSkipped {
+                payroll_member: payroll_member.key(),
+                last_paid: payroll_member.last_paid,
+            });
+        }
+
+        if low {
+            emit!(TreasuryLow {
+                owner: treasury.owner,
+                treasury: treasury.key(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Member moves their whole accrual into their wallet, paying their own fees.
+    pub fn claim_pay(
+        ctx: Context<ClaimPay>,
+        computation_offset: u64,
+        accrued_new_nonce: u128,
+        member_new_balance_nonce: u128,
+    ) -> Result<()> {
+        // An accrual still in flight would overwrite the claimed `accrued` when it lands.
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        ctx.accounts
+            .payroll_member
+            .ready_for_run(now, stale_timeout)?;
+        ctx.accounts
+            .member_wallet
+            .begin_update(now, stale_timeout)?;
+
+        let payroll_member = &ctx.accounts.payroll_member;
+        let member_wallet = &ctx.accounts.member_wallet;
+        require!(payroll_member.accrued_nonce != 0, ErrorCode::ZeroAmount);
+        require!(
+            accrued_new_nonce > payroll_member.accrued_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            member_new_balance_nonce > member_wallet.balance_nonce,
+            ErrorCode::NonceReused
+        );
+
+        let args = ArgBuilder::new()
+            // Accrual.
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(payroll_member.accrued_nonce)
+            .account(
+                payroll_member.key(),
+                PayrollMember::ACCRUED_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Output context for the accrual.
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(accrued_new_nonce)
+            // Member balance.
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_wallet.balance_nonce)
+            .account(
+                member_wallet.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Output context for member.
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_new_balance_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.member_wallet.key(),
+            &mut ctx.accounts.member_wallet.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![ClaimAccruedCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payroll_member.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.member_wallet.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "claim_accrued")]
+    pub fn claim_accrued_callback(
+        ctx: Context<ClaimAccruedCallback>,
+        output: SignedComputationOutputs<ClaimAccruedOutput>,
+    ) -> Result<()> {
+        let (accrued, member_balance) = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(ClaimAccruedOutput {
+                field_0:
+                    ClaimAccruedOutputStruct0 {
+                        field_0: accrued,
+                        field_1: member_balance,
+                    },
+            }) => (accrued, member_balance),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.member_wallet.key(),
+                        ctx.accounts.member_wallet.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::ClaimAccrued,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        payroll_member.accrued = accrued.ciphertexts;
+        payroll_member.accrued_nonce = accrued.nonce;
+
+        let member_wallet = &mut ctx.accounts.member_wallet;
+        member_wallet.balance = member_balance.ciphertexts;
+        member_wallet.balance_nonce = member_balance.nonce;
+        member_wallet.update_in_flight = false;
+
+        Ok(())
+    }
+
+    /// Settles elapsed time at the old rate, then reprices from `now`.
+    ///
+    /// The settlement is queued through `run_payroll`; if the treasury can't cover it the
+    /// callback restores the old `last_paid` and rate, so fund the treasury before splitting.
+    pub fn split_member_rate(
+        ctx: Context<SplitMemberRate>,
+        computation_offset: u64,
+        new_rate: u64,
+        treasury_new_balance_nonce: u128,
+        member_new_balance_nonce: u128,
+        org_new_disbursed_nonce: u128,
+        member_new_deductions_nonce: u128,
+    ) -> Result<()> {
+        require!(
+            treasury_new_balance_nonce > ctx.accounts.treasury.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            member_new_balance_nonce > ctx.accounts.member_wallet.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            org_new_disbursed_nonce > ctx.accounts.org.total_disbursed_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            member_new_deductions_nonce > ctx.accounts.payroll_member.deductions_nonce,
+            ErrorCode::NonceReused
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        payroll_member.ready_for_run(now, stale_timeout)?;
+        payroll_member.roll_cycle_interval(&ctx.accounts.payroll);
+        // Priced before the rate changes so elapsed time keeps the old rate.
+        let (amount, remainder) = payroll_member.prorated_owed(&ctx.accounts.payroll, now)?;
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::SplitMemberRate,
+            ctx.accounts.admin.key(),
+        )?;
+
+        // No start time or nothing accrued: reprice only, keeping any fraction owed.
+        if amount == 0 {
+            payroll_member.rate = new_rate;
+            if payroll_member.paid_from() != 0 {
+                payroll_member.last_paid = now;
+                payroll_member.rounding_remainder = remainder;
+            }
+            return Ok(());
+        }
+
+        // The old rate is restored with `last_paid` if the settlement moves nothing.
+        payroll_member.begin_run(now, remainder, amount, now);
+        payroll_member.rate = new_rate;
+        ctx.accounts.payroll.last_run = now;
+        ctx.accounts.org.begin_disbursement(now, stale_timeout)?;
+        ctx.accounts.treasury.begin_update(now, stale_timeout)?;
+        ctx.accounts
+            .member_wallet
+            .begin_update(now, stale_timeout)?;
+
+        let org = &ctx.accounts.org;
+        let treasury = &ctx.accounts.treasury;
+        let member_wallet = &ctx.accounts.member_wallet;
+        let payroll_member = &ctx.accounts.payroll_member;
+        let args = ArgBuilder::new()
+            // Treasury balance.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Locked portion of the treasury.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.locked_balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Org reserve the treasury must keep; ignored until one is set.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.min_reserve_nonce)
+            .account(
+                org.key(),
+                Organization::MIN_RESERVE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.min_reserve_nonce != 0)
+            // Plaintext amount owed.
+            .plaintext_u128(amount)
+            // Low-balance alert level (0 never triggers).
+            .plaintext_u128(ctx.accounts.org.treasury_low_threshold.unwrap_or(0))
+            // Reveal treasury exhaustion only when the payroll opted into auto-pause.
+            .plaintext_bool(ctx.accounts.payroll.auto_pause)
+            .plaintext_u128(AUTO_PAUSE_EPSILON)
+            // Output context for treasury.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury_new_balance_nonce)
+            // Member balance.
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_wallet.balance_nonce)
+            .account(
+                member_wallet.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Output context for member.
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_new_balance_nonce)
+            // Org disbursed total; starts from zero before the first payment lands.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.total_disbursed_nonce)
+            .account(
+                org.key(),
+                Organization::TOTAL_DISBURSED_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.total_disbursed_nonce == 0)
+            // Output context for the org total.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org_new_disbursed_nonce)
+            // Outstanding deductions; start from zero before the first one lands.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(payroll_member.deductions_nonce)
+            .account(
+                payroll_member.key(),
+                PayrollMember::DEDUCTIONS_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(payroll_member.deductions_nonce == 0)
+            // Output context for deductions.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(member_new_deductions_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.treasury.key(),
+            &mut ctx.accounts.treasury.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RunPayrollCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.treasury.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.member_wallet.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payroll_member.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payroll.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.org.key(),
+                        is_writable: true,
+                    },
+                    // No runner allowance was charged; the program ID stands in for `None`.
+                    CallbackAccount {
+                        pubkey: crate::ID,
+                        is_writable: false,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Closes the shared Arcium signer PDA and returns its rent to the upgrade authority.
+    ///
+    /// The next queue instruction recreates it via `init_if_needed`. Intended for
+    /// decommissioned or ephemeral deployments, so it refuses while any computation is
+    /// pending: `remaining_accounts` holds every registry mint, in registry order, and each
+    /// must have no open `PendingOp`.
+    pub fn close_sign_pda<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseSignPda<'info>>,
+    ) -> Result<()> {
+        let mints = &ctx.accounts.mint_registry.mints;
+        require!(
+            ctx.remaining_accounts.len() == mints.len(),
+            ErrorCode::InvalidBatch
+        );
+        for (info, expected) in ctx.remaining_accounts.iter().zip(mints.iter()) {
+            require_keys_eq!(info.key(), *expected, ErrorCode::InvalidBatch);
+            let cvct_mint = Account::<CvctMint>::try_from(info)?;
+            require!(
+                cvct_mint.open_pending_ops == 0,
+                ErrorCode::ComputationsPending
+            );
+        }
+        Ok(())
+    }
+
+    /// Creates the member's `CvctAccount`, adds them to the payroll and seeds an optional
+    /// bonus from the treasury, all in one instruction and one computation, co-signed by
+    /// the member.
+    pub fn onboard_member(
+        ctx: Context<OnboardMember>,
+        computation_offset: u64,
+        member_enc_pubkey: [u8; 32],
+        member_nonce: u128,
+        member_locked_nonce: u128,
+        rate: u64,
+        bonus: u64,
+        treasury_new_balance_nonce: u128,
+    ) -> Result<()> {
+        require!(
+            treasury_new_balance_nonce > ctx.accounts.treasury.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        ctx.accounts.payroll.admit_members(1)?;
+
+        let member_key = ctx.accounts.member.key();
+        let cvct_account_key = ctx.accounts.cvct_account.key();
+        // Owned by the member, not the admin paying for it.
+        ctx.accounts.cvct_account.set_inner(CvctAccount {
+            version: ACCOUNT_VERSION,
+            owner: member_key,
+            cvct_mint: ctx.accounts.cvct_mint.key(),
+            owner_enc_pubkey: member_enc_pubkey,
+            balance: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            balance_nonce: 0,
+            locked_balance: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            locked_balance_nonce: 0,
+            unlock_at: 0,
+            open_snapshots: 0,
+            op_counter: 0,
+            update_in_flight: true,
+            update_queued_at: Clock::get()?.unix_timestamp,
+        });
+        ctx.accounts.payroll_member.set_inner(PayrollMember {
+            version: ACCOUNT_VERSION,
+            payroll: ctx.accounts.payroll.key(),
+            recipient: member_key,
+            cvct_wallet: cvct_account_key,
+            accrued: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            accrued_nonce: 0,
+            hours: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            hours_nonce: 0,
+            hours_enc_pubkey: [0u8; 32],
+            deductions: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            deductions_nonce: 0,
+            bonus_accrued: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            bonus_accrued_nonce: 0,
+            rate,
+            interval_override: None,
+            cycle_interval: ctx.accounts.payroll.interval,
+            interval_changed_at: 0,
+            last_paid: 0,
+            previous_last_paid: 0,
+            rounding_remainder: 0,
+            previous_rounding_remainder: 0,
+            previous_rate: 0,
+            clawable: 0,
+            last_payment_at: 0,
+            run_in_flight: false,
+            run_charge: 0,
+            op_counter: 0,
+            deduction_in_flight: false,
+            deduction_queued_at: 0,
+            created_at: Clock::get()?.unix_timestamp,
+            active: true,
+            bump: ctx.bumps.payroll_member,
+        });
+        // The bonus debit rewrites the treasury like a run does.
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        ctx.accounts.treasury.begin_update(now, stale_timeout)?;
+
+        let org = &ctx.accounts.org;
+        let treasury = &ctx.accounts.treasury;
+        let args = ArgBuilder::new()
+            // Output contexts for the new account.
+            .x25519_pubkey(member_enc_pubkey)
+            .plaintext_u128(member_nonce)
+            .x25519_pubkey(member_enc_pubkey)
+            .plaintext_u128(member_locked_nonce)
+            // Treasury balance.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Locked portion of the treasury.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.locked_balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Org reserve the treasury must keep; ignored until one is set.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.min_reserve_nonce)
+            .account(
+                org.key(),
+                Organization::MIN_RESERVE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.min_reserve_nonce != 0)
+            // Plaintext bonus (0 skips it).
+            .plaintext_u128(bonus as u128)
+            // Output context for treasury.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury_new_balance_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.cvct_account.key(),
+            &mut ctx.accounts.cvct_account.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![OnboardMemberCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: cvct_account_key,
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.treasury.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payroll_member.key(),
+                        is_writable: false,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "onboard_member")]
+    pub fn onboard_member_callback(
+        ctx: Context<OnboardMemberCallback>,
+        output: SignedComputationOutputs<OnboardMemberOutput>,
+    ) -> Result<()> {
+        let (balance, locked_balance, treasury_balance, bonus_paid) = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(OnboardMemberOutput {
+                field_0:
+                    OnboardMemberOutputStruct0 {
+                        field_0: balance,
+                        field_1: locked_balance,
+                        field_2: treasury_balance,
+                        field_3: bonus_paid,
+                    },
+            }) => (balance, locked_balance, treasury_balance, bonus_paid),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_account.key(),
+                        ctx.accounts.cvct_account.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::OnboardMember,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let cvct_account = &mut ctx.accounts.cvct_account;
+        cvct_account.balance = balance.ciphertexts;
+        cvct_account.balance_nonce = balance.nonce;
+        cvct_account.locked_balance = locked_balance.ciphertexts;
+        cvct_account.locked_balance_nonce = locked_balance.nonce;
+        cvct_account.update_in_flight = false;
+
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.balance = treasury_balance.ciphertexts;
+        treasury.balance_nonce = treasury_balance.nonce;
+        treasury.update_in_flight = false;
+
+        emit!(MemberOnboarded {
+            payroll_member: ctx.accounts.payroll_member.key(),
+            cvct_account: ctx.accounts.cvct_account.key(),
+            bonus_paid,
+        });
+
+        Ok(())
+    }
+
+    pub fn set_timekeeper(ctx: Context<SetTimekeeper>, timekeeper: Pubkey) -> Result<()> {
+        ctx.accounts.payroll.timekeeper = timekeeper;
+        log_authority_action(
+            ctx.accounts.payroll.org,
+            AuthorityActionKind::SetTimekeeper,
+            ctx.accounts.admin.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Records a member's hours for the current period, encrypted client-side by the
+    /// reporter for the MXE; replaces any earlier report until a run consumes it.
+    pub fn report_hours(
+        ctx: Context<ReportHours>,
+        hours: [u8; 32],
+        hours_nonce: u128,
+        reporter_enc_pubkey: [u8; 32],
+    ) -> Result<()> {
+        let reporter = ctx.accounts.reporter.key();
+        require!(
+            reporter == ctx.accounts.org.authority || reporter == ctx.accounts.payroll.timekeeper,
+            ErrorCode::Unauthorized
+        );
+        require!(hours_nonce != 0, ErrorCode::NonceReused);
+
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        payroll_member.hours = [hours; ENCRYPTED_U128_CIPHERTEXTS];
+        payroll_member.hours_nonce = hours_nonce;
+        payroll_member.hours_enc_pubkey = reporter_enc_pubkey;
+        Ok(())
+    }
+
+    /// Pays one period of reported hours at the member's hourly rate.
+    pub fn run_hourly_payroll_for_member(
+        ctx: Context<RunHourlyPayrollForMember>,
+        computation_offset: u64,
+        treasury_new_balance_nonce: u128,
+        member_new_balance_nonce: u128,
+        org_new_disbursed_nonce: u128,
+        member_new_deductions_nonce: u128,
+    ) -> Result<()> {
+        require!(
+            treasury_new_balance_nonce > ctx.accounts.treasury.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            member_new_balance_nonce > ctx.accounts.member_wallet.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            org_new_disbursed_nonce > ctx.accounts.org.total_disbursed_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            member_new_deductions_nonce > ctx.accounts.payroll_member.deductions_nonce,
+            ErrorCode::NonceReused
+        );
+
+        // The hourly amount only exists inside MPC, so there is nothing to charge a capped
+        // runner; they can't run hourly payrolls.
+        let capped = RunnerAllowance::check_runner(
+            &ctx.accounts.runner_allowance,
+            &ctx.accounts.org,
+            &ctx.accounts.payroll.admin,
+            &ctx.accounts.payer.key(),
+        )?;
+        require!(!capped, ErrorCode::RunnerLimitExceeded);
+
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        let payroll = &ctx.accounts.payroll;
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        require!(payroll_member.hours_nonce != 0, ErrorCode::ZeroAmount);
+        payroll_member.ready_for_run(now, stale_timeout)?;
+        payroll_member.roll_cycle_interval(payroll);
+        // Hours are reported per period, so a run settles exactly one.
+        let paid_through = match payroll_member.paid_from() {
+            0 => now,
+            paid_from => {
+                let (periods, _, paid_through) =
+                    payroll_member.advance_schedule(payroll, paid_from, now, 1)?;
+                require!(periods > 0, ErrorCode::PaymentNotDue);
+                paid_through
+            }
+        };
+
+        if payroll_member.rate == 0 {
+            emit!(ZeroPaymentSkipped {
+                payroll_member: payroll_member.key(),
+                last_paid: payroll_member.last_paid,
+            });
+            return Ok(());
+        }
+
+        // The hourly amount is only known inside MPC, so it can't be clawed back.
+        let remainder = payroll_member.rounding_remainder;
+        payroll_member.begin_run(paid_through, remainder, 0, now);
+        ctx.accounts.payroll.last_run = now;
+        ctx.accounts.org.begin_disbursement(now, stale_timeout)?;
+        ctx.accounts.treasury.begin_update(now, stale_timeout)?;
+        ctx.accounts
+            .member_wallet
+            .begin_update(now, stale_timeout)?;
+
+        let org = &ctx.accounts.org;
+        let treasury = &ctx.accounts.treasury;
+        let member_wallet = &ctx.accounts.member_wallet;
+        let payroll_member = &ctx.accounts.payroll_member;
+        let args = ArgBuilder::new()
+            // Treasury balance.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Locked portion of the treasury.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.locked_balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Org reserve the treasury must keep; ignored until one is set.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.min_reserve_nonce)
+            .account(
+                org.key(),
+                Organization::MIN_RESERVE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.min_reserve_nonce != 0)
+            // Plaintext hourly rate.
+            .plaintext_u128(payroll_member.rate as u128)
+            // Reported hours.
+            .x25519_pubkey(payroll_member.hours_enc_pubkey)
+            .plaintext_u128(payroll_member.hours_nonce)
+            .account(
+                payroll_member.key(),
+                PayrollMember::HOURS_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Low-balance alert level (0 never triggers).
+            .plaintext_u128(org.treasury_low_threshold.unwrap_or(0))
+            // Output context for treasury.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury_new_balance_nonce)
+            // Member balance.
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_wallet.balance_nonce)
+            .account(
+                member_wallet.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Output context for member.
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_new_balance_nonce)
+            // Org disbursed total.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.total_disbursed_nonce)
+            .account(
+                org.key(),
+                Organization::TOTAL_DISBURSED_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.total_disbursed_nonce == 0)
+            // Output context for the org total.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org_new_disbursed_nonce)
+            // Outstanding deductions; start from zero before the first one lands.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(payroll_member.deductions_nonce)
+            .account(
+                payroll_member.key(),
+                PayrollMember::DEDUCTIONS_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(payroll_member.deductions_nonce == 0)
+            // Output context for deductions.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(member_new_deductions_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.treasury.key(),
+            &mut ctx.accounts.treasury.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RunHourlyPayrollCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.treasury.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.member_wallet.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payroll_member.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.org.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "run_hourly_payroll")]
+    pub fn run_hourly_payroll_callback(
+        ctx: Context<RunHourlyPayrollCallback>,
+        output: SignedComputationOutputs<RunHourlyPayrollOutput>,
+    ) -> Result<()> {
+        let (treasury_balance, member_balance, total_disbursed, deductions, ok, low) = match output
+            .verify_output(
+                &ctx.accounts.cluster_account,
+                &ctx.accounts.computation_account,
+            ) {
+            Ok(RunHourlyPayrollOutput {
+                field_0:
+                    RunHourlyPayrollOutputStruct0 {
+                        field_0: treasury_balance,
+                        field_1: member_balance,
+                        field_2: total_disbursed,
+                        field_3: deductions,
+                        field_4: ok,
+                        field_5: low,
+                    },
+            }) => (
+                treasury_balance,
+                member_balance,
+                total_disbursed,
+                deductions,
+                ok,
+                low,
+            ),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.treasury.key(),
+                        ctx.accounts.treasury.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::RunHourlyPayroll,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.balance = treasury_balance.ciphertexts;
+        treasury.balance_nonce = treasury_balance.nonce;
+        treasury.update_in_flight = false;
+
+        let member_wallet = &mut ctx.accounts.member_wallet;
+        member_wallet.balance = member_balance.ciphertexts;
+        member_wallet.balance_nonce = member_balance.nonce;
+        member_wallet.update_in_flight = false;
+
+        let org = &mut ctx.accounts.org;
+        org.total_disbursed = total_disbursed.ciphertexts;
+        org.total_disbursed_nonce = total_disbursed.nonce;
+        org.disbursement_in_flight = false;
+
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        payroll_member.deductions = deductions.ciphertexts;
+        payroll_member.deductions_nonce = deductions.nonce;
+        payroll_member.finish_run(ok);
+        emit!(PayrollRunCompleted {
+            payroll_member: payroll_member.key(),
+            paid: ok,
+        });
+
+        if ok {
+            // Hours are consumed; the next period needs a fresh report.
+            payroll_member.hours_nonce = 0;
+        } else {
+            emit!(ZeroPaymentSkipped {
+                payroll_member: payroll_member.key(),
+                last_paid: payroll_member.last_paid,
+            });
+        }
+
+        if low {
+            emit!(TreasuryLow {
+                owner: treasury.owner,
+                treasury: treasury.key(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Deposits the signer's backing tokens but mints the CVCT to `beneficiary_cvct_account`,
+    /// re-encrypted only under the beneficiary's key.
+    pub fn deposit_for(
+        ctx: Context<DepositFor>,
+        computation_offset: u64,
+        amount: u64,
+        beneficiary_new_balance_nonce: u128,
+        mint_new_total_supply_nonce: u128,
+        vault_new_total_locked_nonce: u128,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::ZeroAmount);
+        let beneficiary = &ctx.accounts.beneficiary_cvct_account;
+        let cvct_mint = &ctx.accounts.cvct_mint;
+        let vault = &ctx.accounts.vault;
+        require!(
+            beneficiary_new_balance_nonce > beneficiary.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            mint_new_total_supply_nonce > cvct_mint.total_supply_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            vault_new_total_locked_nonce > vault.total_locked_nonce,
+            ErrorCode::NonceReused
+        );
+
+        let minted = cvct_mint.to_cvct(amount)?;
+        require!(minted > 0, ErrorCode::ZeroAmount);
+
+        transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.depositor_token_account.to_account_info(),
+                    to: ctx.accounts.vault_token_account.to_account_info(),
+                    authority: ctx.accounts.depositor.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        // Same circuit as deposit_and_mint; only the credited account differs.
+        let beneficiary = &ctx.accounts.beneficiary_cvct_account;
+        let cvct_mint = &ctx.accounts.cvct_mint;
+        let vault = &ctx.accounts.vault;
+        let args = ArgBuilder::new()
+            // Beneficiary balance.
+            .x25519_pubkey(beneficiary.owner_enc_pubkey)
+            .plaintext_u128(beneficiary.balance_nonce)
+            .account(
+                beneficiary.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Plaintext CVCT minted and backing locked.
+            .plaintext_u128(minted)
+            .plaintext_u128(amount as u128)
+            // Output context for the beneficiary, not the depositor.
+            .x25519_pubkey(beneficiary.owner_enc_pubkey)
+            .plaintext_u128(beneficiary_new_balance_nonce)
+            // Total supply input from mint.
+            .x25519_pubkey(cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(cvct_mint.total_supply_nonce)
+            .account(
+                cvct_mint.key(),
+                CvctMint::TOTAL_SUPPLY_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .x25519_pubkey(cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(mint_new_total_supply_nonce)
+            // Total locked input from vault.
+            .x25519_pubkey(cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(vault.total_locked_nonce)
+            .account(vault.key(), Vault::TOTAL_LOCKED_OFFSET, ENCRYPTED_U128_LEN)
+            .x25519_pubkey(cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(vault_new_total_locked_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        ctx.accounts
+            .beneficiary_cvct_account
+            .begin_update(now, stale_timeout)?;
+        consume_computation_offset(
+            &ctx.accounts.beneficiary_cvct_account.key(),
+            &mut ctx.accounts.beneficiary_cvct_account.op_counter,
+            computation_offset,
+        )?;
+
+        ctx.accounts.pending_op.set_inner(PendingOp {
+            version: ACCOUNT_VERSION,
+            computation_offset,
+            kind: ComputationKind::DepositAndMint,
+            initiator: ctx.accounts.depositor.key(),
+            rent_payer: ctx.accounts.depositor.key(),
+            created_at: Clock::get()?.unix_timestamp,
+            cvct_mint: ctx.accounts.cvct_mint.key(),
+            refund_amount: amount,
+            failed: false,
+        });
+        ctx.accounts.cvct_mint.open_pending_ops += 1;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![DepositAndMintCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.beneficiary_cvct_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.cvct_mint.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.vault.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.pending_op.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.depositor.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        emit!(ComputationQueued {
+            computation_offset,
+            kind: ComputationKind::DepositAndMint,
+            accounts: vec![
+                ctx.accounts.beneficiary_cvct_account.key(),
+                ctx.accounts.cvct_mint.key(),
+                ctx.accounts.vault.key(),
+            ],
+        });
+
+        Ok(())
+    }
+
+    /// Org-authority escape hatch: pays exactly `periods` intervals now, skipping the
+    /// `PaymentNotDue` check but not the treasury sufficiency check.
+    pub fn force_pay_member(
+        ctx: Context<ForcePayMember>,
+        computation_offset: u64,
+        periods: u64,
+        treasury_new_balance_nonce: u128,
+        member_new_balance_nonce: u128,
+        org_new_disbursed_nonce: u128,
+        member_new_deductions_nonce: u128,
+    ) -> Result<()> {
+        require!(periods > 0, ErrorCode::ZeroAmount);
+        require!(
+            treasury_new_balance_nonce > ctx.accounts.treasury.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            member_new_balance_nonce > ctx.accounts.member_wallet.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            org_new_disbursed_nonce > ctx.accounts.org.total_disbursed_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            member_new_deductions_nonce > ctx.accounts.payroll_member.deductions_nonce,
+            ErrorCode::NonceReused
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        let payroll = &ctx.accounts.payroll;
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        payroll_member.ready_for_run(now, stale_timeout)?;
+        payroll_member.roll_cycle_interval(payroll);
+        // Paid periods are consumed from the schedule, so a later run can't pay them again.
+        let base = match payroll_member.paid_from() {
+            0 => now,
+            paid_from => paid_from,
+        };
+        let (_, seconds, paid_through) =
+            payroll_member.advance_schedule(payroll, base, i64::MAX, periods)?;
+        let (amount, remainder) = payroll_member.amount_for_periods(payroll, periods, seconds)?;
+        require!(amount > 0, ErrorCode::ZeroAmount);
+        require!(
+            !ctx.accounts.org.requires_approval(amount),
+            ErrorCode::ApprovalRequired
+        );
+
+        payroll_member.begin_run(paid_through, remainder, amount, now);
+        ctx.accounts.payroll.last_run = now;
+        ctx.accounts.org.begin_disbursement(now, stale_timeout)?;
+        ctx.accounts.treasury.begin_update(now, stale_timeout)?;
+        ctx.accounts
+            .member_wallet
+            .begin_update(now, stale_timeout)?;
+
+        let org = &ctx.accounts.org;
+        let treasury = &ctx.accounts.treasury;
+        let member_wallet = &ctx.accounts.member_wallet;
+        let payroll_member = &ctx.accounts.payroll_member;
+        // Same inputs as run_payroll_for_member so the reserve and balance checks apply.
+        let args = ArgBuilder::new()
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.locked_balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.min_reserve_nonce)
+            .account(
+                org.key(),
+                Organization::MIN_RESERVE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.min_reserve_nonce != 0)
+            .plaintext_u128(amount)
+            .plaintext_u128(org.treasury_low_threshold.unwrap_or(0))
+            .plaintext_bool(ctx.accounts.payroll.auto_pause)
+            .plaintext_u128(AUTO_PAUSE_EPSILON)
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury_new_balance_nonce)
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_wallet.balance_nonce)
+            .account(
+                member_wallet.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_new_balance_nonce)
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.total_disbursed_nonce)
+            .account(
+                org.key(),
+                Organization::TOTAL_DISBURSED_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.total_disbursed_nonce == 0)
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org_new_disbursed_nonce)
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(payroll_member.deductions_nonce)
+            .account(
+                payroll_member.key(),
+                PayrollMember::DEDUCTIONS_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(payroll_member.deductions_nonce == 0)
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(member_new_deductions_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.treasury.key(),
+            &mut ctx.accounts.treasury.op_counter,
+            computation_offset,
+        )?;
+
+        // run_payroll_callback restores last_paid if the treasury couldn't cover it.
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RunPayrollCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.treasury.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.member_wallet.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payroll_member.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payroll.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.org.key(),
+                        is_writable: true,
+                    },
+                    // No runner allowance was charged; the program ID stands in for `None`.
+                    CallbackAccount {
+                        pubkey: crate::ID,
+                        is_writable: false,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        emit!(ForcedPayment {
+            payroll_member: ctx.accounts.payroll_member.key(),
+            authority: ctx.accounts.authority.key(),
+            periods,
+            amount,
+            paid_through,
+        });
+
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::ForcePayMember,
+            ctx.accounts.authority.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Creates or updates the disbursement cap for `runner`; `spent` is kept.
+    pub fn set_runner_allowance(
+        ctx: Context<SetRunnerAllowance>,
+        runner: Pubkey,
+        limit: u128,
+    ) -> Result<()> {
+        let allowance = &mut ctx.accounts.runner_allowance;
+        allowance.version = ACCOUNT_VERSION;
+        allowance.org = ctx.accounts.org.key();
+        allowance.runner = runner;
+        allowance.limit = limit;
+        allowance.bump = ctx.bumps.runner_allowance;
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::SetRunnerAllowance,
+            ctx.accounts.authority.key(),
+        )?;
+        Ok(())
+    }
+
+    pub fn reset_runner_allowance(ctx: Context<ResetRunnerAllowance>) -> Result<()> {
+        ctx.accounts.runner_allowance.spent = 0;
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::ResetRunnerAllowance,
+            ctx.accounts.authority.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Locks backing tokens without minting, growing the unallocated pool
+    /// (`total_locked - total_supply`) that `credit_from_vault` distributes confidentially.
+    pub fn lock_collateral(
+        ctx: Context<LockCollateral>,
+        computation_offset: u64,
+        amount: u64,
+        vault_new_total_locked_nonce: u128,
+        vault_new_unallocated_nonce: u128,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::ZeroAmount);
+        require!(
+            vault_new_total_locked_nonce > ctx.accounts.vault.total_locked_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            vault_new_unallocated_nonce > ctx.accounts.vault.unallocated_nonce,
+            ErrorCode::NonceReused
+        );
+
+        // The one public step: the pooled amount is visible, later credits are not.
+        transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.authority_token_account.to_account_info(),
+                    to: ctx.accounts.vault_token_account.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let cvct_mint = &ctx.accounts.cvct_mint;
+        let vault = &ctx.accounts.vault;
+        let args = ArgBuilder::new()
+            .x25519_pubkey(cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(vault.total_locked_nonce)
+            .account(vault.key(), Vault::TOTAL_LOCKED_OFFSET, ENCRYPTED_U128_LEN)
+            // Locked in backing units, the same scale deposit_and_mint adds.
+            .plaintext_u128(amount as u128)
+            .x25519_pubkey(cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(vault_new_total_locked_nonce)
+            // Supply and the pool, so the pool can start from what supply leaves over.
+            .x25519_pubkey(cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(cvct_mint.total_supply_nonce)
+            .account(
+                cvct_mint.key(),
+                CvctMint::TOTAL_SUPPLY_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .x25519_pubkey(cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(vault.unallocated_nonce)
+            .account(vault.key(), Vault::UNALLOCATED_OFFSET, ENCRYPTED_U128_LEN)
+            .plaintext_bool(vault.unallocated_nonce == 0)
+            .x25519_pubkey(cvct_mint.authority_enc_pubkey)
+            .plaintext_u128(vault_new_unallocated_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.cvct_mint.key(),
+            &mut ctx.accounts.cvct_mint.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![LockCollateralCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.vault.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.cvct_mint.key(),
+                        is_writable: false,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "lock_collateral")]
+    pub fn lock_collateral_callback(
+        ctx: Context<LockCollateralCallback>,
+        output: SignedComputationOutputs<LockCollateralOutput>,
+    ) -> Result<()> {
+        let (total_locked, unallocated) = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(LockCollateralOutput {
+                field_0:
+                    LockCollateralOutputStruct0 {
+                        field_0: total_locked,
+                        field_1: unallocated,
+                    },
+            }) => (total_locked, unallocated),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_mint.key(),
+                        ctx.accounts.cvct_mint.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::LockCollateral,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let vault = &mut ctx.accounts.vault;
+        vault.total_locked = total_locked.ciphertexts;
+        vault.total_locked_nonce = total_locked.nonce;
+        vault.unallocated = unallocated.ciphertexts;
+        vault.unallocated_nonce = unallocated.nonce;
+
+        Ok(())
+    }
+
+    /// Emits everything needed to decrypt `cvct_account`'s balance, so clients can
+    /// simulate this instead of hardcoding the account layout.
+    pub fn read_balance_handle(ctx: Context<ReadBalanceHandle>) -> Result<()> {
+        let cvct_account = &ctx.accounts.cvct_account;
+        emit!(BalanceHandle {
+            cvct_account: cvct_account.key(),
+            version: cvct_account.version,
+            ciphertexts: cvct_account.balance,
+            nonce: cvct_account.balance_nonce,
+            enc_pubkey: cvct_account.owner_enc_pubkey,
+        });
+        Ok(())
+    }
+
+    /// `transfer_cvct` that also reports whether funds moved via `TransferResult`.
+    /// Use `transfer_cvct` when even that bit shouldn't be published.
+    pub fn transfer_with_receipt(
+        ctx: Context<TransferWithReceipt>,
+        computation_offset: u64,
+        amount: u64,
+        allow_partial: bool,
+        from_new_balance_nonce: u128,
+        to_new_balance_nonce: u128,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::ZeroAmount);
+        ctx.accounts.cvct_mint.check_transfer_limit(amount)?;
+        require!(
+            ctx.accounts.cvct_mint.transfer_fee_bps == 0,
+            ErrorCode::TransferFeeRequired
+        );
+        require!(
+            from_new_balance_nonce > ctx.accounts.from_cvct_account.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            to_new_balance_nonce > ctx.accounts.to_cvct_account.balance_nonce,
+            ErrorCode::NonceReused
+        );
+
+        let from = &ctx.accounts.from_cvct_account;
+        let to = &ctx.accounts.to_cvct_account;
+        let args = ArgBuilder::new()
+            .x25519_pubkey(from.owner_enc_pubkey)
+            .plaintext_u128(from.balance_nonce)
+            .account(from.key(), CvctAccount::BALANCE_OFFSET, ENCRYPTED_U128_LEN)
+            .x25519_pubkey(from.owner_enc_pubkey)
+            .plaintext_u128(from.locked_balance_nonce)
+            .account(
+                from.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_u128(amount as u128)
+            .plaintext_bool(allow_partial)
+            .x25519_pubkey(from.owner_enc_pubkey)
+            .plaintext_u128(from_new_balance_nonce)
+            .x25519_pubkey(to.owner_enc_pubkey)
+            .plaintext_u128(to.balance_nonce)
+            .account(to.key(), CvctAccount::BALANCE_OFFSET, ENCRYPTED_U128_LEN)
+            .x25519_pubkey(to.owner_enc_pubkey)
+            .plaintext_u128(to_new_balance_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        ctx.accounts
+            .from_cvct_account
+            .begin_update(now, stale_timeout)?;
+        ctx.accounts
+            .to_cvct_account
+            .begin_update(now, stale_timeout)?;
+        consume_computation_offset(
+            &ctx.accounts.from_cvct_account.key(),
+            &mut ctx.accounts.from_cvct_account.op_counter,
+            computation_offset,
+        )?;
+
+        ctx.accounts.pending_op.set_inner(PendingOp {
+            version: ACCOUNT_VERSION,
+            computation_offset,
+            kind: ComputationKind::TransferWithReceipt,
+            initiator: ctx.accounts.user.key(),
+            rent_payer: ctx.accounts.fee_payer.key(),
+            created_at: Clock::get()?.unix_timestamp,
+            cvct_mint: ctx.accounts.cvct_mint.key(),
+            refund_amount: 0,
+            failed: false,
+        });
+        ctx.accounts.cvct_mint.open_pending_ops += 1;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![TransferWithReceiptCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.from_cvct_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.to_cvct_account.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.pending_op.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.fee_payer.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.cvct_mint.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        emit!(ComputationQueued {
+            computation_offset,
+            kind: ComputationKind::TransferWithReceipt,
+            accounts: vec![
+                ctx.accounts.from_cvct_account.key(),
+                ctx.accounts.to_cvct_account.key(),
+            ],
+        });
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "transfer_with_receipt")]
+    pub fn transfer_with_receipt_callback(
+        ctx: Context<TransferWithReceiptCallback>,
+        output: SignedComputationOutputs<TransferWithReceiptOutput>,
+    ) -> Result<()> {
+        let (from_balance, to_balance, moved) = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(TransferWithReceiptOutput {
+                field_0:
+                    TransferWithReceiptOutputStruct0 {
+                        field_0: from_balance,
+                        field_1: to_balance,
+                        field_2: moved,
+                    },
+            }) => (from_balance, to_balance, moved),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.from_cvct_account.key(),
+                        ctx.accounts.from_cvct_account.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::TransferWithReceipt,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let from_cvct_account = &mut ctx.accounts.from_cvct_account;
+        from_cvct_account.balance = from_balance.ciphertexts;
+        from_cvct_account.balance_nonce = from_balance.nonce;
+        from_cvct_account.update_in_flight = false;
+
+        let to_cvct_account = &mut ctx.accounts.to_cvct_account;
+        to_cvct_account.balance = to_balance.ciphertexts;
+        to_cvct_account.balance_nonce = to_balance.nonce;
+        to_cvct_account.update_in_flight = false;
+
+        emit!(TransferResult {
+            from_cvct_account: from_cvct_account.key(),
+            to_cvct_account: to_cvct_account.key(),
+            moved,
+        });
+
+        ctx.accounts.cvct_mint.pending_op_closed();
+
+        Ok(())
+    }
+
+    /// Creates a `PayrollMember` for each roster entry, up to `MEMBER_IMPORT_BATCH_SIZE`;
+    /// larger rosters are paginated by the client.
+    ///
+    /// `remaining_accounts` holds `[recipient_cvct_account, payroll_member]` per entry, in order.
+    pub fn import_members<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ImportMembers<'info>>,
+        members: Vec<MemberImport>,
+    ) -> Result<()> {
+        let count = members.len();
+        require!(
+            count > 0
+                && count <= MEMBER_IMPORT_BATCH_SIZE
+                && ctx.remaining_accounts.len() == 2 * count,
+            ErrorCode::InvalidBatch
+        );
+        ctx.accounts.payroll.admit_members(count as u32)?;
+
+        let payroll_key = ctx.accounts.payroll.key();
+        let org = &ctx.accounts.org;
+        let org_mint = org.cvct_mint;
+        let now = Clock::get()?.unix_timestamp;
+
+        for (member, accounts) in members.iter().zip(ctx.remaining_accounts.chunks(2)) {
+            let (wallet_info, member_info) = (&accounts[0], &accounts[1]);
+            // Same wallet checks add_payroll_member applies.
+            let wallet = Account::<CvctAccount>::try_from(wallet_info)?;
+            require_keys_eq!(wallet.owner, member.recipient, ErrorCode::Unauthorized);
+            require_keys_eq!(wallet.cvct_mint, org_mint, ErrorCode::InvalidBatch);
+            for key in [&member.recipient, wallet_info.key] {
+                require!(
+                    !ctx.accounts
+                        .payroll
+                        .is_internal_account(org, &payroll_key, key),
+                    ErrorCode::InvalidVault
+                );
+            }
+
+            let (expected, bump) = Pubkey::find_program_address(
+                &[
+                    b"payroll_member",
+                    payroll_key.as_ref(),
+                    member.recipient.as_ref(),
+                ],
+                &crate::ID,
+            );
+            require_keys_eq!(member_info.key(), expected, ErrorCode::InvalidBatch);
+            require!(member_info.data_is_empty(), ErrorCode::InvalidBatch);
+
+            create_program_account(
+                member_info,
+                &ctx.accounts.admin,
+                &ctx.accounts.system_program,
+                8 + PayrollMember::INIT_SPACE,
+                &[
+                    b"payroll_member",
+                    payroll_key.as_ref(),
+                    member.recipient.as_ref(),
+                    &[bump],
+                ],
+            )?;
+
+            let state = PayrollMember {
+                version: ACCOUNT_VERSION,
+                payroll: payroll_key,
+                recipient: member.recipient,
+                cvct_wallet: wallet_info.key(),
+                accrued: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+                accrued_nonce: 0,
+                hours: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+                hours_nonce: 0,
+                hours_enc_pubkey: [0u8; 32],
+                deductions: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+                deductions_nonce: 0,
+                bonus_accrued: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+                bonus_accrued_nonce: 0,
+                rate: member.rate,
+                interval_override: None,
+                cycle_interval: ctx.accounts.payroll.interval,
+                interval_changed_at: 0,
+                last_paid: 0,
+                previous_last_paid: 0,
+                rounding_remainder: 0,
+                previous_rounding_remainder: 0,
+                previous_rate: 0,
+                clawable: 0,
+                last_payment_at: 0,
+                run_in_flight: false,
+                run_charge: 0,
+                op_counter: 0,
+                deduction_in_flight: false,
+                deduction_queued_at: 0,
+                created_at: now,
+                active: member.active,
+                bump,
+            };
+            let mut data = member_info.try_borrow_mut_data()?;
+            state.try_serialize(&mut &mut data[..])?;
+        }
+
+        emit!(MembersImported {
+            payroll: payroll_key,
+            count: count as u32,
+        });
+        Ok(())
+    }
+
+    /// Fails a computation whose callback never landed within the mint's `stale_timeout`:
+    /// marks its `PendingOp` failed, so a late callback can no longer apply, and returns
+    /// any backing the op moved into the vault at queue time.
+    pub fn sweep_stale_computation(ctx: Context<SweepStaleComputation>) -> Result<()> {
+        let pending_op = &ctx.accounts.pending_op;
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now.saturating_sub(pending_op.created_at) >= ctx.accounts.cvct_mint.stale_timeout(),
+            ErrorCode::ComputationNotStale
+        );
+
+        let cvct_mint_key = ctx.accounts.cvct_mint.key();
+        let vault_seeds = &[
+            b"vault".as_ref(),
+            cvct_mint_key.as_ref(),
+            &[ctx.bumps.vault],
+        ];
+        let signer_seeds = &[&vault_seeds[..]];
+
+        let refunded = pending_op.refund_amount;
+        if refunded > 0 {
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.vault_token_account.to_account_info(),
+                        to: ctx.accounts.initiator_token_account.to_account_info(),
+                        authority: ctx.accounts.vault.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                refunded,
+            )?;
+        }
+
+        // An unwrapping burn lent the user's token account close authority to the vault.
+        let close_authority =
+            Option::<Pubkey>::from(ctx.accounts.initiator_token_account.close_authority);
+        if pending_op.kind == ComputationKind::BurnAndWithdraw
+            && close_authority == Some(ctx.accounts.vault.key())
+        {
+            set_authority(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    SetAuthority {
+                        current_authority: ctx.accounts.vault.to_account_info(),
+                        account_or_mint: ctx.accounts.initiator_token_account.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                spl_token::instruction::AuthorityType::CloseAccount,
+                None,
+            )?;
+        }
+
+        emit!(ComputationSwept {
+            computation_offset: pending_op.computation_offset,
+            kind: pending_op.kind,
+            refunded,
+        });
+
+        ctx.accounts.pending_op.failed = true;
+        ctx.accounts.cvct_mint.pending_op_closed();
+
+        Ok(())
+    }
+
+    /// Owner-only disclosure: re-encrypts the balance for `viewer_enc_pubkey` and emits
+    /// it as `BalanceRevealed`, without storing the copy or registering the viewer.
+    pub fn reveal_to(
+        ctx: Context<RevealTo>,
+        computation_offset: u64,
+        viewer_enc_pubkey: [u8; 32],
+        viewer_nonce: u128,
+    ) -> Result<()> {
+        let cvct_account = &ctx.accounts.cvct_account;
+        let args = ArgBuilder::new()
+            .x25519_pubkey(cvct_account.owner_enc_pubkey)
+            .plaintext_u128(cvct_account.balance_nonce)
+            .account(
+                cvct_account.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Output encryption context for the one-time viewer.
+            .x25519_pubkey(viewer_enc_pubkey)
+            .plaintext_u128(viewer_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.cvct_account.key(),
+            &mut ctx.accounts.cvct_account.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RevealToCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[CallbackAccount {
+                    pubkey: ctx.accounts.cvct_account.key(),
+                    is_writable: false,
+                }],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "reveal_to")]
+    pub fn reveal_to_callback(
+        ctx: Context<RevealToCallback>,
+        output: SignedComputationOutputs<RevealToOutput>,
+    ) -> Result<()> {
+        let balance = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(RevealToOutput { field_0 }) => field_0,
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.cvct_account.key(),
+                        ctx.accounts.cvct_account.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::RevealTo,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        emit!(BalanceRevealed {
+            cvct_account: ctx.accounts.cvct_account.key(),
+            ciphertexts: balance.ciphertexts,
+            nonce: balance.nonce,
+        });
+
+        Ok(())
+    }
+
+    /// Pays this member on their own cadence instead of `payroll.interval`; `None` clears it.
+    /// Cycles already under way finish on the old cadence (`interval_switch_at`), so the
+    /// change never re-buckets time that already elapsed.
+    pub fn set_member_interval(
+        ctx: Context<SetMemberInterval>,
+        interval_override: Option<i64>,
+    ) -> Result<()> {
+        require!(
+            interval_override.map_or(true, |interval| interval > 0
+                && interval <= MAX_MEMBER_INTERVAL),
+            ErrorCode::InvalidInterval
+        );
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        require!(!payroll_member.run_in_flight, ErrorCode::RunInFlight);
+        payroll_member.roll_cycle_interval(&ctx.accounts.payroll);
+        payroll_member.interval_override = interval_override;
+        payroll_member.interval_changed_at = Clock::get()?.unix_timestamp;
+        log_authority_action(
+            ctx.accounts.payroll.org,
+            AuthorityActionKind::SetMemberInterval,
+            ctx.accounts.admin.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Sets who must co-sign `close_payroll`. Once a threshold is in force, changing it
+    /// needs the same approvals, passed as signer `remaining_accounts`.
+    pub fn set_close_approvers(
+        ctx: Context<SetCloseApprovers>,
+        close_approvers: Vec<Pubkey>,
+        close_threshold: u8,
+    ) -> Result<()> {
+        let org = &mut ctx.accounts.org;
+        org.check_close_approvals(&ctx.accounts.authority.key(), ctx.remaining_accounts)?;
+
+        let mut sorted = close_approvers.clone();
+        sorted.sort();
+        sorted.dedup();
+        require!(
+            close_approvers.len() <= MAX_CLOSE_APPROVERS
+                && sorted.len() == close_approvers.len()
+                && close_threshold as usize <= close_approvers.len().max(1),
+            ErrorCode::InvalidCloseApprovers
+        );
+
+        org.close_approvers = close_approvers;
+        org.close_threshold = close_threshold;
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::SetCloseApprovers,
+            ctx.accounts.authority.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Emits whether `run_payroll_for_member` would pay the member right now, from the
+    /// same schedule math the run uses. Safe to simulate; touches no balances.
+    pub fn member_due_status(ctx: Context<ReadMemberDueStatus>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let payroll = &ctx.accounts.payroll;
+        let payroll_member = &ctx.accounts.payroll_member;
+        let (periods_owed, seconds, _) = payroll_member.periods_owed(payroll, now)?;
+        // A run that would pay nothing only emits `ZeroPaymentSkipped`, so it isn't due.
+        let amount = match payroll.rate_unit {
+            // Hours are encrypted, so only a zero rate is known to pay nothing.
+            RateUnit::PerReportedHour => payroll_member.rate as u128,
+            _ if periods_owed == 0 => 0,
+            _ => {
+                payroll_member
+                    .amount_for_periods(payroll, periods_owed, seconds)?
+                    .0
+            }
+        };
+        emit!(MemberDueStatus {
+            payroll_member: payroll_member.key(),
+            is_due: payroll.active
+                && payroll_member.active
+                && ctx.accounts.cvct_mint.mode == MintMode::Normal
+                && amount > 0,
+            periods_owed,
+            next_due_at: payroll_member.next_due_at(payroll, now),
+        });
+        Ok(())
+    }
+
+    /// Emits what a run would pay the member at the caller's `now`, from the same
+    /// schedule and rounding math the run uses. Read-only; meant for simulation.
+    pub fn quote_payment(ctx: Context<QuotePayment>, now: i64) -> Result<()> {
+        let payroll = &ctx.accounts.payroll;
+        let payroll_member = &ctx.accounts.payroll_member;
+        let (periods_owed, seconds, paid_through) = payroll_member.periods_owed(payroll, now)?;
+        let amount = if periods_owed == 0 {
+            0
+        } else {
+            let (amount, _) = payroll_member.amount_for_periods(payroll, periods_owed, seconds)?;
+            amount
+        };
+        emit!(PaymentQuote {
+            payroll_member: payroll_member.key(),
+            now,
+            periods_owed,
+            amount,
+            paid_through,
+        });
+        Ok(())
+    }
+
+    /// Adds a client-encrypted deduction to the member's outstanding total. Later runs
+    /// withhold it from what they pay, never below zero, carrying any rest forward.
+    pub fn apply_deduction(
+        ctx: Context<ApplyDeduction>,
+        computation_offset: u64,
+        amount_ciphertext: [u8; 32],
+        amount_enc_pubkey: [u8; 32],
+        amount_nonce: u128,
+        member_new_deductions_nonce: u128,
+    ) -> Result<()> {
+        require!(
+            member_new_deductions_nonce > ctx.accounts.payroll_member.deductions_nonce,
+            ErrorCode::NonceReused
+        );
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        ctx.accounts
+            .payroll_member
+            .begin_deduction(now, stale_timeout)?;
+
+        let org = &ctx.accounts.org;
+        let payroll_member = &ctx.accounts.payroll_member;
+        let args = ArgBuilder::new()
+            // Client-encrypted deduction amount.
+            .x25519_pubkey(amount_enc_pubkey)
+            .plaintext_u128(amount_nonce)
+            .encrypted_u128(amount_ciphertext)
+            // Outstanding deductions; start from zero before the first one lands.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(payroll_member.deductions_nonce)
+            .account(
+                payroll_member.key(),
+                PayrollMember::DEDUCTIONS_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(payroll_member.deductions_nonce == 0)
+            // Output context for deductions.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(member_new_deductions_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.payroll_member.key(),
+            &mut ctx.accounts.payroll_member.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![ApplyDeductionCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[CallbackAccount {
+                    pubkey: ctx.accounts.payroll_member.key(),
+                    is_writable: true,
+                }],
+            )?],
+            1,
+            0,
+        )?;
+
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::ApplyDeduction,
+            ctx.accounts.authority.key(),
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "apply_deduction")]
+    pub fn apply_deduction_callback(
+        ctx: Context<ApplyDeductionCallback>,
+        output: SignedComputationOutputs<ApplyDeductionOutput>,
+    ) -> Result<()> {
+        let deductions = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(ApplyDeductionOutput { field_0 }) => field_0,
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.payroll_member.key(),
+                        ctx.accounts.payroll_member.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::ApplyDeduction,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        payroll_member.deductions = deductions.ciphertexts;
+        payroll_member.deductions_nonce = deductions.nonce;
+        payroll_member.deduction_in_flight = false;
+
+        emit!(DeductionApplied {
+            payroll_member: payroll_member.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Re-points a member's payments at another of the recipient's `CvctAccount`s,
+    /// keeping their rate and pay history. Required after `migrate_org_mint`, since
+    /// runs only credit wallets on the org's current mint.
+    pub fn update_member_wallet(ctx: Context<UpdateMemberWallet>) -> Result<()> {
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        let old_wallet = payroll_member.cvct_wallet;
+        payroll_member.cvct_wallet = ctx.accounts.new_wallet.key();
+
+        emit!(MemberWalletUpdated {
+            payroll_member: payroll_member.key(),
+            old_wallet,
+            new_wallet: payroll_member.cvct_wallet,
+        });
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::UpdateMemberWallet,
+            ctx.accounts.authority.key(),
+        )?;
+        Ok(())
+    }
+
+    pub fn add_spender(ctx: Context<UpdateSpenders>, spender: Pubkey) -> Result<()> {
+        let org = &mut ctx.accounts.org;
+        require!(!org.is_spender(&spender), ErrorCode::InvalidSpender);
+        require!(
+            org.authorized_spenders.len() < MAX_AUTHORIZED_SPENDERS,
+            ErrorCode::SpenderLimitReached
+        );
+        org.authorized_spenders.push(spender);
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::AddSpender,
+            ctx.accounts.authority.key(),
+        )?;
+        Ok(())
+    }
+
+    pub fn remove_spender(ctx: Context<UpdateSpenders>, spender: Pubkey) -> Result<()> {
+        let org = &mut ctx.accounts.org;
+        let index = org
+            .authorized_spenders
+            .iter()
+            .position(|key| *key == spender)
+            .ok_or(ErrorCode::InvalidSpender)?;
+        org.authorized_spenders.swap_remove(index);
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::RemoveSpender,
+            ctx.accounts.authority.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Sets the org's encryption key on an org upgraded from layout version 1, which
+    /// predates the key; an org that already has one keeps it.
+    pub fn set_org_enc_pubkey(
+        ctx: Context<SetOrgEncPubkey>,
+        authority_enc_pubkey: [u8; 32],
+    ) -> Result<()> {
+        let org = &mut ctx.accounts.org;
+        require!(
+            org.authority_enc_pubkey == [0u8; 32],
+            ErrorCode::OrgEncPubkeyAlreadySet
+        );
+        org.authority_enc_pubkey = authority_enc_pubkey;
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::SetOrgEncPubkey,
+            ctx.accounts.authority.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Changes the payroll interval going forward. Each member finishes the cycle it is
+    /// in on the old interval and switches at its next boundary (`interval_switch_at`),
+    /// so a change never creates or removes periods that already elapsed.
+    pub fn update_payroll_interval(ctx: Context<UpdatePayrollStatus>, interval: i64) -> Result<()> {
+        require!(interval > 0, ErrorCode::InvalidInterval);
+        let payroll = &mut ctx.accounts.payroll;
+        payroll.interval = interval;
+        payroll.interval_changed_at = Clock::get()?.unix_timestamp;
+        log_authority_action(
+            ctx.accounts.payroll.org,
+            AuthorityActionKind::UpdatePayrollInterval,
+            ctx.accounts.admin.key(),
+        )?;
+        Ok(())
+    }
+
+    /// Attests that a member's rate lies in `[low, high]`. Rates are stored in plaintext,
+    /// so this is a plain comparison; it only gives compliance tooling an on-chain record.
+    pub fn prove_rate_in_band(ctx: Context<ProveRateInBand>, low: u64, high: u64) -> Result<()> {
+        require!(low <= high, ErrorCode::InvalidAmount);
+        let rate = ctx.accounts.payroll_member.rate;
+        emit!(RateInBand {
+            payroll_member: ctx.accounts.payroll_member.key(),
+            low,
+            high,
+            in_band: (low..=high).contains(&rate),
+        });
+        Ok(())
+    }
+
+    pub fn simulate_payroll_run(
+        ctx: Context<SimulatePayrollRun>,
+        computation_offset: u64,
+    ) -> Result<()> {
+        // Dry run of run_payroll_for_member: same date math and sufficiency test, but
+        // last_paid, balances and deductions stay untouched.
+        let now = Clock::get()?.unix_timestamp;
+        let payroll = &ctx.accounts.payroll;
+        let payroll_member = &ctx.accounts.payroll_member;
+        let (periods, seconds, _) = payroll_member.periods_owed(payroll, now)?;
+        require!(periods > 0, ErrorCode::PaymentNotDue);
+        let (amount, _) = payroll_member.amount_for_periods(payroll, periods, seconds)?;
+
+        let org = &ctx.accounts.org;
+        let treasury = &ctx.accounts.treasury;
+        let args = ArgBuilder::new()
+            // Treasury balance.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Locked portion of the treasury.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.locked_balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            // Org reserve the treasury must keep; ignored until one is set.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.min_reserve_nonce)
+            .account(
+                org.key(),
+                Organization::MIN_RESERVE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.min_reserve_nonce != 0)
+            // Plaintext amount and periods a run would pay right now.
+            .plaintext_u128(amount)
+            .plaintext_u128(periods as u128)
+            // Outstanding deductions; start from zero before the first one lands.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(payroll_member.deductions_nonce)
+            .account(
+                payroll_member.key(),
+                PayrollMember::DEDUCTIONS_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(payroll_member.deductions_nonce == 0)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.treasury.key(),
+            &mut ctx.accounts.treasury.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![SimulatePayrollRunCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payroll_member.key(),
+                        is_writable: false,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.treasury.key(),
+                        is_writable: false,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "simulate_payroll_run")]
+    pub fn simulate_payroll_run_callback(
+        ctx: Context<SimulatePayrollRunCallback>,
+        output: SignedComputationOutputs<SimulatePayrollRunOutput>,
+    ) -> Result<()> {
+        let (would_succeed, periods, amount) = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(SimulatePayrollRunOutput {
+                field_0:
+                    SimulatePayrollRunOutputStruct0 {
+                        field_0: would_succeed,
+                        field_1: periods,
+                        field_2: amount,
+                    },
+            }) => (would_succeed, periods, amount),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.treasury.key(),
+                        ctx.accounts.treasury.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::SimulatePayrollRun,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        emit!(PayrollSimulation {
+            payroll_member: ctx.accounts.payroll_member.key(),
+            periods_owed: periods as u64,
+            amount,
+            would_succeed,
+        });
+
+        Ok(())
+    }
+
+    /// Takes back up to the member's last payment from their wallet into the funding
+    /// treasury, within `payroll.clawback_window` of that payment. Locked funds are
+    /// never taken.
+    pub fn clawback(
+        ctx: Context<ClawbackPayment>,
+        computation_offset: u64,
+        amount: u64,
+        member_new_balance_nonce: u128,
+        treasury_new_balance_nonce: u128,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::ZeroAmount);
+        require!(
+            member_new_balance_nonce > ctx.accounts.member_wallet.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            treasury_new_balance_nonce > ctx.accounts.treasury.balance_nonce,
+            ErrorCode::NonceReused
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let window = ctx.accounts.payroll.clawback_window;
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        require!(
+            window > 0
+                && payroll_member.last_payment_at != 0
+                && now.saturating_sub(payroll_member.last_payment_at) <= window,
+            ErrorCode::ClawbackWindowClosed
+        );
+        // Reserved now so concurrent clawbacks can't exceed the payment; the callback
+        // gives it back if nothing moved.
+        payroll_member.clawable = payroll_member
+            .clawable
+            .checked_sub(amount as u128)
+            .ok_or(ErrorCode::ClawbackExceedsPayment)?;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        ctx.accounts.treasury.begin_update(now, stale_timeout)?;
+        ctx.accounts
+            .member_wallet
+            .begin_update(now, stale_timeout)?;
+
+        let member_wallet = &ctx.accounts.member_wallet;
+        let treasury = &ctx.accounts.treasury;
+        let args = ArgBuilder::new()
+            // Member balance and its locked portion.
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_wallet.balance_nonce)
+            .account(
+                member_wallet.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_wallet.locked_balance_nonce)
+            .account(
+                member_wallet.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_u128(amount as u128)
+            // Output context for the member.
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_new_balance_nonce)
+            // Treasury balance and its output context.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury_new_balance_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.member_wallet.key(),
+            &mut ctx.accounts.member_wallet.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![ClawbackCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.member_wallet.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.treasury.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payroll_member.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        emit!(ComputationQueued {
+            computation_offset,
+            kind: ComputationKind::Clawback,
+            accounts: vec![
+                ctx.accounts.member_wallet.key(),
+                ctx.accounts.treasury.key(),
+            ],
+        });
+
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::Clawback,
+            ctx.accounts.authority.key(),
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "clawback")]
+    pub fn clawback_callback(
+        ctx: Context<ClawbackCallback>,
+        output: SignedComputationOutputs<ClawbackOutput>,
+    ) -> Result<()> {
+        let (member_balance, treasury_balance, ok, amount) = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(ClawbackOutput {
+                field_0:
+                    ClawbackOutputStruct0 {
+                        field_0: member_balance,
+                        field_1: treasury_balance,
+                        field_2: ok,
+                        field_3: amount,
+                    },
+            }) => (member_balance, treasury_balance, ok, amount),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.member_wallet.key(),
+                        ctx.accounts.member_wallet.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::Clawback,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let member_wallet = &mut ctx.accounts.member_wallet;
+        member_wallet.balance = member_balance.ciphertexts;
+        member_wallet.balance_nonce = member_balance.nonce;
+        member_wallet.update_in_flight = false;
+
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.balance = treasury_balance.ciphertexts;
+        treasury.balance_nonce = treasury_balance.nonce;
+        treasury.update_in_flight = false;
+
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        if !ok {
+            // Nothing moved, so the reserved amount is clawable again.
+            payroll_member.clawable = payroll_member.clawable.saturating_add(amount);
+        }
+
+        emit!(PaymentClawedBack {
+            payroll_member: payroll_member.key(),
+            amount: amount as u64,
+            ok,
+        });
+
+        Ok(())
+    }
+
+    /// Prices what a run would pay the member now and records it for a second signer.
+    /// Only for amounts above the org's `approval_threshold`; the schedule does not move
+    /// until `approve_payment`.
+    pub fn propose_payment(ctx: Context<ProposePayment>) -> Result<()> {
+        let proposer = ctx.accounts.proposer.key();
+        require!(
+            proposer == ctx.accounts.payroll.admin || ctx.accounts.org.is_spender(&proposer),
+            ErrorCode::Unauthorized
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        payroll_member.roll_cycle_interval(&ctx.accounts.payroll);
+        let (amount, paid_through, remainder) =
+            payroll_member.amount_owed(&ctx.accounts.payroll, now)?;
+        require!(amount > 0, ErrorCode::ZeroAmount);
+        require!(
+            ctx.accounts.org.requires_approval(amount),
+            ErrorCode::ApprovalNotRequired
+        );
+
+        ctx.accounts.pending_payment.set_inner(PendingPayment {
+            version: ACCOUNT_VERSION,
+            payroll_member: payroll_member.key(),
+            proposer,
+            amount,
+            last_paid: payroll_member.last_paid,
+            paid_through,
+            rounding_remainder: remainder,
+            created_at: now,
+            bump: ctx.bumps.pending_payment,
+        });
+
+        emit!(PaymentProposed {
+            pending_payment: ctx.accounts.pending_payment.key(),
+            payroll_member: payroll_member.key(),
+            proposer,
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Pays a proposed payment through the regular run circuit. The approver must be
+    /// the org authority or an org spender other than the proposer.
+    pub fn approve_payment(
+        ctx: Context<ApprovePayment>,
+        computation_offset: u64,
+        treasury_new_balance_nonce: u128,
+        member_new_balance_nonce: u128,
+        org_new_disbursed_nonce: u128,
+        member_new_deductions_nonce: u128,
+    ) -> Result<()> {
+        require!(
+            treasury_new_balance_nonce > ctx.accounts.treasury.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            member_new_balance_nonce > ctx.accounts.member_wallet.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            org_new_disbursed_nonce > ctx.accounts.org.total_disbursed_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            member_new_deductions_nonce > ctx.accounts.payroll_member.deductions_nonce,
+            ErrorCode::NonceReused
+        );
+
+        let approver = ctx.accounts.approver.key();
+        require!(
+            ctx.accounts.org.is_spender(&approver),
+            ErrorCode::Unauthorized
+        );
+        let pending = &ctx.accounts.pending_payment;
+        require_keys_neq!(approver, pending.proposer, ErrorCode::ProposerCannotApprove);
+        let capped = RunnerAllowance::check_runner(
+            &ctx.accounts.runner_allowance,
+            &ctx.accounts.org,
+            &ctx.accounts.payroll.admin,
+            &approver,
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        let amount = pending.amount;
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        payroll_member.ready_for_run(now, stale_timeout)?;
+        require!(
+            payroll_member.last_paid == pending.last_paid,
+            ErrorCode::PaymentSuperseded
+        );
+        payroll_member.begin_run(
+            pending.paid_through,
+            pending.rounding_remainder,
+            amount,
+            now,
+        );
+        ctx.accounts.payroll.last_run = now;
+        ctx.accounts.org.begin_disbursement(now, stale_timeout)?;
+        ctx.accounts.treasury.begin_update(now, stale_timeout)?;
+        ctx.accounts
+            .member_wallet
+            .begin_update(now, stale_timeout)?;
+        if capped {
+            RunnerAllowance::charge_at(&ctx.accounts.runner_allowance, amount)?;
+            ctx.accounts.payroll_member.run_charge = amount;
+        }
+
+        let org = &ctx.accounts.org;
+        let treasury = &ctx.accounts.treasury;
+        let member_wallet = &ctx.accounts.member_wallet;
+        let payroll_member = &ctx.accounts.payroll_member;
+        // Same inputs as run_payroll_for_member so the reserve and balance checks apply.
+        let args = ArgBuilder::new()
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.locked_balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.min_reserve_nonce)
+            .account(
+                org.key(),
+                Organization::MIN_RESERVE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.min_reserve_nonce != 0)
+            .plaintext_u128(amount)
+            .plaintext_u128(org.treasury_low_threshold.unwrap_or(0))
+            .plaintext_bool(ctx.accounts.payroll.auto_pause)
+            .plaintext_u128(AUTO_PAUSE_EPSILON)
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury_new_balance_nonce)
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_wallet.balance_nonce)
+            .account(
+                member_wallet.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_new_balance_nonce)
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.total_disbursed_nonce)
+            .account(
+                org.key(),
+                Organization::TOTAL_DISBURSED_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.total_disbursed_nonce == 0)
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org_new_disbursed_nonce)
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(payroll_member.deductions_nonce)
+            .account(
+                payroll_member.key(),
+                PayrollMember::DEDUCTIONS_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(payroll_member.deductions_nonce == 0)
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(member_new_deductions_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.treasury.key(),
+            &mut ctx.accounts.treasury.op_counter,
+            computation_offset,
+        )?;
+
+        // run_payroll_callback restores last_paid if the treasury couldn't cover it.
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![RunPayrollCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.treasury.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.member_wallet.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payroll_member.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payroll.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.org.key(),
+                        is_writable: true,
+                    },
+                    // Refunded if the run moves nothing; the program ID stands in for `None`
+                    // when the runner wasn't charged.
+                    if capped {
+                        CallbackAccount {
+                            pubkey: ctx.accounts.runner_allowance.key(),
+                            is_writable: true,
+                        }
+                    } else {
+                        CallbackAccount {
+                            pubkey: crate::ID,
+                            is_writable: false,
+                        }
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        emit!(PaymentApproved {
+            pending_payment: ctx.accounts.pending_payment.key(),
+            payroll_member: ctx.accounts.payroll_member.key(),
+            approver,
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Drops a proposal without paying; the member's schedule was never moved.
+    pub fn cancel_payment(_ctx: Context<CancelPayment>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Adds a client-encrypted amount to the member's unclaimed bonus. Nothing leaves
+    /// the treasury until the member calls `claim_bonus`.
+    pub fn accrue_bonus(
+        ctx: Context<AccrueBonus>,
+        computation_offset: u64,
+        amount_ciphertext: [u8; 32],
+        amount_enc_pubkey: [u8; 32],
+        amount_nonce: u128,
+        bonus_new_nonce: u128,
+    ) -> Result<()> {
+        require!(
+            bonus_new_nonce > ctx.accounts.payroll_member.bonus_accrued_nonce,
+            ErrorCode::NonceReused
+        );
+
+        let org = &ctx.accounts.org;
+        let payroll_member = &ctx.accounts.payroll_member;
+        let args = ArgBuilder::new()
+            // Client-encrypted bonus amount.
+            .x25519_pubkey(amount_enc_pubkey)
+            .plaintext_u128(amount_nonce)
+            .encrypted_u128(amount_ciphertext)
+            // Unclaimed bonus; starts from zero before the first one lands.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(payroll_member.bonus_accrued_nonce)
+            .account(
+                payroll_member.key(),
+                PayrollMember::BONUS_ACCRUED_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(payroll_member.bonus_accrued_nonce == 0)
+            // Output context for the bonus.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(bonus_new_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.payroll_member.key(),
+            &mut ctx.accounts.payroll_member.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![AccrueBonusCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[CallbackAccount {
+                    pubkey: ctx.accounts.payroll_member.key(),
+                    is_writable: true,
+                }],
+            )?],
+            1,
+            0,
+        )?;
+
+        log_authority_action(
+            ctx.accounts.org.key(),
+            AuthorityActionKind::AccrueBonus,
+            ctx.accounts.authority.key(),
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "accrue_bonus")]
+    pub fn accrue_bonus_callback(
+        ctx: Context<AccrueBonusCallback>,
+        output: SignedComputationOutputs<AccrueBonusOutput>,
+    ) -> Result<()> {
+        let bonus = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(AccrueBonusOutput { field_0 }) => field_0,
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.payroll_member.key(),
+                        ctx.accounts.payroll_member.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::AccrueBonus,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        payroll_member.bonus_accrued = bonus.ciphertexts;
+        payroll_member.bonus_accrued_nonce = bonus.nonce;
+
+        emit!(BonusAccrued {
+            payroll_member: payroll_member.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Member pays out their whole unclaimed bonus from the funding treasury into their
+    /// wallet and zeroes it, paying their own fees. All or nothing: if the treasury
+    /// can't cover it above the reserve, the bonus stays accrued.
+    pub fn claim_bonus(
+        ctx: Context<ClaimBonus>,
+        computation_offset: u64,
+        treasury_new_balance_nonce: u128,
+        bonus_new_nonce: u128,
+        member_new_balance_nonce: u128,
+        org_new_disbursed_nonce: u128,
+    ) -> Result<()> {
+        let payroll_member = &ctx.accounts.payroll_member;
+        require!(
+            payroll_member.bonus_accrued_nonce != 0,
+            ErrorCode::ZeroAmount
+        );
+        require!(
+            treasury_new_balance_nonce > ctx.accounts.treasury.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            bonus_new_nonce > payroll_member.bonus_accrued_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            member_new_balance_nonce > ctx.accounts.member_wallet.balance_nonce,
+            ErrorCode::NonceReused
+        );
+        require!(
+            org_new_disbursed_nonce > ctx.accounts.org.total_disbursed_nonce,
+            ErrorCode::NonceReused
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let stale_timeout = ctx.accounts.cvct_mint.stale_timeout();
+        ctx.accounts.org.begin_disbursement(now, stale_timeout)?;
+        ctx.accounts.treasury.begin_update(now, stale_timeout)?;
+        ctx.accounts
+            .member_wallet
+            .begin_update(now, stale_timeout)?;
+
+        let org = &ctx.accounts.org;
+        let treasury = &ctx.accounts.treasury;
+        let member_wallet = &ctx.accounts.member_wallet;
+        let args = ArgBuilder::new()
+            // Treasury balance, its locked portion and the org reserve.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury.locked_balance_nonce)
+            .account(
+                treasury.key(),
+                CvctAccount::LOCKED_BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.min_reserve_nonce)
+            .account(
+                org.key(),
+                Organization::MIN_RESERVE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.min_reserve_nonce != 0)
+            // Output context for treasury.
+            .x25519_pubkey(treasury.owner_enc_pubkey)
+            .plaintext_u128(treasury_new_balance_nonce)
+            // Unclaimed bonus and its output context.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(payroll_member.bonus_accrued_nonce)
+            .account(
+                payroll_member.key(),
+                PayrollMember::BONUS_ACCRUED_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(bonus_new_nonce)
+            // Member balance and its output context.
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_wallet.balance_nonce)
+            .account(
+                member_wallet.key(),
+                CvctAccount::BALANCE_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .x25519_pubkey(member_wallet.owner_enc_pubkey)
+            .plaintext_u128(member_new_balance_nonce)
+            // Org disbursed total; starts from zero before the first payment lands.
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org.total_disbursed_nonce)
+            .account(
+                org.key(),
+                Organization::TOTAL_DISBURSED_OFFSET,
+                ENCRYPTED_U128_LEN,
+            )
+            .plaintext_bool(org.total_disbursed_nonce == 0)
+            .x25519_pubkey(org.authority_enc_pubkey)
+            .plaintext_u128(org_new_disbursed_nonce)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        consume_computation_offset(
+            &ctx.accounts.treasury.key(),
+            &mut ctx.accounts.treasury.op_counter,
+            computation_offset,
+        )?;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            None,
+            vec![ClaimBonusCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[
+                    CallbackAccount {
+                        pubkey: ctx.accounts.treasury.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.payroll_member.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.member_wallet.key(),
+                        is_writable: true,
+                    },
+                    CallbackAccount {
+                        pubkey: ctx.accounts.org.key(),
+                        is_writable: true,
+                    },
+                ],
+            )?],
+            1,
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "claim_bonus")]
+    pub fn claim_bonus_callback(
+        ctx: Context<ClaimBonusCallback>,
+        output: SignedComputationOutputs<ClaimBonusOutput>,
+    ) -> Result<()> {
+        let (treasury_balance, bonus, member_balance, total_disbursed, paid) = match output
+            .verify_output(
+                &ctx.accounts.cluster_account,
+                &ctx.accounts.computation_account,
+            ) {
+            Ok(ClaimBonusOutput {
+                field_0:
+                    ClaimBonusOutputStruct0 {
+                        field_0: treasury_balance,
+                        field_1: bonus,
+                        field_2: member_balance,
+                        field_3: total_disbursed,
+                        field_4: paid,
+                    },
+            }) => (
+                treasury_balance,
+                bonus,
+                member_balance,
+                total_disbursed,
+                paid,
+            ),
+            Err(_) => {
+                emit!(ComputationAborted {
+                    computation_offset: aborted_computation_offset(
+                        &ctx.accounts.treasury.key(),
+                        ctx.accounts.treasury.op_counter,
+                        &ctx.accounts.mxe_account,
+                        ctx.accounts.computation_account.key,
+                    )?,
+                    instruction_kind: ComputationKind::ClaimBonus,
+                });
+                return Err(ErrorCode::AbortedComputation.into());
+            }
+        };
+
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.balance = treasury_balance.ciphertexts;
+        treasury.balance_nonce = treasury_balance.nonce;
+        treasury.update_in_flight = false;
+
+        let payroll_member = &mut ctx.accounts.payroll_member;
+        payroll_member.bonus_accrued = bonus.ciphertexts;
+        payroll_member.bonus_accrued_nonce = bonus.nonce;
+
+        let member_wallet = &mut ctx.accounts.member_wallet;
+        member_wallet.balance = member_balance.ciphertexts;
+        member_wallet.balance_nonce = member_balance.nonce;
+        member_wallet.update_in_flight = false;
+
+        let org = &mut ctx.accounts.org;
+        org.total_disbursed = total_disbursed.ciphertexts;
+        org.total_disbursed_nonce = total_disbursed.nonce;
+        org.disbursement_in_flight = false;
+
+        emit!(BonusClaimed {
+            payroll_member: payroll_member.key(),
+            paid,
+        });
+
+        Ok(())
+    }
+}
+
+// Each encrypted-field offset plus the bytes stored after it must add up to the
+// account size, so adding or reordering a field fails the build until the offsets
+// handed to `ArgBuilder::account` are updated.
+const _: () = assert!(
+    CvctMint::TOTAL_SUPPLY_OFFSET as usize
+        + ENCRYPTED_U128_LEN as usize
+        + 16
+        + 1
+        + 2
+        + 32
+        + 1
+        + 8
+        + 8
+        + 8
+        + (1 + 16)
+        + 8
+        + 8
+        + 8
+        == 8 + CvctMint::INIT_SPACE
+);
+const _: () = assert!(
+    Vault::BACKED_SUPPLY_OFFSET as usize + ENCRYPTED_U128_LEN as usize + 16 + 32 + 1 + 8
+        == 8 + Vault::INIT_SPACE
+);
+const _: () = assert!(
+    CvctAccount::LOCKED_BALANCE_OFFSET as usize
+        + ENCRYPTED_U128_LEN as usize
+        + 16
+        + 8
+        + 2
+        + 8
+        + 1
+        + 8
+        == 8 + CvctAccount::INIT_SPACE
+);
+const _: () = assert!(
+    Organization::MIN_RESERVE_OFFSET as usize + ENCRYPTED_U128_LEN as usize + 16
+        == Organization::TOTAL_DISBURSED_OFFSET as usize
+);
+const _: () = assert!(
+    Organization::TOTAL_DISBURSED_OFFSET as usize
+        + ENCRYPTED_U128_LEN as usize
+        + 16
+        + 1
+        + 8
+        + 4
+        + (1 + 16)
+        + (1 + 16)
+        + 1
+        + (4 + 32 * MAX_CLOSE_APPROVERS)
+        + (4 + 32 * MAX_AUTHORIZED_SPENDERS)
+        + 1
+        == 8 + Organization::INIT_SPACE
+);
+const _: () = assert!(
+    PayrollMember::BONUS_ACCRUED_OFFSET as usize
+        + ENCRYPTED_U128_LEN as usize
+        + 16
+        + 8
+        + (1 + 8)
+        + 8
+        + 8
+        + 8
+        + 8
+        + 16
+        + 16
+        + 8
+        + 16
+        + 8
+        + 1
+        + 16
+        + 8
+        + 1
+        + 8
+        + 8
+        + 1
+        + 1
+        == 8 + PayrollMember::INIT_SPACE
+);
+
+#[account]
+#[derive(InitSpace)]
+pub struct CvctMint {
+    /// Layout version; see `migrate_account_size`.
+    pub version: u8,
+    pub authority: Pubkey,
+    pub backing_mint: Pubkey,
+    /// X25519 pubkey used to encrypt/decrypt mint totals off-chain.
+    pub authority_enc_pubkey: [u8; 32],
+    /// Encrypted total supply (1 ciphertext for u128).
+    pub total_supply: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+    /// Nonce used with the encrypted total supply.
+    pub total_supply_nonce: u128,
+    pub decimals: u8,
+    /// Fee charged on transfers, in basis points of the transferred amount.
+    pub transfer_fee_bps: u16,
+    /// CVCT account credited with transfer fees.
+    pub fee_collector: Pubkey,
+    /// Which value-moving instructions are allowed; see `MintMode`.
+    pub mode: MintMode,
+    /// CVCT base units minted per `exchange_rate_den` backing base units.
+    pub exchange_rate_num: u64,
+    pub exchange_rate_den: u64,
+    /// Withdrawals releasing more than this many backing base units emit
+    /// `LargeWithdrawal`; 0 disables it.
+    pub reveal_threshold: u64,
+    /// Largest amount a single transfer may move; `None` is unlimited.
+    pub max_transfer: Option<u128>,
+    /// Computations queued against this mint; see `computation_offset_for`.
+    pub op_counter: u64,
+    /// `PendingOp` markers for this mint still waiting on their callback; the callback
+    /// or the sweep counts them down. `close_sign_pda` requires 0 on every registered mint.
+    pub open_pending_ops: u64,
+    /// Seconds a computation may stay pending before `sweep_stale_computation` may fail
+    /// it and a stuck run or disbursement stops blocking; see `stale_timeout`.
+    pub computation_timeout: i64,
+}
+
+impl CvctMint {
+    /// Byte offset of `total_supply`, for `ArgBuilder::account`.
+    pub const TOTAL_SUPPLY_OFFSET: u32 = 8 + 1 + 32 + 32 + 32;
+
+    /// `computation_timeout`, or the default for mints sized before the setting existed.
+    pub fn stale_timeout(&self) -> i64 {
+        if self.computation_timeout > 0 {
+            self.computation_timeout
+        } else {
+            STALE_COMPUTATION_TIMEOUT
+        }
+    }
+
+    /// Counts down a settled `PendingOp`; markers queued before the count existed were
+    /// never counted, so it stops at zero.
+    pub fn pending_op_closed(&mut self) {
+        self.open_pending_ops = self.open_pending_ops.saturating_sub(1);
+    }
+
+    /// Rejects transfers above `max_transfer`.
+    pub fn check_transfer_limit(&self, amount: u64) -> Result<()> {
+        // Transfer amounts are plaintext instruction args, so the cap needs no MPC reveal.
+        if let Some(max_transfer) = self.max_transfer {
+            require!(
+                amount as u128 <= max_transfer,
+                ErrorCode::TransferLimitExceeded
+            );
+        }
+        Ok(())
+    }
+
+    /// The SPL payout is public anyway; large ones are surfaced for the issuer's compliance.
+    pub fn flag_large_withdrawal(&self, cvct_account: Pubkey, user: Pubkey, backing: u64) {
+        if self.reveal_threshold > 0 && backing > self.reveal_threshold {
+            emit!(LargeWithdrawal {
+                cvct_account,
+                user,
+                amount: backing,
+            });
+        }
+    }
+
+    /// CVCT minted for a backing deposit, rounded down.
+    pub fn to_cvct(&self, backing: u64) -> Result<u128> {
+        (backing as u128)
+            .checked_mul(self.exchange_rate_num as u128)
+            .ok_or(ErrorCode::InvalidAmount)?
+            .checked_div(self.exchange_rate_den as u128)
+            .ok_or_else(|| ErrorCode::InvalidExchangeRate.into())
+    }
+
+    /// Backing released for burned CVCT, rounded down so the vault never overpays: the
+    /// inverse of `to_cvct`, so a burn below one backing unit's worth releases nothing.
+    pub fn to_backing(&self, cvct: u64) -> Result<u128> {
+        (cvct as u128)
+            .checked_mul(self.exchange_rate_den as u128)
+            .ok_or(ErrorCode::InvalidAmount)?
+            .checked_div(self.exchange_rate_num as u128)
+            .ok_or_else(|| ErrorCode::InvalidExchangeRate.into())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Vault {
+    /// Layout version; see `migrate_account_size`.
+    pub version: u8,
+    pub cvct_mint: Pubkey,
+    pub backing_mint: Pubkey,
+    /// SPL token account holding backing assets.
+    pub backing_token_account: Pubkey,
+    /// Encrypted total locked in the vault (1 ciphertext for u128).
+    pub total_locked: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+    /// Nonce used with the encrypted total locked.
+    pub total_locked_nonce: u128,
+    /// Encrypted collateral locked by `lock_collateral` but not yet credited.
+    pub unallocated: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+    /// Nonce used with the encrypted unallocated pool; 0 until the pool is first written.
+    pub unallocated_nonce: u128,
+    /// Encrypted total supply plus the unallocated pool, summed by `refresh_backed_supply`
+    /// for `check_equal` to compare against `total_locked`.
+    pub backed_supply: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+    /// Nonce used with `backed_supply`; 0 until the first refresh lands.
+    pub backed_supply_nonce: u128,
+    /// `total_supply_nonce` and `unallocated_nonce` the last refresh read; the sum is
+    /// current only while both still match.
+    pub backed_supply_sources: [u128; 2],
+    /// Set while a refresh is in flight.
+    pub backed_supply_in_flight: bool,
+    /// Unix timestamp the in-flight refresh was queued.
+    pub backed_supply_queued_at: i64,
+}
 
 impl Vault {
-    pub const LEN: usize = 32 + 32 + 32 + (32 * ENCRYPTED_U128_CIPHERTEXTS) + 16;
+    /// Byte offset of `total_locked`, for `ArgBuilder::account`.
+    pub const TOTAL_LOCKED_OFFSET: u32 = 8 + 1 + 32 + 32 + 32;
+    /// Byte offset of `unallocated`, for `ArgBuilder::account`.
+    pub const UNALLOCATED_OFFSET: u32 = Self::TOTAL_LOCKED_OFFSET + ENCRYPTED_U128_LEN + 16;
+    /// Byte offset of `backed_supply`, for `ArgBuilder::account`.
+    pub const BACKED_SUPPLY_OFFSET: u32 = Self::UNALLOCATED_OFFSET + ENCRYPTED_U128_LEN + 16;
+
+    /// The encrypted value `is_equal` compares with `total_locked`, as (account, offset,
+    /// nonce): the mint's supply until a pool exists, then `backed_supply`, which must
+    /// still sum the current supply and pool.
+    pub fn backed_supply_operand(
+        &self,
+        vault_key: &Pubkey,
+        cvct_mint: &Account<CvctMint>,
+    ) -> Result<(Pubkey, u32, u128)> {
+        if self.unallocated_nonce == 0 {
+            return Ok((
+                cvct_mint.key(),
+                CvctMint::TOTAL_SUPPLY_OFFSET,
+                cvct_mint.total_supply_nonce,
+            ));
+        }
+        require!(
+            !self.backed_supply_in_flight
+                && self.backed_supply_nonce != 0
+                && self.backed_supply_sources
+                    == [cvct_mint.total_supply_nonce, self.unallocated_nonce],
+            ErrorCode::StaleBackedSupply
+        );
+        Ok((
+            *vault_key,
+            Self::BACKED_SUPPLY_OFFSET,
+            self.backed_supply_nonce,
+        ))
+    }
+
+    /// The vault's ATA for its backing mint, re-derived rather than trusted from storage.
+    pub fn derived_token_account(&self, vault_key: &Pubkey) -> Pubkey {
+        get_associated_token_address(vault_key, &self.backing_mint)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CvctAccount {
+    /// Layout version; see `migrate_account_size`.
+    pub version: u8,
+    pub owner: Pubkey,
+    pub cvct_mint: Pubkey,
+    /// X25519 pubkey used to encrypt/decrypt this account's balance.
+    pub owner_enc_pubkey: [u8; 32],
+    /// Encrypted balance (1 ciphertext for u128).
+    pub balance: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+    /// Nonce used with the encrypted balance.
+    pub balance_nonce: u128,
+    /// Encrypted portion of `balance` that cannot be spent until `unlock_at`.
+    pub locked_balance: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+    /// Nonce used with the encrypted locked balance.
+    pub locked_balance_nonce: u128,
+    /// Unix timestamp after which the locked portion can be released.
+    pub unlock_at: i64,
+    /// Number of `BalanceSnapshot`s currently open for this account.
+    pub open_snapshots: u16,
+    /// Computations queued against this account; see `computation_offset_for`.
+    pub op_counter: u64,
+    /// Set while a computation that rewrites this account is in flight, so the next one
+    /// reads the ciphertext the previous one wrote.
+    pub update_in_flight: bool,
+    /// Unix timestamp the in-flight computation was queued.
+    pub update_queued_at: i64,
+}
+
+impl CvctAccount {
+    /// Byte offset of `balance`, for `ArgBuilder::account`.
+    pub const BALANCE_OFFSET: u32 = 8 + 1 + 32 + 32 + 32;
+    /// Byte offset of `locked_balance`, for `ArgBuilder::account`.
+    pub const LOCKED_BALANCE_OFFSET: u32 = Self::BALANCE_OFFSET + ENCRYPTED_U128_LEN + 16;
+
+    /// Refuses a computation that would rewrite this account while another one is in
+    /// flight; one whose callback never landed within the mint's `stale_timeout` no
+    /// longer blocks.
+    pub fn begin_update(&mut self, now: i64, stale_timeout: i64) -> Result<()> {
+        require!(
+            self.is_idle(now, stale_timeout),
+            ErrorCode::AccountUpdateInFlight
+        );
+        self.update_in_flight = true;
+        self.update_queued_at = now;
+        Ok(())
+    }
+
+    /// Whether nothing that rewrites this account is in flight, counting a computation
+    /// stale after the mint's `stale_timeout`.
+    pub fn is_idle(&self, now: i64, stale_timeout: i64) -> bool {
+        !self.update_in_flight || now.saturating_sub(self.update_queued_at) >= stale_timeout
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct EmergencyWithdrawal {
+    /// Layout version; see `migrate_account_size`.
+    pub version: u8,
+    pub cvct_mint: Pubkey,
+    /// SPL token account that receives recovered backing tokens.
+    pub recovery_token_account: Pubkey,
+    pub amount: u64,
+    /// Unix timestamp of the proposal; execution unlocks after `EMERGENCY_WITHDRAW_DELAY`.
+    pub proposed_at: i64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BackingMintRecord {
+    /// Layout version; see `migrate_account_size`.
+    pub version: u8,
+    /// CVCT mint that wraps this backing mint.
+    pub cvct_mint: Pubkey,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct MintRegistry {
+    /// Layout version; see `migrate_account_size`.
+    pub version: u8,
+    /// Platform authority that approves mints; the upgrade authority at init.
+    pub authority: Pubkey,
+    /// CVCT mints orgs may be created against.
+    #[max_len(MAX_REGISTERED_MINTS)]
+    pub mints: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl MintRegistry {
+    pub fn is_approved(&self, cvct_mint: &Pubkey) -> bool {
+        self.mints.contains(cvct_mint)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BalanceSnapshot {
+    /// Layout version; see `migrate_account_size`.
+    pub version: u8,
+    pub cvct_account: Pubkey,
+    /// Reporting period this snapshot belongs to.
+    pub period_index: u64,
+    /// Balance re-encrypted under the mint authority's X25519 key.
+    pub balance: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+    /// Nonce used with the snapshot ciphertext.
+    pub balance_nonce: u128,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Organization {
+    /// Layout version; see `migrate_account_size`.
+    pub version: u8,
+    pub authority: Pubkey,
+    pub cvct_mint: Pubkey,
+    /// Org-owned `CvctAccount` that funds payroll; default until `init_org_treasury`.
+    pub cvct_treasury_vault: Pubkey,
+    /// X25519 pubkey the org's encrypted totals and reserve are encrypted to.
+    pub authority_enc_pubkey: [u8; 32],
+    /// Encrypted treasury floor payroll runs may not spend below, under `authority_enc_pubkey`.
+    pub min_reserve: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+    /// Nonce used with `min_reserve`; 0 means no reserve.
+    pub min_reserve_nonce: u128,
+    /// Encrypted lifetime total paid out by every payroll in the org, under
+    /// `authority_enc_pubkey`.
+    pub total_disbursed: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+    /// Nonce used with `total_disbursed`; 0 until the first payment lands.
+    pub total_disbursed_nonce: u128,
+    /// Set while a payment updating `total_disbursed` is in flight, so each one reads the
+    /// total the previous one wrote, whichever payroll it came from.
+    pub disbursement_in_flight: bool,
+    /// Unix timestamp the in-flight payment was queued.
+    pub disbursement_queued_at: i64,
+    /// Payrolls created under the org; never decreases.
+    pub payroll_count: u32,
+    /// Plaintext alert level; runs emit `TreasuryLow` once the treasury falls below it.
+    pub treasury_low_threshold: Option<u128>,
+    /// Payments above this many base units need `propose_payment` and a second
+    /// approver's `approve_payment`; `None` pays everything immediately.
+    pub approval_threshold: Option<u128>,
+    /// Distinct `close_approvers` signatures `close_payroll` needs; 0 or 1 keeps the
+    /// admin-only close.
+    pub close_threshold: u8,
+    #[max_len(MAX_CLOSE_APPROVERS)]
+    pub close_approvers: Vec<Pubkey>,
+    /// Keys besides the authority that may run payroll without a runner allowance.
+    #[max_len(MAX_AUTHORIZED_SPENDERS)]
+    pub authorized_spenders: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl Organization {
+    /// Whether a payment of `amount` must go through `propose_payment`.
+    pub fn requires_approval(&self, amount: u128) -> bool {
+        self.approval_threshold
+            .is_some_and(|threshold| amount > threshold)
+    }
+
+    /// Whether `key` is the authority or one of its `authorized_spenders`.
+    pub fn is_spender(&self, key: &Pubkey) -> bool {
+        *key == self.authority || self.authorized_spenders.contains(key)
+    }
+
+    /// Byte offset of `min_reserve`, for `ArgBuilder::account`.
+    pub const MIN_RESERVE_OFFSET: u32 = 8 + 1 + 32 + 32 + 32 + 32;
+
+    /// Byte offset of `total_disbursed`, for `ArgBuilder::account`.
+    pub const TOTAL_DISBURSED_OFFSET: u32 = 8 + 1 + 32 + 32 + 32 + 32 + 32 + 16;
+
+    /// Refuses a payment while another one's `total_disbursed` update is in flight; one
+    /// whose callback never landed within the mint's `stale_timeout` no longer blocks.
+    pub fn begin_disbursement(&mut self, now: i64, stale_timeout: i64) -> Result<()> {
+        require!(
+            !self.disbursement_in_flight
+                || now.saturating_sub(self.disbursement_queued_at) >= stale_timeout,
+            ErrorCode::DisbursementInFlight
+        );
+        self.disbursement_in_flight = true;
+        self.disbursement_queued_at = now;
+        Ok(())
+    }
+
+    /// Requires `close_threshold` distinct approvers to have signed, counting `admin`
+    /// and any signer in `signers`. No-op below a threshold of 2.
+    pub fn check_close_approvals(&self, admin: &Pubkey, signers: &[AccountInfo]) -> Result<()> {
+        if self.close_threshold <= 1 {
+            return Ok(());
+        }
+        // Approvers are de-duplicated on write, so this counts distinct keys.
+        let approvals = self
+            .close_approvers
+            .iter()
+            .filter(|approver| {
+                *approver == admin
+                    || signers
+                        .iter()
+                        .any(|info| info.is_signer && info.key == *approver)
+            })
+            .count();
+        require!(
+            approvals >= self.close_threshold as usize,
+            ErrorCode::CloseApprovalsMissing
+        );
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Payroll {
+    /// Layout version; see `migrate_account_size`.
+    pub version: u8,
+    pub org: Pubkey,
+    pub admin: Pubkey,
+    /// Seconds between payments.
+    pub interval: i64,
+    /// When `interval` last changed; members switch at their first cycle boundary after it.
+    pub interval_changed_at: i64,
+    /// Unit `PayrollMember.rate` is expressed in.
+    pub rate_unit: RateUnit,
+    /// Rounding applied to fractional per-day and pro-rata amounts.
+    pub rounding_mode: RoundingMode,
+    /// Upper bound on periods paid by a single run; `None` pays everything owed.
+    pub max_periods_per_run: Option<u32>,
+    /// Most members the payroll may hold, keeping batch runs within a transaction.
+    pub max_members: u32,
+    /// Members added so far; never decreases.
+    pub member_count: u32,
+    /// Unix timestamp of the last queued run for any member.
+    pub last_run: i64,
+    /// Seconds after a payment during which the org authority may claw it back; 0 disables.
+    pub clawback_window: i64,
+    /// Payroll-owned `CvctAccount` funding runs; default falls back to the org treasury.
+    pub treasury: Pubkey,
+    /// May `report_hours` alongside the org authority; default means none.
+    pub timekeeper: Pubkey,
+    /// Deactivate the payroll once a run can't be covered or leaves its treasury with
+    /// less than `AUTO_PAUSE_EPSILON` spendable.
+    pub auto_pause: bool,
+    pub active: bool,
+    pub bump: u8,
+}
+
+impl Payroll {
+    /// Counts `count` new members against `max_members`.
+    pub fn admit_members(&mut self, count: u32) -> Result<()> {
+        let member_count = self
+            .member_count
+            .checked_add(count)
+            .filter(|total| *total <= self.max_members)
+            .ok_or(ErrorCode::MemberLimitReached)?;
+        self.member_count = member_count;
+        Ok(())
+    }
+
+    /// Whether `key` is the org, this payroll (`payroll_key`) or one of their treasuries.
+    /// Member recipients and wallets may be none of these, or runs would pay the org.
+    pub fn is_internal_account(
+        &self,
+        org: &Organization,
+        payroll_key: &Pubkey,
+        key: &Pubkey,
+    ) -> bool {
+        *key == self.org
+            || key == payroll_key
+            || *key == org.cvct_treasury_vault
+            || *key == self.treasury
+    }
+
+    /// Treasury debited by runs of this payroll.
+    pub fn funding_treasury(&self, org: &Organization) -> Pubkey {
+        if self.treasury == Pubkey::default() {
+            org.cvct_treasury_vault
+        } else {
+            self.treasury
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PayrollMember {
+    /// Layout version; see `migrate_account_size`.
+    pub version: u8,
+    pub payroll: Pubkey,
+    pub recipient: Pubkey,
+    /// Recipient `CvctAccount` credited on each run.
+    pub cvct_wallet: Pubkey,
+    /// Encrypted pay accrued by `accrue_for_member` and not yet claimed.
+    pub accrued: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+    /// Nonce used with `accrued`; 0 until the first accrual lands.
+    pub accrued_nonce: u128,
+    /// Encrypted hours reported for the current period of an hourly payroll.
+    pub hours: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+    /// Nonce used with `hours`; 0 when nothing is reported.
+    pub hours_nonce: u128,
+    /// X25519 pubkey the reporter encrypted `hours` under.
+    pub hours_enc_pubkey: [u8; 32],
+    /// Encrypted deductions (for the org authority's key) withheld from future runs.
+    pub deductions: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+    /// Nonce used with `deductions`; 0 until the first deduction or run lands.
+    pub deductions_nonce: u128,
+    /// Encrypted bonus (for the org authority's key) granted by `accrue_bonus` and not
+    /// yet claimed; the treasury is only debited by `claim_bonus`.
+    pub bonus_accrued: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+    /// Nonce used with `bonus_accrued`; 0 until the first bonus lands.
+    pub bonus_accrued_nonce: u128,
+    /// Amount paid per interval, in base units.
+    pub rate: u64,
+    /// Member-specific pay interval used instead of `payroll.interval` when set.
+    pub interval_override: Option<i64>,
+    /// Interval the member's unpaid cycles started on; 0 for members that predate it.
+    pub cycle_interval: i64,
+    /// Unix timestamp of the last `set_member_interval`; 0 if the override never changed.
+    pub interval_changed_at: i64,
+    /// Unix timestamp of the last queued payment; 0 until the first run.
+    pub last_paid: i64,
+    /// `last_paid` before the in-flight run, restored if that run moves nothing.
+    pub previous_last_paid: i64,
+    /// Rounding carried into the next per-day payment, in base units × `SECONDS_PER_DAY`:
+    /// positive when underpaid so far, negative when overpaid.
+    pub rounding_remainder: i128,
+    /// `rounding_remainder` before the in-flight run, restored with `previous_last_paid`.
+    pub previous_rounding_remainder: i128,
+    /// `rate` before the in-flight run, restored with `previous_last_paid` so a failed
+    /// `split_member_rate` settlement leaves its periods owed at the old rate.
+    pub previous_rate: u64,
+    /// Gross amount of the last wallet payment still open to clawback.
+    pub clawable: u128,
+    /// Unix timestamp the last payment was queued; 0 until the first run.
+    pub last_payment_at: i64,
+    /// Set by `begin_run` and cleared by the run's callback; one run in flight at a time.
+    pub run_in_flight: bool,
+    /// Amount the in-flight run charged to its runner's `RunnerAllowance`, refunded by the
+    /// callback if the run moves nothing. A run that goes stale stays charged until a reset.
+    pub run_charge: u128,
+    /// Counter mixed into the computation offsets of queued member-only updates.
+    pub op_counter: u64,
+    /// Set by `apply_deduction` and cleared by its callback; runs wait for it, since
+    /// both rewrite `deductions` from the ciphertext read at queue time.
+    pub deduction_in_flight: bool,
+    /// Unix timestamp the in-flight deduction was queued.
+    pub deduction_queued_at: i64,
+    /// Unix timestamp the member was added; the first run pays from here.
+    pub created_at: i64,
+    pub active: bool,
+    pub bump: u8,
+}
+
+/// Emits the audit record for a privileged org or payroll action.
+pub fn log_authority_action(
+    org: Pubkey,
+    action_kind: AuthorityActionKind,
+    actor: Pubkey,
+) -> Result<()> {
+    emit!(AuthorityAction {
+        org,
+        action_kind,
+        actor,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Tops up rent from `payer` and reallocs a program account to `len`; new bytes read as zero.
+pub fn grow_program_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    len: usize,
+) -> Result<()> {
+    let shortfall = Rent::get()?
+        .minimum_balance(len)
+        .saturating_sub(account.lamports());
+    if shortfall > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    account.resize(len)?;
+    Ok(())
+}
+
+/// Creates the program-owned PDA `account` with `space` bytes the way Anchor's `init`
+/// does: lamports already sent to the address are topped up to rent exemption instead
+/// of making `create_account` fail, so pre-funding a PDA can't block its creation.
+pub fn create_program_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let lamports = account.lamports();
+    if lamports == 0 {
+        return anchor_lang::system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                anchor_lang::system_program::CreateAccount {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+                &[signer_seeds],
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let shortfall = rent.saturating_sub(lamports);
+    if shortfall > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            anchor_lang::system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            anchor_lang::system_program::Assign {
+                account_to_assign: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        &crate::ID,
+    )
+}
+
+/// `Organization` body as written at layout version 1, after the version byte.
+#[derive(AnchorDeserialize)]
+pub struct OrganizationV1 {
+    pub authority: Pubkey,
+    pub cvct_mint: Pubkey,
+    pub cvct_treasury_vault: Pubkey,
+    pub treasury_low_threshold: Option<u128>,
+    pub bump: u8,
+}
+
+/// `Payroll` body as written at layout version 1, after the version byte.
+#[derive(AnchorDeserialize)]
+pub struct PayrollV1 {
+    pub org: Pubkey,
+    pub admin: Pubkey,
+    pub interval: i64,
+    pub rate_unit: RateUnit,
+    pub max_periods_per_run: Option<u32>,
+    pub last_run: i64,
+    pub treasury: Pubkey,
+    pub active: bool,
+    pub bump: u8,
+}
+
+/// `PayrollMember` body as written at layout version 1, after the version byte.
+#[derive(AnchorDeserialize)]
+pub struct PayrollMemberV1 {
+    pub payroll: Pubkey,
+    pub recipient: Pubkey,
+    pub cvct_wallet: Pubkey,
+    pub rate: u64,
+    pub last_paid: i64,
+    pub previous_last_paid: i64,
+    pub active: bool,
+    pub bump: u8,
+}
+
+/// Rewrites a version-1 account in the current layout. The payroll accounts gained
+/// fields mid-struct, so they are decoded and re-encoded with the new fields empty;
+/// every other account only grew at the end and just needs the new version stamped.
+pub fn upgrade_v1_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    target: usize,
+) -> Result<()> {
+    let data = account.try_borrow_data()?;
+    let (discriminator, mut body) = (&data[..8], &data[9..]);
+    if discriminator == Organization::DISCRIMINATOR {
+        let old =
+            OrganizationV1::deserialize(&mut body).map_err(|_| ErrorCode::InvalidAccountSize)?;
+        drop(data);
+        // The org's encryption key didn't exist yet; `set_org_enc_pubkey` sets it once.
+        let org = Organization {
+            version: ACCOUNT_VERSION,
+            authority: old.authority,
+            cvct_mint: old.cvct_mint,
+            cvct_treasury_vault: old.cvct_treasury_vault,
+            authority_enc_pubkey: [0u8; 32],
+            min_reserve: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            min_reserve_nonce: 0,
+            total_disbursed: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            total_disbursed_nonce: 0,
+            disbursement_in_flight: false,
+            disbursement_queued_at: 0,
+            payroll_count: 0,
+            treasury_low_threshold: old.treasury_low_threshold,
+            approval_threshold: None,
+            close_threshold: 0,
+            close_approvers: Vec::new(),
+            authorized_spenders: Vec::new(),
+            bump: old.bump,
+        };
+        rewrite_program_account(account, payer, system_program, target, &org)
+    } else if discriminator == Payroll::DISCRIMINATOR {
+        let old = PayrollV1::deserialize(&mut body).map_err(|_| ErrorCode::InvalidAccountSize)?;
+        drop(data);
+        // Members admitted before the upgrade aren't counted against `max_members`.
+        let payroll = Payroll {
+            version: ACCOUNT_VERSION,
+            org: old.org,
+            admin: old.admin,
+            interval: old.interval,
+            interval_changed_at: 0,
+            rate_unit: old.rate_unit,
+            rounding_mode: RoundingMode::Floor,
+            max_periods_per_run: old.max_periods_per_run,
+            max_members: DEFAULT_MAX_MEMBERS,
+            member_count: 0,
+            last_run: old.last_run,
+            clawback_window: 0,
+            treasury: old.treasury,
+            timekeeper: Pubkey::default(),
+            auto_pause: false,
+            active: old.active,
+            bump: old.bump,
+        };
+        rewrite_program_account(account, payer, system_program, target, &payroll)
+    } else if discriminator == PayrollMember::DISCRIMINATOR {
+        let old =
+            PayrollMemberV1::deserialize(&mut body).map_err(|_| ErrorCode::InvalidAccountSize)?;
+        drop(data);
+        // `created_at` and `cycle_interval` stay 0, the markers for members that predate
+        // them: the first run keeps the old bootstrap and cycles use the current interval.
+        let payroll_member = PayrollMember {
+            version: ACCOUNT_VERSION,
+            payroll: old.payroll,
+            recipient: old.recipient,
+            cvct_wallet: old.cvct_wallet,
+            accrued: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            accrued_nonce: 0,
+            hours: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            hours_nonce: 0,
+            hours_enc_pubkey: [0u8; 32],
+            deductions: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            deductions_nonce: 0,
+            bonus_accrued: [[0u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+            bonus_accrued_nonce: 0,
+            rate: old.rate,
+            interval_override: None,
+            cycle_interval: 0,
+            interval_changed_at: 0,
+            last_paid: old.last_paid,
+            previous_last_paid: old.previous_last_paid,
+            rounding_remainder: 0,
+            previous_rounding_remainder: 0,
+            previous_rate: old.rate,
+            clawable: 0,
+            last_payment_at: 0,
+            run_in_flight: false,
+            run_charge: 0,
+            op_counter: 0,
+            deduction_in_flight: false,
+            deduction_queued_at: 0,
+            created_at: 0,
+            active: old.active,
+            bump: old.bump,
+        };
+        rewrite_program_account(account, payer, system_program, target, &payroll_member)
+    } else {
+        drop(data);
+        account.try_borrow_mut_data()?[8] = ACCOUNT_VERSION;
+        Ok(())
+    }
+}
+
+/// Grows `account` to `len` and overwrites it with `value`, discriminator included.
+pub fn rewrite_program_account<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    len: usize,
+    value: &T,
+) -> Result<()> {
+    if account.data_len() < len {
+        grow_program_account(account, payer, system_program, len)?;
+    }
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    value.try_serialize(&mut &mut data[..])
+}
+
+/// Narrows an encrypted or derived amount to the `u64` an SPL transfer takes,
+/// failing instead of truncating.
+pub fn spl_amount(amount: u128) -> Result<u64> {
+    u64::try_from(amount).map_err(|_| error!(ErrorCode::AmountTooLarge))
+}
+
+/// Deterministic `computation_offset` for the computation queued when `account`'s
+/// `op_counter` is `op_counter`: the first 8 bytes (little-endian) of
+/// `sha256("cvct_op" || account || op_counter_le)`. Clients read the counter, derive
+/// the offset and the computation PDA from it, and each queue instruction requires
+/// that offset and bumps the counter so the next derivation is fresh.
+pub fn computation_offset_for(account: &Pubkey, op_counter: u64) -> u64 {
+    let hash = hashv(&[b"cvct_op", account.as_ref(), &op_counter.to_le_bytes()]);
+    let mut offset = [0u8; 8];
+    offset.copy_from_slice(&hash.to_bytes()[..8]);
+    u64::from_le_bytes(offset)
+}
+
+/// Rejects any `computation_offset` but the one derived from `account`'s current
+/// counter, then bumps the counter for the next queue.
+pub fn consume_computation_offset(
+    account: &Pubkey,
+    op_counter: &mut u64,
+    computation_offset: u64,
+) -> Result<()> {
+    require_eq!(
+        computation_offset,
+        computation_offset_for(account, *op_counter),
+        ErrorCode::InvalidComputationOffset
+    );
+    *op_counter += 1;
+    Ok(())
+}
+
+/// Recovers the `computation_offset` behind `computation_account` from `account`'s
+/// last `ABORTED_OFFSET_LOOKBACK` counters; 0 when it was queued longer ago.
+pub fn aborted_computation_offset(
+    account: &Pubkey,
+    op_counter: u64,
+    mxe_account: &MXEAccount,
+    computation_account: &Pubkey,
+) -> Result<u64> {
+    for counter in (op_counter.saturating_sub(ABORTED_OFFSET_LOOKBACK)..op_counter).rev() {
+        let computation_offset = computation_offset_for(account, counter);
+        if derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+            == *computation_account
+        {
+            return Ok(computation_offset);
+        }
+    }
+    Ok(0)
+}
+
+/// Whole periods payable at `now`, capped at `max_periods` when set; 0 when nothing
+/// is payable yet.
+///
+/// Periods are `floor((now - last_paid) / interval)`: an elapsed time of exactly
+/// `interval` pays one period and `2 * interval - 1` still pays one. Callers advance
+/// `last_paid` by `periods * interval` (not to `now`), so the remainder carries into
+/// the next run and no fraction is dropped or paid twice across cycles.
+pub fn periods_elapsed(
+    last_paid: i64,
+    now: i64,
+    interval: i64,
+    max_periods: Option<u32>,
+) -> Result<u64> {
+    require!(interval > 0, ErrorCode::InvalidInterval);
+    // No start time at all (members added before `created_at`) pays a single period;
+    // otherwise pay every full interval elapsed.
+    let periods = if last_paid == 0 {
+        1
+    } else {
+        // A clock behind `last_paid` owes nothing rather than wrapping.
+        (now.saturating_sub(last_paid).max(0) / interval) as u64
+    };
+    Ok(match max_periods {
+        Some(cap) => periods.min(cap as u64),
+        None => periods,
+    })
+}
+
+impl PayrollMember {
+    /// Byte offset of `accrued`, for `ArgBuilder::account`.
+    pub const ACCRUED_OFFSET: u32 = 8 + 1 + 32 + 32 + 32;
+    /// Byte offset of `hours`, for `ArgBuilder::account`.
+    pub const HOURS_OFFSET: u32 = Self::ACCRUED_OFFSET + ENCRYPTED_U128_LEN + 16;
+    /// Byte offset of `deductions`, for `ArgBuilder::account`.
+    pub const DEDUCTIONS_OFFSET: u32 = Self::HOURS_OFFSET + ENCRYPTED_U128_LEN + 16 + 32;
+    /// Byte offset of `bonus_accrued`, for `ArgBuilder::account`.
+    pub const BONUS_ACCRUED_OFFSET: u32 = Self::DEDUCTIONS_OFFSET + ENCRYPTED_U128_LEN + 16;
+
+    /// Pay interval for this member: the override if set, else the payroll's.
+    pub fn interval(&self, payroll: &Payroll) -> i64 {
+        self.interval_override.unwrap_or(payroll.interval)
+    }
+
+    /// Start of the unpaid span: `last_paid`, or `created_at` before the first run.
+    /// 0 only for members added before `created_at` was recorded.
+    pub fn paid_from(&self) -> i64 {
+        if self.last_paid == 0 {
+            self.created_at
+        } else {
+            self.last_paid
+        }
+    }
+
+    /// Interval the unpaid cycles run on until `interval_switch_at`; members that
+    /// predate the snapshot run on the current interval.
+    pub fn cycle_interval(&self, payroll: &Payroll) -> i64 {
+        match self.cycle_interval {
+            0 => self.interval(payroll),
+            cycle_interval => cycle_interval,
+        }
+    }
+
+    /// First `cycle_interval` boundary after `paid_from` at or past the latest payroll or
+    /// member interval change. Cycles before it keep the old interval, so a change never
+    /// reprices time that already elapsed; `paid_from` itself when nothing is pending.
+    pub fn interval_switch_at(&self, payroll: &Payroll) -> i64 {
+        let old = self.cycle_interval(payroll);
+        let paid_from = self.paid_from();
+        let changed_at = payroll.interval_changed_at.max(self.interval_changed_at);
+        let pending = old != self.interval(payroll) && paid_from != 0 && changed_at > paid_from;
+        if !pending || old <= 0 {
+            return paid_from;
+        }
+        let cycles = (changed_at - paid_from + old - 1) / old;
+        paid_from.saturating_add(cycles.saturating_mul(old))
+    }
+
+    /// Moves `cycle_interval` to the current interval once no unpaid cycle predates the
+    /// switch. Run paths call this before pricing, so a refused run can't skip it.
+    pub fn roll_cycle_interval(&mut self, payroll: &Payroll) {
+        if self.paid_from() >= self.interval_switch_at(payroll) {
+            self.cycle_interval = self.interval(payroll);
+        }
+    }
+
+    /// Walks the schedule from `start` by up to `max_periods` whole periods that end by
+    /// `until`: old-interval cycles up to `interval_switch_at`, then current-interval
+    /// ones. Returns the periods, the seconds they cover and where they end.
+    pub fn advance_schedule(
+        &self,
+        payroll: &Payroll,
+        start: i64,
+        until: i64,
+        max_periods: u64,
+    ) -> Result<(u64, u128, i64)> {
+        let (old, new) = (self.cycle_interval(payroll), self.interval(payroll));
+        require!(old > 0 && new > 0, ErrorCode::InvalidInterval);
+        let switch_at = self.interval_switch_at(payroll).max(start);
+
+        let step = |from: i64, periods: u64, interval: i64| {
+            (periods as i64)
+                .checked_mul(interval)
+                .and_then(|elapsed| from.checked_add(elapsed))
+                .ok_or(ErrorCode::InvalidAmount)
+        };
+
+        let old_periods = periods_elapsed(start, until.min(switch_at), old, None)?.min(max_periods);
+        let mut paid_through = step(start, old_periods, old)?;
+        let mut new_periods = 0;
+        if paid_through >= switch_at {
+            new_periods =
+                periods_elapsed(paid_through, until, new, None)?.min(max_periods - old_periods);
+            paid_through = step(paid_through, new_periods, new)?;
+        }
+        let seconds = (old_periods as u128) * (old as u128) + (new_periods as u128) * (new as u128);
+        Ok((old_periods + new_periods, seconds, paid_through))
+    }
+
+    /// Whole periods payable at `now`, after `max_periods_per_run`, and the timestamp
+    /// they pay through. No start time at all pays a single period through `now`.
+    pub fn periods_owed(&self, payroll: &Payroll, now: i64) -> Result<(u64, u128, i64)> {
+        let cap = payroll.max_periods_per_run.map_or(u64::MAX, u64::from);
+        match self.paid_from() {
+            0 => {
+                let interval = self.interval(payroll);
+                require!(interval > 0, ErrorCode::InvalidInterval);
+                Ok((1, interval as u128, now))
+            }
+            paid_from => self.advance_schedule(payroll, paid_from, now, cap),
+        }
+    }
+
+    /// Earliest time a run pays anything: one cycle after `paid_from`, or
+    /// immediately when there is no start time.
+    pub fn next_due_at(&self, payroll: &Payroll, now: i64) -> i64 {
+        match self.paid_from() {
+            0 => now,
+            paid_from if paid_from < self.interval_switch_at(payroll) => {
+                paid_from.saturating_add(self.cycle_interval(payroll))
+            }
+            paid_from => paid_from.saturating_add(self.interval(payroll)),
+        }
+    }
+
+    /// Plaintext amount due at `now`, the timestamp it pays through and the rounding
+    /// remainder to carry afterwards.
+    ///
+    /// Shared by every run entry point and keeper pre-checks so the cap can't be bypassed.
+    pub fn amount_owed(&self, payroll: &Payroll, now: i64) -> Result<(u128, i64, i128)> {
+        let (periods, seconds, paid_through) = self.periods_owed(payroll, now)?;
+        require!(periods > 0, ErrorCode::PaymentNotDue);
+        // Capped runs only advance by what was paid, leaving the rest owed.
+        let (amount, remainder) = self.amount_for_periods(payroll, periods, seconds)?;
+        Ok((amount, paid_through, remainder))
+    }
+
+    /// Plaintext amount for `periods` whole intervals spanning `seconds`, at the current
+    /// rate, and the rounding remainder to carry afterwards.
+    pub fn amount_for_periods(
+        &self,
+        payroll: &Payroll,
+        periods: u64,
+        seconds: u128,
+    ) -> Result<(u128, i128)> {
+        let rate = self.rate as u128;
+        let amount = match payroll.rate_unit {
+            RateUnit::PerInterval => rate.checked_mul(periods as u128),
+            RateUnit::PerSecond => rate.checked_mul(seconds),
+            RateUnit::PerDay => {
+                let scaled = rate.checked_mul(seconds).ok_or(ErrorCode::InvalidAmount)?;
+                return self.round_carried(payroll, scaled);
+            }
+            // Hours are encrypted, so the amount only exists inside MPC.
+            RateUnit::PerReportedHour => return err!(ErrorCode::HourlyPayroll),
+        }
+        .ok_or(ErrorCode::InvalidAmount)?;
+        Ok((amount, self.rounding_remainder))
+    }
+
+    /// Rounds `scaled / SECONDS_PER_DAY` per the payroll's mode after adding the carried
+    /// remainder, so the total paid never drifts more than one unit from `rate × time`.
+    fn round_carried(&self, payroll: &Payroll, scaled: u128) -> Result<(u128, i128)> {
+        let day = SECONDS_PER_DAY as i128;
+        let total = i128::try_from(scaled)
+            .ok()
+            .and_then(|scaled| scaled.checked_add(self.rounding_remainder))
+            .ok_or(ErrorCode::InvalidAmount)?;
+        let amount = payroll.rounding_mode.div(total, day).max(0);
+        Ok((amount as u128, total - amount * day))
+    }
+
+    /// Advances the schedule at queue time, keeping what `revert_run` restores.
+    /// `clawable` is the amount the run credits to the wallet, 0 when it credits nothing
+    /// a clawback could take back.
+    pub fn begin_run(
+        &mut self,
+        paid_through: i64,
+        rounding_remainder: i128,
+        clawable: u128,
+        now: i64,
+    ) {
+        self.run_in_flight = true;
+        self.run_charge = 0;
+        self.previous_last_paid = self.last_paid;
+        self.previous_rounding_remainder = self.rounding_remainder;
+        self.previous_rate = self.rate;
+        self.last_paid = paid_through;
+        self.rounding_remainder = rounding_remainder;
+        self.clawable = clawable;
+        self.last_payment_at = now;
+    }
+
+    /// Refuses a run while another is in flight, since the single `previous_*` slot can
+    /// only undo one, or while a deduction is. A run whose callback never landed within
+    /// the mint's `stale_timeout` is reverted so its periods stay owed; a deduction that
+    /// stale is dropped.
+    pub fn ready_for_run(&mut self, now: i64, stale_timeout: i64) -> Result<()> {
+        if self.deduction_in_flight {
+            require!(
+                now.saturating_sub(self.deduction_queued_at) >= stale_timeout,
+                ErrorCode::DeductionInFlight
+            );
+            self.deduction_in_flight = false;
+        }
+        if self.run_in_flight {
+            require!(
+                now.saturating_sub(self.last_payment_at) >= stale_timeout,
+                ErrorCode::RunInFlight
+            );
+            self.run_in_flight = false;
+            self.revert_run();
+        }
+        Ok(())
+    }
+
+    /// Marks a deduction in flight, on the same terms as a run: nothing else that
+    /// rewrites `deductions` may be queued until its callback lands.
+    pub fn begin_deduction(&mut self, now: i64, stale_timeout: i64) -> Result<()> {
+        self.ready_for_run(now, stale_timeout)?;
+        self.deduction_in_flight = true;
+        self.deduction_queued_at = now;
+        Ok(())
+    }
+
+    /// Clears the in-flight marker from the run's callback, undoing the run when `!ok`.
+    pub fn finish_run(&mut self, ok: bool) {
+        self.run_in_flight = false;
+        if !ok {
+            self.revert_run();
+        }
+    }
+
+    /// Undoes `begin_run` for a run that moved nothing, so its periods stay owed and
+    /// nothing is open to clawback.
+    pub fn revert_run(&mut self) {
+        self.last_paid = self.previous_last_paid;
+        self.rounding_remainder = self.previous_rounding_remainder;
+        self.rate = self.previous_rate;
+        self.clawable = 0;
+    }
+
+    /// Pro-rata amount accrued since `paid_from` at the current rate, including any
+    /// partial period, and the rounding remainder to carry; 0 when there is no start time.
+    pub fn prorated_owed(&self, payroll: &Payroll, now: i64) -> Result<(u128, i128)> {
+        let (old, new) = (self.cycle_interval(payroll), self.interval(payroll));
+        require!(old > 0 && new > 0, ErrorCode::InvalidInterval);
+        let paid_from = self.paid_from();
+        if paid_from == 0 {
+            return Ok((0, self.rounding_remainder));
+        }
+        let elapsed = now.saturating_sub(paid_from).max(0) as u128;
+        // Time before the interval switch is priced per old cycle, the rest per new one.
+        let switch_at = self.interval_switch_at(payroll);
+        let old_elapsed = now.min(switch_at).saturating_sub(paid_from).max(0) as u128;
+        let rate = self.rate as i128;
+        let mode = payroll.rounding_mode;
+        let amount = match payroll.rate_unit {
+            // Partial cycles are rounded per the mode; there is no per-cycle remainder.
+            RateUnit::PerInterval => rate
+                .checked_mul(old_elapsed as i128)
+                .map(|scaled| mode.div(scaled, old as i128))
+                .zip(rate.checked_mul((elapsed - old_elapsed) as i128))
+                .and_then(|(old_part, scaled)| old_part.checked_add(mode.div(scaled, new as i128)))
+                .map(|amount| amount as u128),
+            RateUnit::PerSecond => (self.rate as u128).checked_mul(elapsed),
+            RateUnit::PerDay => {
+                let scaled = (self.rate as u128)
+                    .checked_mul(elapsed)
+                    .ok_or(ErrorCode::InvalidAmount)?;
+                return self.round_carried(payroll, scaled);
+            }
+            // Reported hours aren't time-based; any pending report is paid at the new rate.
+            RateUnit::PerReportedHour => Some(0),
+        }
+        .ok_or(ErrorCode::InvalidAmount)?;
+        Ok((amount, self.rounding_remainder))
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RunnerAllowance {
+    /// Layout version; see `migrate_account_size`.
+    pub version: u8,
+    pub org: Pubkey,
+    pub runner: Pubkey,
+    /// Most the runner may disburse between resets, in base units.
+    pub limit: u128,
+    /// Charged when a run is queued and refunded if it moves nothing; cleared by
+    /// `reset_runner_allowance`.
+    pub spent: u128,
+    pub bump: u8,
+}
+
+impl RunnerAllowance {
+    /// Charges `amount` against the allowance, failing if it would exceed the limit.
+    pub fn charge(&mut self, amount: u128) -> Result<()> {
+        let spent = self
+            .spent
+            .checked_add(amount)
+            .ok_or(ErrorCode::RunnerLimitExceeded)?;
+        require!(spent <= self.limit, ErrorCode::RunnerLimitExceeded);
+        self.spent = spent;
+        Ok(())
+    }
+
+    /// Gives back a charge whose run moved nothing; a reset in between may already have.
+    pub fn refund(&mut self, amount: u128) {
+        self.spent = self.spent.saturating_sub(amount);
+    }
+
+    /// Checks `runner` may queue payroll runs and returns whether they are capped. The
+    /// payroll admin and org authority run freely; anyone with an allowance at `info`
+    /// spends against it, and org spenders without one run freely.
+    pub fn check_runner(
+        info: &AccountInfo,
+        org: &Organization,
+        admin: &Pubkey,
+        runner: &Pubkey,
+    ) -> Result<bool> {
+        if runner == admin || *runner == org.authority {
+            return Ok(false);
+        }
+        if info.owner == &crate::ID {
+            return Ok(true);
+        }
+        require!(org.is_spender(runner), ErrorCode::Unauthorized);
+        Ok(false)
+    }
+
+    /// Charges `amount` to the allowance stored at `info`.
+    pub fn charge_at(info: &AccountInfo, amount: u128) -> Result<()> {
+        let mut data = info.try_borrow_mut_data()?;
+        let mut allowance = RunnerAllowance::try_deserialize(&mut &data[..])?;
+        allowance.charge(amount)?;
+        allowance.try_serialize(&mut &mut data[..])
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct DepositReceipt {
+    /// Layout version; see `migrate_account_size`.
+    pub version: u8,
+    pub user: Pubkey,
+    pub idempotency_key: [u8; 32],
+    /// Unix timestamp of the deposit that reserved this key.
+    pub created_at: i64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PendingOp {
+    /// Layout version; see `migrate_account_size`.
+    pub version: u8,
+    pub computation_offset: u64,
+    pub kind: ComputationKind,
+    /// Signer that authorized the operation.
+    pub initiator: Pubkey,
+    /// Refunded when the marker is closed.
+    pub rent_payer: Pubkey,
+    pub created_at: i64,
+    pub cvct_mint: Pubkey,
+    /// Backing tokens already moved into the vault at queue time, returned to the
+    /// initiator if the op is swept; 0 when nothing moved before the callback.
+    pub refund_amount: u64,
+    /// Set by `sweep_stale_computation`; a late callback is refused and the initiator
+    /// may then reclaim the rent with `close_pending_op`.
+    pub failed: bool,
+}
+
+/// A payment above the org's `approval_threshold`, waiting for a second signer.
+#[account]
+#[derive(InitSpace)]
+pub struct PendingPayment {
+    /// Layout version; see `migrate_account_size`.
+    pub version: u8,
+    pub payroll_member: Pubkey,
+    /// Signer that proposed the payment; gets the rent back when it closes.
+    pub proposer: Pubkey,
+    /// Amount the run would pay, priced when proposed.
+    pub amount: u128,
+    /// Member's `last_paid` when proposed; approval fails if a run moved it since.
+    pub last_paid: i64,
+    /// Schedule position and rounding the member moves to once approved.
+    pub paid_through: i64,
+    pub rounding_remainder: i128,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+#[queue_computation_accounts("init_mint_state", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct InitializeCvctMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_MINT_STATE))]
+    /// On-chain computation definition for `init_mint_state`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CvctMint::INIT_SPACE,
+        seeds = [b"cvct_mint", authority.key().as_ref()],
+        bump,
+    )]
+    /// CVCT mint metadata (encrypted totals updated by callback).
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Vault::INIT_SPACE,
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+    )]
+    /// Vault metadata (encrypted total locked updated by callback).
+    pub vault: Box<Account<'info, Vault>>,
+    /// SPL mint that backs CVCT.
+    pub backing_mint: Account<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + BackingMintRecord::INIT_SPACE,
+        seeds = [b"backing", backing_mint.key().as_ref()],
+        bump,
+    )]
+    /// Claims the backing mint so only one CVCT mint can wrap it.
+    pub backing_record: Box<Account<'info, BackingMintRecord>>,
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = backing_mint,
+        associated_token::authority = vault,
+    )]
+    /// ATA owned by vault PDA to hold backing SPL tokens.
+    pub vault_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[callback_accounts("init_mint_state")]
+#[derive(Accounts)]
+pub struct InitMintStateCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_MINT_STATE))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// CVCT mint to update encrypted total supply.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(mut)]
+    /// Vault to update encrypted total locked.
+    pub vault: Box<Account<'info, Vault>>,
+}
+
+#[queue_computation_accounts("init_account_state", fee_payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct InitializeCvctAccount<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(mut)]
+    /// Pays Arcium fees and rent; pass the signer itself when not sponsored.
+    pub fee_payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = fee_payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_ACCOUNT_STATE))]
+    /// On-chain computation definition for `init_account_state`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        init,
+        payer = fee_payer,
+        space = 8 + CvctAccount::INIT_SPACE,
+        seeds = [b"cvct_account", cvct_mint.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    /// CVCT account metadata (encrypted balance updated by callback).
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[callback_accounts("init_account_state")]
+#[derive(Accounts)]
+pub struct InitAccountStateCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_ACCOUNT_STATE))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// CVCT account to update encrypted balance and locked balance.
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+}
+
+#[queue_computation_accounts("deposit_and_mint", user)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, amount: u64, idempotency_key: [u8; 32])]
+pub struct DepositAndMint<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = user,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DEPOSIT_AND_MINT))]
+    /// On-chain computation definition for `deposit_and_mint`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = cvct_mint.authority == user.key() @ ErrorCode::Unauthorized,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+        constraint = vault.cvct_mint == cvct_mint.key() @ ErrorCode::InvalidVault,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(
+        mut,
+        seeds = [b"cvct_account", cvct_mint.key().as_ref(), user.key().as_ref()],
+        bump,
+        constraint = cvct_account.cvct_mint == cvct_mint.key(),
+        constraint = cvct_account.owner == user.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        constraint = user_token_account.mint == cvct_mint.backing_mint,
+        constraint = user_token_account.owner == user.key(),
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    #[account(
+        address = cvct_mint.backing_mint,
+        constraint = backing_mint.decimals == cvct_mint.decimals @ ErrorCode::DecimalsMismatch,
+    )]
+    /// Backing mint; decimals must match so amounts are credited 1:1.
+    pub backing_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = vault_token_account.key() == vault.backing_token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + DepositReceipt::INIT_SPACE,
+        seeds = [b"deposit", user.key().as_ref(), idempotency_key.as_ref()],
+        bump,
+    )]
+    /// Optional retry guard keyed by `idempotency_key`.
+    pub deposit_receipt: Option<Box<Account<'info, DepositReceipt>>>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + PendingOp::INIT_SPACE,
+        seeds = [b"pending_op", &computation_offset.to_le_bytes()],
+        bump,
+    )]
+    /// Pending-operation marker cleared by the callback.
+    pub pending_op: Box<Account<'info, PendingOp>>,
+}
+
+#[callback_accounts("deposit_and_mint")]
+#[derive(Accounts)]
+pub struct DepositAndMintCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DEPOSIT_AND_MINT))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// CVCT account to update encrypted balance.
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    /// CVCT mint to update encrypted total supply.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(mut)]
+    /// Vault to update encrypted total locked.
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = rent_payer,
+        constraint = !pending_op.failed @ ErrorCode::ComputationFailed,
+    )]
+    /// Closed once the result is applied; refused once the sweep failed it.
+    pub pending_op: Box<Account<'info, PendingOp>>,
+    #[account(mut)]
+    /// CHECK: refund target, matched against `pending_op.rent_payer`.
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+#[queue_computation_accounts("burn_and_withdraw", fee_payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct BurnAndWithdraw<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut)]
+    /// Pays Arcium fees and rent; pass the signer itself when not sponsored.
+    pub fee_payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = fee_payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_BURN_AND_WITHDRAW))]
+    /// On-chain computation definition for `burn_and_withdraw`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = cvct_mint.mode != MintMode::Paused @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+        constraint = vault.cvct_mint == cvct_mint.key() @ ErrorCode::InvalidVault,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(
+        mut,
+        seeds = [b"cvct_account", cvct_mint.key().as_ref(), user.key().as_ref()],
+        bump,
+        constraint = cvct_account.cvct_mint == cvct_mint.key(),
+        constraint = cvct_account.owner == user.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        constraint = user_token_account.mint == cvct_mint.backing_mint,
+        constraint = user_token_account.owner == user.key(),
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = recipient_token_account.mint == cvct_mint.backing_mint,
+    )]
+    /// Optional destination for the backing tokens; defaults to `user_token_account`.
+    pub recipient_token_account: Option<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        constraint = vault_token_account.key() == vault.backing_token_account,
+        constraint = vault_token_account.key() == vault.derived_token_account(&vault.key())
+            @ ErrorCode::InvalidVault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    #[account(
+        init,
+        payer = fee_payer,
+        space = 8 + PendingOp::INIT_SPACE,
+        seeds = [b"pending_op", &computation_offset.to_le_bytes()],
+        bump,
+    )]
+    /// Pending-operation marker cleared by the callback.
+    pub pending_op: Box<Account<'info, PendingOp>>,
+}
+
+#[callback_accounts("burn_and_withdraw")]
+#[derive(Accounts)]
+pub struct BurnAndWithdrawCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_BURN_AND_WITHDRAW))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// CVCT account to update encrypted balance.
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    /// CVCT mint to update encrypted total supply.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+    )]
+    /// Vault to update encrypted total locked and sign SPL transfer.
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(mut)]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    /// Receives the withdrawn backing tokens.
+    pub recipient_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    #[account(
+        mut,
+        constraint = unwrap_destination.key() == recipient_token_account.owner,
+    )]
+    /// CHECK: wallet credited with lamports when unwrapping wrapped SOL.
+    pub unwrap_destination: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = rent_payer,
+        constraint = !pending_op.failed @ ErrorCode::ComputationFailed,
+    )]
+    /// Closed once the result is applied; refused once the sweep failed it.
+    pub pending_op: Box<Account<'info, PendingOp>>,
+    #[account(mut)]
+    /// CHECK: refund target, matched against `pending_op.rent_payer`.
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+#[queue_computation_accounts("transfer_cvct", fee_payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct TransferCvct<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut)]
+    /// Pays Arcium fees and rent; pass the signer itself when not sponsored.
+    pub fee_payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = fee_payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TRANSFER_CVCT))]
+    /// On-chain computation definition for `transfer_cvct`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = from_cvct_account.owner == user.key() @ ErrorCode::Unauthorized,
+    )]
+    pub from_cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        constraint = to_cvct_account.cvct_mint == from_cvct_account.cvct_mint,
+        constraint = to_cvct_account.key() != from_cvct_account.key() @ ErrorCode::SelfTransfer,
+    )]
+    pub to_cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        constraint = cvct_mint.key() == from_cvct_account.cvct_mint,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        init,
+        payer = fee_payer,
+        space = 8 + PendingOp::INIT_SPACE,
+        seeds = [b"pending_op", &computation_offset.to_le_bytes()],
+        bump,
+    )]
+    /// Pending-operation marker cleared by the callback.
+    pub pending_op: Box<Account<'info, PendingOp>>,
+}
+
+#[callback_accounts("transfer_cvct")]
+#[derive(Accounts)]
+pub struct TransferCvctCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TRANSFER_CVCT))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// Sender CVCT account to update encrypted balance.
+    pub from_cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    /// Recipient CVCT account to update encrypted balance.
+    pub to_cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = rent_payer,
+        constraint = !pending_op.failed @ ErrorCode::ComputationFailed,
+    )]
+    /// Closed once the result is applied; refused once the sweep failed it.
+    pub pending_op: Box<Account<'info, PendingOp>>,
+    #[account(mut)]
+    /// CHECK: refund target, matched against `pending_op.rent_payer`.
+    pub rent_payer: UncheckedAccount<'info>,
+    #[account(mut, address = pending_op.cvct_mint)]
+    /// Mint whose open `PendingOp` count drops as the marker closes.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[queue_computation_accounts("check_collateral", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct AssertCollateralized<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CHECK_COLLATERAL))]
+    /// On-chain computation definition for `check_collateral`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    /// Only the mint authority may trigger the collateralization reveal.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+        constraint = vault.cvct_mint == cvct_mint.key() @ ErrorCode::InvalidVault,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(
+        constraint = vault_token_account.key() == vault.backing_token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+}
+
+#[callback_accounts("check_collateral")]
+#[derive(Accounts)]
+pub struct CheckCollateralCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CHECK_COLLATERAL))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    /// Counter account the computation's offset was derived from.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[queue_computation_accounts("withdraw_all", fee_payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct WithdrawAll<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut)]
+    /// Pays Arcium fees and rent; pass the signer itself when not sponsored.
+    pub fee_payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = fee_payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_WITHDRAW_ALL))]
+    /// On-chain computation definition for `withdraw_all`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = cvct_mint.mode != MintMode::Paused @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+        constraint = vault.cvct_mint == cvct_mint.key() @ ErrorCode::InvalidVault,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(
+        mut,
+        seeds = [b"cvct_account", cvct_mint.key().as_ref(), user.key().as_ref()],
+        bump,
+        constraint = cvct_account.cvct_mint == cvct_mint.key(),
+        constraint = cvct_account.owner == user.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        constraint = user_token_account.mint == cvct_mint.backing_mint,
+        constraint = user_token_account.owner == user.key(),
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = vault_token_account.key() == vault.backing_token_account,
+        constraint = vault_token_account.key() == vault.derived_token_account(&vault.key())
+            @ ErrorCode::InvalidVault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[callback_accounts("withdraw_all")]
+#[derive(Accounts)]
+pub struct WithdrawAllCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_WITHDRAW_ALL))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// CVCT account to zero the encrypted balance.
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    /// CVCT mint to update encrypted total supply.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+    )]
+    /// Vault to update encrypted total locked and sign SPL transfer.
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(mut)]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub user_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeEmergency<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + EmergencyWithdrawal::INIT_SPACE,
+        seeds = [b"emergency", cvct_mint.key().as_ref()],
+        bump,
+    )]
+    /// Pending emergency withdrawal (one per mint).
+    pub emergency: Box<Account<'info, EmergencyWithdrawal>>,
+    #[account(
+        constraint = recovery_token_account.mint == cvct_mint.backing_mint,
+    )]
+    pub recovery_token_account: Account<'info, TokenAccount>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteEmergency<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+        constraint = vault.cvct_mint == cvct_mint.key() @ ErrorCode::InvalidVault,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"emergency", cvct_mint.key().as_ref()],
+        bump,
+    )]
+    pub emergency: Box<Account<'info, EmergencyWithdrawal>>,
+    #[account(
+        mut,
+        constraint = vault_token_account.key() == vault.backing_token_account,
+        constraint = vault_token_account.key() == vault.derived_token_account(&vault.key())
+            @ ErrorCode::InvalidVault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = recovery_token_account.key() == emergency.recovery_token_account,
+    )]
+    pub recovery_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CancelEmergency<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"emergency", cvct_mint.key().as_ref()],
+        bump,
+    )]
+    pub emergency: Box<Account<'info, EmergencyWithdrawal>>,
+}
+
+#[derive(Accounts)]
+pub struct RepairVaultTokenAccount<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(
+        constraint = vault_token_account.key() == vault.derived_token_account(&vault.key())
+            @ ErrorCode::InvalidVault,
+        constraint = vault_token_account.mint == vault.backing_mint @ ErrorCode::InvalidVault,
+        constraint = vault_token_account.owner == vault.key() @ ErrorCode::InvalidVault,
+    )]
+    /// Recreated vault ATA for the backing mint.
+    pub vault_token_account: Account<'info, TokenAccount>,
+}
+
+#[derive(Accounts)]
+pub struct SetTransferFee<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        constraint = fee_collector.cvct_mint == cvct_mint.key(),
+    )]
+    /// CVCT account that will receive transfer fees.
+    pub fee_collector: Box<Account<'info, CvctAccount>>,
+}
+
+#[queue_computation_accounts("transfer_with_fee", fee_payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct TransferCvctWithFee<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut)]
+    /// Pays Arcium fees and rent; pass the signer itself when not sponsored.
+    pub fee_payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = fee_payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TRANSFER_WITH_FEE))]
+    /// On-chain computation definition for `transfer_with_fee`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = from_cvct_account.owner == user.key() @ ErrorCode::Unauthorized,
+    )]
+    pub from_cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        constraint = to_cvct_account.cvct_mint == from_cvct_account.cvct_mint,
+        constraint = to_cvct_account.key() != from_cvct_account.key() @ ErrorCode::SelfTransfer,
+    )]
+    pub to_cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(
+        constraint = cvct_mint.key() == from_cvct_account.cvct_mint,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        address = cvct_mint.fee_collector,
+        constraint = fee_collector.key() != from_cvct_account.key() @ ErrorCode::InvalidFeeCollector,
+        constraint = fee_collector.key() != to_cvct_account.key() @ ErrorCode::InvalidFeeCollector,
+    )]
+    pub fee_collector: Box<Account<'info, CvctAccount>>,
+}
+
+#[callback_accounts("transfer_with_fee")]
+#[derive(Accounts)]
+pub struct TransferWithFeeCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TRANSFER_WITH_FEE))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// Sender CVCT account to update encrypted balance.
+    pub from_cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    /// Recipient CVCT account to update encrypted balance.
+    pub to_cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    /// Fee collector CVCT account to update encrypted balance.
+    pub fee_collector: Box<Account<'info, CvctAccount>>,
+}
+
+#[queue_computation_accounts("credit_from_vault", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct CreditFromVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CREDIT_FROM_VAULT))]
+    /// On-chain computation definition for `credit_from_vault`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+        constraint = vault.cvct_mint == cvct_mint.key() @ ErrorCode::InvalidVault,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(
+        mut,
+        constraint = cvct_account.cvct_mint == cvct_mint.key(),
+    )]
+    /// CVCT account credited from already-locked collateral.
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+}
+
+#[callback_accounts("credit_from_vault")]
+#[derive(Accounts)]
+pub struct CreditFromVaultCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CREDIT_FROM_VAULT))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// CVCT account to update encrypted balance.
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    /// CVCT mint to update encrypted total supply.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(mut)]
+    /// Vault whose unallocated pool the credit was drawn from.
+    pub vault: Box<Account<'info, Vault>>,
+}
+
+#[queue_computation_accounts("lock_balance", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct LockBalance<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_LOCK_BALANCE))]
+    /// On-chain computation definition for `lock_balance`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        constraint = cvct_account.cvct_mint == cvct_mint.key(),
+    )]
+    /// CVCT account whose balance gets a time-locked portion.
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+}
+
+#[callback_accounts("lock_balance")]
+#[derive(Accounts)]
+pub struct LockBalanceCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_LOCK_BALANCE))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// CVCT account to update encrypted locked balance.
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+}
+
+#[queue_computation_accounts("unlock_balance", fee_payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct UnlockBalance<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(mut)]
+    /// Pays Arcium fees and rent; pass the signer itself when not sponsored.
+    pub fee_payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = fee_payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_UNLOCK_BALANCE))]
+    /// On-chain computation definition for `unlock_balance`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = cvct_account.owner == owner.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(address = cvct_account.cvct_mint)]
+    /// Supplies the `stale_timeout` for the account's in-flight guard.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[callback_accounts("unlock_balance")]
+#[derive(Accounts)]
+pub struct UnlockBalanceCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_UNLOCK_BALANCE))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// CVCT account to clear encrypted locked balance.
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+}
+
+#[queue_computation_accounts("bootstrap_mint_state", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct BootstrapMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_BOOTSTRAP_MINT_STATE))]
+    /// On-chain computation definition for `bootstrap_mint_state`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CvctMint::INIT_SPACE,
+        seeds = [b"cvct_mint", authority.key().as_ref()],
+        bump,
+    )]
+    /// CVCT mint metadata (encrypted totals updated by callback).
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Vault::INIT_SPACE,
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+    )]
+    /// Vault metadata (encrypted total locked updated by callback).
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CvctAccount::INIT_SPACE,
+        seeds = [b"cvct_account", cvct_mint.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    /// Authority's own CVCT account (encrypted balance updated by callback).
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    /// SPL mint that backs CVCT.
+    pub backing_mint: Account<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + BackingMintRecord::INIT_SPACE,
+        seeds = [b"backing", backing_mint.key().as_ref()],
+        bump,
+    )]
+    /// Claims the backing mint so only one CVCT mint can wrap it.
+    pub backing_record: Box<Account<'info, BackingMintRecord>>,
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = backing_mint,
+        associated_token::authority = vault,
+    )]
+    /// ATA owned by vault PDA to hold backing SPL tokens.
+    pub vault_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[callback_accounts("bootstrap_mint_state")]
+#[derive(Accounts)]
+pub struct BootstrapMintStateCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_BOOTSTRAP_MINT_STATE))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// CVCT mint to update encrypted total supply.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(mut)]
+    /// Vault to update encrypted total locked.
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(mut)]
+    /// Authority CVCT account to update encrypted balances.
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+}
+
+#[queue_computation_accounts("snapshot_balance", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64, period_index: u64)]
+pub struct SnapshotBalance<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SNAPSHOT_BALANCE))]
+    /// On-chain computation definition for `snapshot_balance`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        constraint = cvct_account.cvct_mint == cvct_mint.key(),
+    )]
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + BalanceSnapshot::INIT_SPACE,
+        seeds = [
+            b"snapshot",
+            cvct_account.key().as_ref(),
+            &period_index.to_le_bytes(),
+        ],
+        bump,
+    )]
+    /// Period-end snapshot (ciphertext written by callback).
+    pub snapshot: Box<Account<'info, BalanceSnapshot>>,
+}
+
+#[callback_accounts("snapshot_balance")]
+#[derive(Accounts)]
+pub struct SnapshotBalanceCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SNAPSHOT_BALANCE))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// Snapshot to store the re-encrypted balance.
+    pub snapshot: Box<Account<'info, BalanceSnapshot>>,
+    /// Counter account the computation's offset was derived from.
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseBalanceSnapshot<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        constraint = cvct_account.cvct_mint == cvct_mint.key(),
+    )]
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        close = authority,
+        constraint = snapshot.cvct_account == cvct_account.key(),
+    )]
+    pub snapshot: Box<Account<'info, BalanceSnapshot>>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccountSize<'info> {
+    #[account(mut)]
+    /// Pays any additional rent for the larger layout.
+    pub payer: Signer<'info>,
+    #[account(mut)]
+    /// CHECK: owner and discriminator are validated in the handler; the account may be
+    /// too small to deserialize under the current layout.
+    pub account: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitOrg<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Organization::INIT_SPACE,
+        seeds = [b"org", authority.key().as_ref()],
+        bump,
+    )]
+    pub org: Box<Account<'info, Organization>>,
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        seeds = [b"mint_registry"],
+        bump = mint_registry.bump,
+        constraint = mint_registry.is_approved(&cvct_mint.key()) @ ErrorCode::MintNotApproved,
+    )]
+    pub mint_registry: Box<Account<'info, MintRegistry>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitMintRegistry<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MintRegistry::INIT_SPACE,
+        seeds = [b"mint_registry"],
+        bump,
+    )]
+    pub mint_registry: Box<Account<'info, MintRegistry>>,
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()),
+    )]
+    pub program: Program<'info, crate::program::Cvct>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(payer.key())
+            @ ErrorCode::Unauthorized,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterMint<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"mint_registry"],
+        bump = mint_registry.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub mint_registry: Box<Account<'info, MintRegistry>>,
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[derive(Accounts)]
+pub struct DeregisterMint<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"mint_registry"],
+        bump = mint_registry.bump,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub mint_registry: Box<Account<'info, MintRegistry>>,
+}
+
+#[queue_computation_accounts("init_account_state", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct InitOrgTreasury<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_ACCOUNT_STATE))]
+    /// On-chain computation definition for `init_account_state`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::Unauthorized,
+        constraint = org.cvct_treasury_vault == Pubkey::default()
+            || cvct_mint.key() != org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    pub org: Box<Account<'info, Organization>>,
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CvctAccount::INIT_SPACE,
+        seeds = [b"cvct_account", cvct_mint.key().as_ref(), org.key().as_ref()],
+        bump,
+    )]
+    /// Org treasury account (encrypted balance set by callback).
+    pub treasury: Box<Account<'info, CvctAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct SetTreasuryReserve<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub org: Box<Account<'info, Organization>>,
+}
+
+#[derive(Accounts)]
+pub struct CreatePayroll<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// Runs and manages the new payroll; the org authority for its own payroll line.
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Payroll::INIT_SPACE,
+        seeds = [b"payroll", org.key().as_ref(), admin.key().as_ref()],
+        bump,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("init_account_state", admin)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct InitPayrollTreasury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = admin,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_ACCOUNT_STATE))]
+    /// On-chain computation definition for `init_account_state`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        mut,
+        has_one = org,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(constraint = cvct_mint.key() == org.cvct_mint @ ErrorCode::InvalidVault)]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        constraint = previous_treasury.key() == payroll.treasury @ ErrorCode::InvalidVault,
+        constraint = previous_treasury.cvct_mint != org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    /// Existing payroll treasury left on the org's previous mint by `migrate_org_mint`.
+    pub previous_treasury: Option<Box<Account<'info, CvctAccount>>>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + CvctAccount::INIT_SPACE,
+        seeds = [b"cvct_account", cvct_mint.key().as_ref(), payroll.key().as_ref()],
+        bump,
+    )]
+    /// Payroll treasury account (encrypted balance set by callback).
+    pub treasury: Box<Account<'info, CvctAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct AddPayrollMember<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        mut,
+        has_one = org,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(
+        constraint = !payroll.is_internal_account(&org, &payroll.key(), &recipient.key())
+            @ ErrorCode::InvalidVault,
+    )]
+    /// CHECK: payee identity; only used as a PDA seed and matched against the wallet owner.
+    pub recipient: UncheckedAccount<'info>,
+    #[account(
+        constraint = recipient_cvct_account.owner == recipient.key() @ ErrorCode::Unauthorized,
+        constraint = recipient_cvct_account.cvct_mint == org.cvct_mint,
+        constraint = !payroll.is_internal_account(
+            &org,
+            &payroll.key(),
+            &recipient_cvct_account.key(),
+        ) @ ErrorCode::InvalidVault,
+    )]
+    pub recipient_cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PayrollMember::INIT_SPACE,
+        seeds = [b"payroll_member", payroll.key().as_ref(), recipient.key().as_ref()],
+        bump,
+    )]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePayrollStatus<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePayrollMembersBatch<'info> {
+    pub admin: Signer<'info>,
+    #[account(has_one = admin @ ErrorCode::Unauthorized)]
+    pub payroll: Box<Account<'info, Payroll>>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePayroll<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        mut,
+        close = admin,
+        has_one = org,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+}
+
+#[queue_computation_accounts("run_payroll", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct RunPayrollForMember<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RUN_PAYROLL))]
+    /// On-chain computation definition for `run_payroll`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut)]
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        mut,
+        has_one = org,
+        constraint = payroll.active @ ErrorCode::PayrollInactive,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(
+        mut,
+        has_one = payroll,
+        constraint = payroll_member.active @ ErrorCode::MemberInactive,
+    )]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(
+        constraint = cvct_mint.key() == org.cvct_mint,
+        constraint = cvct_mint.transfer_fee_bps == 0 @ ErrorCode::TransferFeeRequired,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        constraint = treasury.key() == payroll.funding_treasury(&org) @ ErrorCode::InvalidVault,
+        constraint = treasury.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    /// Payroll or org treasury debited by the run.
+    pub treasury: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        constraint = member_wallet.key() == payroll_member.cvct_wallet,
+        constraint = member_wallet.key() != treasury.key() @ ErrorCode::InvalidVault,
+        constraint = member_wallet.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    /// Member account credited by the run.
+    pub member_wallet: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        seeds = [b"runner_allowance", org.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: the runner's `RunnerAllowance` PDA, passed whether or not it exists so a capped
+    /// runner can't leave it out; read by `RunnerAllowance::check_runner`.
+    pub runner_allowance: UncheckedAccount<'info>,
+}
+
+#[callback_accounts("run_payroll")]
+#[derive(Accounts)]
+pub struct RunPayrollCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RUN_PAYROLL))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// Treasury to update encrypted balance.
+    pub treasury: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    /// Member account to update encrypted balance.
+    pub member_wallet: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    /// Member whose deductions are updated and whose last_paid is rolled back if the run
+    /// moved nothing.
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(mut)]
+    /// Payroll deactivated when an opted-in run exhausts the treasury.
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(mut)]
+    /// Org whose encrypted disbursed total is updated.
+    pub org: Box<Account<'info, Organization>>,
+    #[account(mut)]
+    /// Runner allowance refunded when the run moves nothing; `None` when nothing was charged.
+    pub runner_allowance: Option<Box<Account<'info, RunnerAllowance>>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseDepositReceipt<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        close = user,
+        has_one = user @ ErrorCode::Unauthorized,
+    )]
+    pub deposit_receipt: Box<Account<'info, DepositReceipt>>,
+}
+
+#[derive(Accounts)]
+pub struct SetTreasuryLowThreshold<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub org: Box<Account<'info, Organization>>,
+}
+
+#[queue_computation_accounts("is_zero", fee_payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct CloseCvctAccount<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(mut)]
+    /// Pays Arcium fees and rent; pass the signer itself when not sponsored.
+    pub fee_payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = fee_payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_IS_ZERO))]
+    /// On-chain computation definition for `is_zero`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = cvct_account.owner == owner.key() @ ErrorCode::Unauthorized,
+        constraint = cvct_account.open_snapshots == 0 @ ErrorCode::OpenSnapshots,
+    )]
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(address = cvct_account.cvct_mint)]
+    /// Supplies the `stale_timeout` for the account's in-flight guard.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[callback_accounts("is_zero")]
+#[derive(Accounts)]
+pub struct IsZeroCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_IS_ZERO))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// Account closed once its balance is revealed as zero, or the org treasury
+    /// `migrate_org_mint` needs drained.
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    /// CHECK: `close_account`'s rent destination, matched against `cvct_account.owner`.
+    pub owner: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        constraint = org.cvct_treasury_vault == cvct_account.key() @ ErrorCode::InvalidVault,
+    )]
+    /// Org `migrate_org_mint` rebinds; rejects if it was rebound in the meantime.
+    pub org: Option<Box<Account<'info, Organization>>>,
+    pub new_cvct_mint: Option<Box<Account<'info, CvctMint>>>,
+    pub new_treasury: Option<Box<Account<'info, CvctAccount>>>,
+}
+
+#[queue_computation_accounts("init_account_state_batch", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct BatchInitAccounts<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_ACCOUNT_STATE_BATCH))]
+    /// On-chain computation definition for `init_account_state_batch`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[callback_accounts("init_account_state_batch")]
+#[derive(Accounts)]
+pub struct InitAccountStateBatchCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_ACCOUNT_STATE_BATCH))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    /// Counter account the computation's offset was derived from.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[queue_computation_accounts("is_zero", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct MigrateOrgMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_IS_ZERO))]
+    /// On-chain computation definition for `is_zero`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        mut,
+        constraint = treasury.key() == org.cvct_treasury_vault @ ErrorCode::InvalidVault,
+    )]
+    /// Current treasury; must be revealed empty.
+    pub treasury: Box<Account<'info, CvctAccount>>,
+    #[account(
+        constraint = new_cvct_mint.key() != org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    pub new_cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        seeds = [b"mint_registry"],
+        bump = mint_registry.bump,
+        constraint = mint_registry.is_approved(&new_cvct_mint.key()) @ ErrorCode::MintNotApproved,
+    )]
+    pub mint_registry: Box<Account<'info, MintRegistry>>,
+    #[account(
+        seeds = [b"cvct_account", new_cvct_mint.key().as_ref(), org.key().as_ref()],
+        bump,
+        constraint = new_treasury.owner == org.key() @ ErrorCode::InvalidVault,
+    )]
+    /// Treasury staged for the new mint via `init_org_treasury`.
+    pub new_treasury: Box<Account<'info, CvctAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct MintUnits<'info> {
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[queue_computation_accounts("can_pay_member", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct CanPayMember<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CAN_PAY_MEMBER))]
+    /// On-chain computation definition for `can_pay_member`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        has_one = org,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(
+        has_one = payroll,
+    )]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(
+        mut,
+        constraint = treasury.key() == payroll.funding_treasury(&org) @ ErrorCode::InvalidVault,
+        constraint = treasury.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    pub treasury: Box<Account<'info, CvctAccount>>,
+}
+
+#[callback_accounts("can_pay_member")]
+#[derive(Accounts)]
+pub struct CanPayMemberCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CAN_PAY_MEMBER))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    /// Counter account the computation's offset was derived from.
+    pub treasury: Box<Account<'info, CvctAccount>>,
+}
+
+#[queue_computation_accounts("simulate_payroll_run", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct SimulatePayrollRun<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SIMULATE_PAYROLL_RUN))]
+    /// On-chain computation definition for `simulate_payroll_run`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        has_one = org,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(
+        has_one = payroll,
+    )]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(
+        mut,
+        constraint = treasury.key() == payroll.funding_treasury(&org) @ ErrorCode::InvalidVault,
+        constraint = treasury.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    pub treasury: Box<Account<'info, CvctAccount>>,
+}
+
+#[callback_accounts("simulate_payroll_run")]
+#[derive(Accounts)]
+pub struct SimulatePayrollRunCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_SIMULATE_PAYROLL_RUN))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    /// Counter account the computation's offset was derived from.
+    pub treasury: Box<Account<'info, CvctAccount>>,
+}
+
+#[queue_computation_accounts("is_equal", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct CheckEqual<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_IS_EQUAL))]
+    /// On-chain computation definition for `is_equal`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+}
+
+#[callback_accounts("is_equal")]
+#[derive(Accounts)]
+pub struct IsEqualCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_IS_EQUAL))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    /// Set by `verify_system_health` only, whose structural checks the callback reports
+    /// alongside the equality in a `HealthReport`; `None` for `check_equal`.
+    pub vault: Option<Box<Account<'info, Vault>>>,
+    pub backing_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    pub org: Option<Box<Account<'info, Organization>>>,
+    /// The org's treasury, loaded so its mint and owner can be checked.
+    pub org_treasury: Option<Box<Account<'info, CvctAccount>>>,
+}
+
+#[queue_computation_accounts("backed_supply", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct RefreshBackedSupply<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_BACKED_SUPPLY))]
+    /// On-chain computation definition for `backed_supply`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+}
+
+#[callback_accounts("backed_supply")]
+#[derive(Accounts)]
+pub struct BackedSupplyCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_BACKED_SUPPLY))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// Vault whose `backed_supply` is written.
+    pub vault: Box<Account<'info, Vault>>,
+    /// Counter account the computation's offset was derived from.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[derive(Accounts)]
+pub struct SetMintMode<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[derive(Accounts)]
+pub struct SetComputationTimeout<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[derive(Accounts)]
+pub struct SetMaxTransfer<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[derive(Accounts)]
+pub struct SetRevealThreshold<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[queue_computation_accounts("circuit_breaker", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct CircuitBreaker<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CIRCUIT_BREAKER))]
+    /// On-chain computation definition for `circuit_breaker`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(
+        constraint = vault_token_account.key() == vault.backing_token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+}
+
+#[callback_accounts("circuit_breaker")]
+#[derive(Accounts)]
+pub struct CircuitBreakerCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CIRCUIT_BREAKER))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// Mint paused when the check fails.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePendingOp<'info> {
+    pub initiator: Signer<'info>,
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = initiator @ ErrorCode::Unauthorized,
+        has_one = rent_payer,
+    )]
+    pub pending_op: Box<Account<'info, PendingOp>>,
+    #[account(mut)]
+    /// CHECK: refund target, matched against `pending_op.rent_payer`.
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+#[queue_computation_accounts("is_equal", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct VerifySystemHealth<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_IS_EQUAL))]
+    /// On-chain computation definition for `is_equal`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut)]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    /// Checked in the callback rather than constrained, so mismatches are reported.
+    pub vault: Box<Account<'info, Vault>>,
+    pub backing_token_account: Box<Account<'info, TokenAccount>>,
+    pub org: Option<Box<Account<'info, Organization>>>,
+    /// The org's treasury, loaded so its mint and owner can be checked.
+    pub org_treasury: Option<Box<Account<'info, CvctAccount>>>,
+}
+
+#[queue_computation_accounts("accrue_payroll", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct AccrueForMember<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ACCRUE_PAYROLL))]
+    /// On-chain computation definition for `accrue_payroll`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut)]
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        mut,
+        has_one = org,
+        constraint = payroll.active @ ErrorCode::PayrollInactive,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(
+        mut,
+        has_one = payroll,
+        constraint = payroll_member.active @ ErrorCode::MemberInactive,
+    )]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(
+        constraint = cvct_mint.key() == org.cvct_mint,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        constraint = treasury.key() == payroll.funding_treasury(&org) @ ErrorCode::InvalidVault,
+        constraint = treasury.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    /// Payroll or org treasury debited by the accrual.
+    pub treasury: Box<Account<'info, CvctAccount>>,
+    #[account(
+        constraint = member_wallet.key() == payroll_member.cvct_wallet,
+        constraint = member_wallet.key() != treasury.key() @ ErrorCode::InvalidVault,
+        constraint = member_wallet.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    /// Member wallet whose key the accrual is encrypted to.
+    pub member_wallet: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        seeds = [b"runner_allowance", org.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: the runner's `RunnerAllowance` PDA, passed whether or not it exists so a capped
+    /// runner can't leave it out; read by `RunnerAllowance::check_runner`.
+    pub runner_allowance: UncheckedAccount<'info>,
+}
+
+#[callback_accounts("accrue_payroll")]
+#[derive(Accounts)]
+pub struct AccruePayrollCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ACCRUE_PAYROLL))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// Treasury to update encrypted balance.
+    pub treasury: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    /// Member whose accrual is updated.
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(mut)]
+    /// Org whose encrypted disbursed total is updated.
+    pub org: Box<Account<'info, Organization>>,
+    #[account(mut)]
+    /// Runner allowance refunded when the run moves nothing; `None` when nothing was charged.
+    pub runner_allowance: Option<Box<Account<'info, RunnerAllowance>>>,
+}
+
+#[queue_computation_accounts("claim_accrued", fee_payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ClaimPay<'info> {
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+    #[account(mut)]
+    /// Pays Arcium fees and rent; pass the signer itself when not sponsored.
+    pub fee_payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = fee_payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CLAIM_ACCRUED))]
+    /// On-chain computation definition for `claim_accrued`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        has_one = recipient @ ErrorCode::Unauthorized,
+    )]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(
+        constraint = cvct_mint.key() == member_wallet.cvct_mint @ ErrorCode::InvalidVault,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        constraint = member_wallet.key() == payroll_member.cvct_wallet,
+        constraint = member_wallet.owner == recipient.key() @ ErrorCode::Unauthorized,
+    )]
+    /// Member account credited with the accrual.
+    pub member_wallet: Box<Account<'info, CvctAccount>>,
+}
+
+#[callback_accounts("claim_accrued")]
+#[derive(Accounts)]
+pub struct ClaimAccruedCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CLAIM_ACCRUED))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// Member whose accrual is zeroed.
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(mut)]
+    /// Member account to update encrypted balance.
+    pub member_wallet: Box<Account<'info, CvctAccount>>,
+}
+
+#[queue_computation_accounts("run_payroll", admin)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct SplitMemberRate<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = admin,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RUN_PAYROLL))]
+    /// On-chain computation definition for `run_payroll`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut)]
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        mut,
+        has_one = org,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(
+        mut,
+        has_one = payroll,
+        constraint = payroll_member.active @ ErrorCode::MemberInactive,
+    )]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(
+        constraint = cvct_mint.key() == org.cvct_mint,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        constraint = treasury.key() == payroll.funding_treasury(&org) @ ErrorCode::InvalidVault,
+        constraint = treasury.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    /// Payroll or org treasury debited by the settlement.
+    pub treasury: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        constraint = member_wallet.key() == payroll_member.cvct_wallet,
+        constraint = member_wallet.key() != treasury.key() @ ErrorCode::InvalidVault,
+        constraint = member_wallet.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    /// Member account credited by the settlement.
+    pub member_wallet: Box<Account<'info, CvctAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseSignPda<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()),
+    )]
+    pub program: Program<'info, crate::program::Cvct>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ ErrorCode::Unauthorized,
+    )]
+    /// Only the deployment's upgrade authority may reclaim the rent.
+    pub program_data: Account<'info, ProgramData>,
+    #[account(seeds = [b"mint_registry"], bump = mint_registry.bump)]
+    /// Lists the mints whose pending computations must all have closed.
+    pub mint_registry: Box<Account<'info, MintRegistry>>,
+}
+
+#[queue_computation_accounts("onboard_member", admin)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct OnboardMember<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = admin,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ONBOARD_MEMBER))]
+    /// On-chain computation definition for `onboard_member`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        mut,
+        has_one = org,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(
+        constraint = !payroll.is_internal_account(&org, &payroll.key(), &member.key())
+            @ ErrorCode::InvalidVault,
+    )]
+    /// New member; owns the created account and seeds the PDAs. Signs so no one else can
+    /// take their one account per mint under an encryption key the member didn't choose.
+    pub member: Signer<'info>,
+    #[account(
+        constraint = cvct_mint.key() == org.cvct_mint,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + CvctAccount::INIT_SPACE,
+        seeds = [b"cvct_account", cvct_mint.key().as_ref(), member.key().as_ref()],
+        bump,
+    )]
+    /// Member's CVCT account (encrypted balances set by callback).
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + PayrollMember::INIT_SPACE,
+        seeds = [b"payroll_member", payroll.key().as_ref(), member.key().as_ref()],
+        bump,
+    )]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(
+        mut,
+        constraint = treasury.key() == payroll.funding_treasury(&org) @ ErrorCode::InvalidVault,
+        constraint = treasury.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    /// Payroll or org treasury the bonus is drawn from.
+    pub treasury: Box<Account<'info, CvctAccount>>,
+}
+
+#[callback_accounts("onboard_member")]
+#[derive(Accounts)]
+pub struct OnboardMemberCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ONBOARD_MEMBER))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// New member account to receive its initial encrypted balances.
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    /// Treasury to update encrypted balance.
+    pub treasury: Box<Account<'info, CvctAccount>>,
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+}
+
+#[derive(Accounts)]
+pub struct SetTimekeeper<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+}
+
+#[derive(Accounts)]
+pub struct ReportHours<'info> {
+    /// Org authority or the payroll's timekeeper; checked in the handler.
+    pub reporter: Signer<'info>,
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        has_one = org,
+        constraint = payroll.rate_unit == RateUnit::PerReportedHour @ ErrorCode::HourlyPayroll,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(
+        mut,
+        has_one = payroll,
+        constraint = payroll_member.active @ ErrorCode::MemberInactive,
+    )]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+}
+
+#[queue_computation_accounts("run_hourly_payroll", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct RunHourlyPayrollForMember<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RUN_HOURLY_PAYROLL))]
+    /// On-chain computation definition for `run_hourly_payroll`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut)]
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        mut,
+        has_one = org,
+        constraint = payroll.active @ ErrorCode::PayrollInactive,
+        constraint = payroll.rate_unit == RateUnit::PerReportedHour @ ErrorCode::HourlyPayroll,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(
+        mut,
+        has_one = payroll,
+        constraint = payroll_member.active @ ErrorCode::MemberInactive,
+    )]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(
+        constraint = cvct_mint.key() == org.cvct_mint,
+        constraint = cvct_mint.transfer_fee_bps == 0 @ ErrorCode::TransferFeeRequired,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        constraint = treasury.key() == payroll.funding_treasury(&org) @ ErrorCode::InvalidVault,
+        constraint = treasury.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    /// Payroll or org treasury debited by the run.
+    pub treasury: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        constraint = member_wallet.key() == payroll_member.cvct_wallet,
+        constraint = member_wallet.key() != treasury.key() @ ErrorCode::InvalidVault,
+        constraint = member_wallet.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    /// Member account credited by the run.
+    pub member_wallet: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        seeds = [b"runner_allowance", org.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: the runner's `RunnerAllowance` PDA, passed whether or not it exists so a capped
+    /// runner can't leave it out; read by `RunnerAllowance::check_runner`.
+    pub runner_allowance: UncheckedAccount<'info>,
+}
+
+#[callback_accounts("run_hourly_payroll")]
+#[derive(Accounts)]
+pub struct RunHourlyPayrollCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RUN_HOURLY_PAYROLL))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// Treasury to update encrypted balance.
+    pub treasury: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    /// Member account to update encrypted balance.
+    pub member_wallet: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    /// Member whose hours are cleared, or last_paid rolled back if nothing moved.
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(mut)]
+    /// Org whose encrypted disbursed total is updated.
+    pub org: Box<Account<'info, Organization>>,
+}
+
+#[queue_computation_accounts("deposit_and_mint", depositor)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct DepositFor<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = depositor,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DEPOSIT_AND_MINT))]
+    /// On-chain computation definition for `deposit_and_mint`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = cvct_mint.authority == depositor.key() @ ErrorCode::Unauthorized,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+        constraint = vault.cvct_mint == cvct_mint.key() @ ErrorCode::InvalidVault,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(
+        mut,
+        constraint = beneficiary_cvct_account.cvct_mint == cvct_mint.key(),
+    )]
+    /// Account credited with the minted CVCT; any owner.
+    pub beneficiary_cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        constraint = depositor_token_account.mint == cvct_mint.backing_mint,
+        constraint = depositor_token_account.owner == depositor.key(),
+    )]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+    #[account(
+        address = cvct_mint.backing_mint,
+        constraint = backing_mint.decimals == cvct_mint.decimals @ ErrorCode::DecimalsMismatch,
+    )]
+    pub backing_mint: Account<'info, Mint>,
+    #[account(
+        mut,
+        constraint = vault_token_account.key() == vault.backing_token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+    #[account(
+        init,
+        payer = depositor,
+        space = 8 + PendingOp::INIT_SPACE,
+        seeds = [b"pending_op", &computation_offset.to_le_bytes()],
+        bump,
+    )]
+    /// Pending-operation marker cleared by the callback.
+    pub pending_op: Box<Account<'info, PendingOp>>,
+}
+
+#[queue_computation_accounts("run_payroll", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ForcePayMember<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RUN_PAYROLL))]
+    /// On-chain computation definition for `run_payroll`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut, has_one = authority @ ErrorCode::Unauthorized)]
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        mut,
+        has_one = org,
+        constraint = payroll.active @ ErrorCode::PayrollInactive,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(
+        mut,
+        has_one = payroll,
+        constraint = payroll_member.active @ ErrorCode::MemberInactive,
+    )]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(
+        constraint = cvct_mint.key() == org.cvct_mint,
+        constraint = cvct_mint.transfer_fee_bps == 0 @ ErrorCode::TransferFeeRequired,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        constraint = treasury.key() == payroll.funding_treasury(&org) @ ErrorCode::InvalidVault,
+        constraint = treasury.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    /// Payroll or org treasury debited by the payment.
+    pub treasury: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        constraint = member_wallet.key() == payroll_member.cvct_wallet,
+        constraint = member_wallet.key() != treasury.key() @ ErrorCode::InvalidVault,
+        constraint = member_wallet.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    /// Member account credited by the payment.
+    pub member_wallet: Box<Account<'info, CvctAccount>>,
+}
+
+#[derive(Accounts)]
+#[instruction(runner: Pubkey)]
+pub struct SetRunnerAllowance<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RunnerAllowance::INIT_SPACE,
+        seeds = [b"runner_allowance", org.key().as_ref(), runner.as_ref()],
+        bump,
+    )]
+    pub runner_allowance: Box<Account<'info, RunnerAllowance>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResetRunnerAllowance<'info> {
+    pub authority: Signer<'info>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub org: Box<Account<'info, Organization>>,
+    #[account(mut, has_one = org)]
+    pub runner_allowance: Box<Account<'info, RunnerAllowance>>,
+}
+
+#[queue_computation_accounts("lock_collateral", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct LockCollateral<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_LOCK_COLLATERAL))]
+    /// On-chain computation definition for `lock_collateral`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = cvct_mint.authority == authority.key() @ ErrorCode::Unauthorized,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+        constraint = vault.cvct_mint == cvct_mint.key() @ ErrorCode::InvalidVault,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(
+        mut,
+        constraint = authority_token_account.mint == cvct_mint.backing_mint,
+        constraint = authority_token_account.owner == authority.key(),
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = vault_token_account.key() == vault.backing_token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[callback_accounts("lock_collateral")]
+#[derive(Accounts)]
+pub struct LockCollateralCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_LOCK_COLLATERAL))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// Vault whose encrypted total_locked grows.
+    pub vault: Box<Account<'info, Vault>>,
+    /// Counter account the computation's offset was derived from.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[derive(Accounts)]
+pub struct ReadBalanceHandle<'info> {
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+}
+
+#[queue_computation_accounts("transfer_with_receipt", fee_payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct TransferWithReceipt<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(mut)]
+    /// Pays Arcium fees and rent; pass the signer itself when not sponsored.
+    pub fee_payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = fee_payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TRANSFER_WITH_RECEIPT))]
+    /// On-chain computation definition for `transfer_with_receipt`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = from_cvct_account.owner == user.key() @ ErrorCode::Unauthorized,
+    )]
+    pub from_cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        constraint = to_cvct_account.cvct_mint == from_cvct_account.cvct_mint,
+        constraint = to_cvct_account.key() != from_cvct_account.key() @ ErrorCode::SelfTransfer,
+    )]
+    pub to_cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        constraint = cvct_mint.key() == from_cvct_account.cvct_mint,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        init,
+        payer = fee_payer,
+        space = 8 + PendingOp::INIT_SPACE,
+        seeds = [b"pending_op", &computation_offset.to_le_bytes()],
+        bump,
+    )]
+    /// Pending-operation marker cleared by the callback.
+    pub pending_op: Box<Account<'info, PendingOp>>,
+}
+
+#[callback_accounts("transfer_with_receipt")]
+#[derive(Accounts)]
+pub struct TransferWithReceiptCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TRANSFER_WITH_RECEIPT))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// Sender CVCT account to update encrypted balance.
+    pub from_cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    /// Recipient CVCT account to update encrypted balance.
+    pub to_cvct_account: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        close = rent_payer,
+        has_one = rent_payer,
+        constraint = !pending_op.failed @ ErrorCode::ComputationFailed,
+    )]
+    /// Closed once the result is applied; refused once the sweep failed it.
+    pub pending_op: Box<Account<'info, PendingOp>>,
+    #[account(mut)]
+    /// CHECK: refund target, matched against `pending_op.rent_payer`.
+    pub rent_payer: UncheckedAccount<'info>,
+    #[account(mut, address = pending_op.cvct_mint)]
+    /// Mint whose open `PendingOp` count drops as the marker closes.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[derive(Accounts)]
+pub struct ImportMembers<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        mut,
+        has_one = org,
+        has_one = admin @ ErrorCode::Unauthorized,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SweepStaleComputation<'info> {
+    #[account(
+        mut,
+        has_one = cvct_mint,
+        constraint = !pending_op.failed @ ErrorCode::ComputationFailed,
+    )]
+    /// Kept open as failed; the initiator reclaims its rent with `close_pending_op`.
+    pub pending_op: Box<Account<'info, PendingOp>>,
+    #[account(mut)]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        seeds = [b"vault", cvct_mint.key().as_ref()],
+        bump,
+        constraint = vault.cvct_mint == cvct_mint.key() @ ErrorCode::InvalidVault,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+    #[account(
+        mut,
+        constraint = vault_token_account.key() == vault.backing_token_account,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+    #[account(
+        mut,
+        constraint = initiator_token_account.mint == cvct_mint.backing_mint,
+        constraint = initiator_token_account.owner == pending_op.initiator @ ErrorCode::Unauthorized,
+    )]
+    /// Receives the refunded backing, and gets back close authority lent by an unwrap.
+    pub initiator_token_account: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[queue_computation_accounts("reveal_to", fee_payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct RevealTo<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(mut)]
+    /// Pays Arcium fees and rent; pass the signer itself when not sponsored.
+    pub fee_payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = fee_payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_TO))]
+    /// On-chain computation definition for `reveal_to`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(
+        mut,
+        constraint = cvct_account.owner == owner.key() @ ErrorCode::Unauthorized,
+    )]
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+}
+
+#[callback_accounts("reveal_to")]
+#[derive(Accounts)]
+pub struct RevealToCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_REVEAL_TO))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    /// Account whose balance was disclosed.
+    pub cvct_account: Box<Account<'info, CvctAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct SetMemberInterval<'info> {
+    pub admin: Signer<'info>,
+    #[account(has_one = admin @ ErrorCode::Unauthorized)]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(mut, has_one = payroll)]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+}
+
+#[derive(Accounts)]
+pub struct SetCloseApprovers<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub org: Box<Account<'info, Organization>>,
+}
+
+#[derive(Accounts)]
+pub struct ReadMemberDueStatus<'info> {
+    pub org: Box<Account<'info, Organization>>,
+    #[account(has_one = org)]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(has_one = payroll)]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    /// Mint the payroll pays in; runs refuse while it is not `Normal`.
+    #[account(constraint = cvct_mint.key() == org.cvct_mint)]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[derive(Accounts)]
+pub struct QuotePayment<'info> {
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(has_one = payroll)]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+}
+
+#[queue_computation_accounts("apply_deduction", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ApplyDeduction<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_APPLY_DEDUCTION))]
+    /// On-chain computation definition for `apply_deduction`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub org: Box<Account<'info, Organization>>,
+    #[account(has_one = org)]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(mut, has_one = payroll)]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(
+        constraint = cvct_mint.key() == org.cvct_mint,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    /// Mint the payroll pays in; supplies the stale timeout for the in-flight marker.
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+}
+
+#[callback_accounts("apply_deduction")]
+#[derive(Accounts)]
+pub struct ApplyDeductionCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_APPLY_DEDUCTION))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// Member whose encrypted deductions are updated.
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMemberWallet<'info> {
+    pub authority: Signer<'info>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub org: Box<Account<'info, Organization>>,
+    #[account(has_one = org)]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(mut, has_one = payroll)]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(
+        constraint = !payroll.is_internal_account(&org, &payroll.key(), &new_wallet.key())
+            @ ErrorCode::InvalidVault,
+        constraint = new_wallet.owner == payroll_member.recipient @ ErrorCode::Unauthorized,
+        constraint = new_wallet.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    pub new_wallet: Box<Account<'info, CvctAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateSpenders<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub org: Box<Account<'info, Organization>>,
+}
+
+#[derive(Accounts)]
+pub struct SetOrgEncPubkey<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority @ ErrorCode::Unauthorized,
+    )]
+    pub org: Box<Account<'info, Organization>>,
+}
+
+#[derive(Accounts)]
+pub struct ProveRateInBand<'info> {
+    pub authority: Signer<'info>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub org: Box<Account<'info, Organization>>,
+    #[account(has_one = org)]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(has_one = payroll)]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+}
+
+#[queue_computation_accounts("clawback", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ClawbackPayment<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CLAWBACK))]
+    /// On-chain computation definition for `clawback`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub org: Box<Account<'info, Organization>>,
+    #[account(has_one = org)]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(mut, has_one = payroll)]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(
+        constraint = cvct_mint.key() == org.cvct_mint,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        constraint = member_wallet.key() == payroll_member.cvct_wallet,
+        constraint = member_wallet.cvct_mint == org.cvct_mint,
+    )]
+    /// Member account the payment is taken back from.
+    pub member_wallet: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        constraint = treasury.key() == payroll.funding_treasury(&org) @ ErrorCode::InvalidVault,
+        constraint = treasury.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+        constraint = treasury.key() != member_wallet.key() @ ErrorCode::InvalidVault,
+    )]
+    /// Treasury the payment came from and is returned to.
+    pub treasury: Box<Account<'info, CvctAccount>>,
+}
+
+#[callback_accounts("clawback")]
+#[derive(Accounts)]
+pub struct ClawbackCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CLAWBACK))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub member_wallet: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    pub treasury: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+}
+
+#[derive(Accounts)]
+pub struct ProposePayment<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        has_one = org,
+        constraint = payroll.active @ ErrorCode::PayrollInactive,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(
+        mut,
+        has_one = payroll,
+        constraint = payroll_member.active @ ErrorCode::MemberInactive,
+    )]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + PendingPayment::INIT_SPACE,
+        seeds = [b"pending_payment", payroll_member.key().as_ref()],
+        bump,
+    )]
+    /// One open proposal per member.
+    pub pending_payment: Box<Account<'info, PendingPayment>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[queue_computation_accounts("run_payroll", approver)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ApprovePayment<'info> {
+    #[account(mut)]
+    pub approver: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = approver,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_RUN_PAYROLL))]
+    /// On-chain computation definition for `run_payroll`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut)]
+    pub org: Box<Account<'info, Organization>>,
+    #[account(
+        mut,
+        has_one = org,
+        constraint = payroll.active @ ErrorCode::PayrollInactive,
+    )]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(
+        mut,
+        has_one = payroll,
+        constraint = payroll_member.active @ ErrorCode::MemberInactive,
+    )]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(
+        mut,
+        close = proposer,
+        has_one = payroll_member,
+        has_one = proposer,
+        seeds = [b"pending_payment", payroll_member.key().as_ref()],
+        bump = pending_payment.bump,
+    )]
+    pub pending_payment: Box<Account<'info, PendingPayment>>,
+    #[account(mut)]
+    /// CHECK: receives the proposal's rent; matched by `has_one` on `pending_payment`.
+    pub proposer: UncheckedAccount<'info>,
+    #[account(
+        constraint = cvct_mint.key() == org.cvct_mint,
+        constraint = cvct_mint.transfer_fee_bps == 0 @ ErrorCode::TransferFeeRequired,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        constraint = treasury.key() == payroll.funding_treasury(&org) @ ErrorCode::InvalidVault,
+        constraint = treasury.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    /// Payroll or org treasury debited by the payment.
+    pub treasury: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        constraint = member_wallet.key() == payroll_member.cvct_wallet,
+        constraint = member_wallet.key() != treasury.key() @ ErrorCode::InvalidVault,
+        constraint = member_wallet.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    /// Member account credited by the payment.
+    pub member_wallet: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        seeds = [b"runner_allowance", org.key().as_ref(), approver.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: the runner's `RunnerAllowance` PDA, passed whether or not it exists so a capped
+    /// runner can't leave it out; read by `RunnerAllowance::check_runner`.
+    pub runner_allowance: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelPayment<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(
+        mut,
+        close = proposer,
+        has_one = proposer @ ErrorCode::Unauthorized,
+    )]
+    pub pending_payment: Box<Account<'info, PendingPayment>>,
+}
+
+#[queue_computation_accounts("accrue_bonus", authority)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct AccrueBonus<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = authority,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ACCRUE_BONUS))]
+    /// On-chain computation definition for `accrue_bonus`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(has_one = authority @ ErrorCode::Unauthorized)]
+    pub org: Box<Account<'info, Organization>>,
+    #[account(has_one = org)]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(
+        mut,
+        has_one = payroll,
+        constraint = payroll_member.active @ ErrorCode::MemberInactive,
+    )]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+}
+
+#[callback_accounts("accrue_bonus")]
+#[derive(Accounts)]
+pub struct AccrueBonusCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ACCRUE_BONUS))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// Member whose encrypted bonus is updated.
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+}
+
+#[queue_computation_accounts("claim_bonus", fee_payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct ClaimBonus<'info> {
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+    #[account(mut)]
+    /// Pays Arcium fees and rent; pass the signer itself when not sponsored.
+    pub fee_payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = fee_payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    /// Arcium signer PDA used to sign the queued computation.
+    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account identifies the Arcium execution environment.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program.
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program.
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CLAIM_BONUS))]
+    /// On-chain computation definition for `claim_bonus`.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster state used for output verification.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    /// Fee pool used by Arcium.
+    pub pool_account: Box<Account<'info, FeePool>>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    /// Arcium clock account.
+    pub clock_account: Box<Account<'info, ClockAccount>>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(mut)]
+    pub org: Box<Account<'info, Organization>>,
+    #[account(mut, has_one = org)]
+    pub payroll: Box<Account<'info, Payroll>>,
+    #[account(
+        has_one = payroll,
+        has_one = recipient @ ErrorCode::Unauthorized,
+    )]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(
+        constraint = cvct_mint.key() == org.cvct_mint,
+        constraint = cvct_mint.mode == MintMode::Normal @ ErrorCode::MintPaused,
+    )]
+    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    #[account(
+        mut,
+        constraint = treasury.key() == payroll.funding_treasury(&org) @ ErrorCode::InvalidVault,
+        constraint = treasury.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+    )]
+    /// Payroll or org treasury debited by the bonus.
+    pub treasury: Box<Account<'info, CvctAccount>>,
+    #[account(
+        mut,
+        constraint = member_wallet.key() == payroll_member.cvct_wallet,
+        constraint = member_wallet.key() != treasury.key() @ ErrorCode::InvalidVault,
+        constraint = member_wallet.cvct_mint == org.cvct_mint @ ErrorCode::InvalidVault,
+        constraint = member_wallet.cvct_mint == org.cvct_mint,
+    )]
+    /// Member account credited with the bonus.
+    pub member_wallet: Box<Account<'info, CvctAccount>>,
+}
+
+#[callback_accounts("claim_bonus")]
+#[derive(Accounts)]
+pub struct ClaimBonusCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_CLAIM_BONUS))]
+    /// Same computation definition as queued instruction.
+    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
+    #[account(address = derive_mxe_pda!())]
+    /// MXE account for this computation.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// Cluster account used to verify Arcium output signature.
+    pub cluster_account: Box<Account<'info, Cluster>>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    pub treasury: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    pub payroll_member: Box<Account<'info, PayrollMember>>,
+    #[account(mut)]
+    pub member_wallet: Box<Account<'info, CvctAccount>>,
+    #[account(mut)]
+    pub org: Box<Account<'info, Organization>>,
+}
+
+#[init_computation_definition_accounts("init_mint_state", payer)]
+#[derive(Accounts)]
+pub struct InitMintStateCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("init_account_state", payer)]
+#[derive(Accounts)]
+pub struct InitAccountStateCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("deposit_and_mint", payer)]
+#[derive(Accounts)]
+pub struct InitDepositAndMintCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("burn_and_withdraw", payer)]
+#[derive(Accounts)]
+pub struct InitBurnAndWithdrawCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("transfer_cvct", payer)]
+#[derive(Accounts)]
+pub struct InitTransferCvctCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("check_collateral", payer)]
+#[derive(Accounts)]
+pub struct InitCheckCollateralCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("withdraw_all", payer)]
+#[derive(Accounts)]
+pub struct InitWithdrawAllCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+/// One roster entry for `import_members`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MemberImport {
+    pub recipient: Pubkey,
+    /// Same units as `PayrollMember::rate`.
+    pub rate: u64,
+    pub active: bool,
+}
+
+/// Operating mode of a `CvctMint`; stored in the byte the old `paused` flag used.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MintMode {
+    Normal,
+    /// Halts every value-moving instruction.
+    Paused,
+    /// Wind-down: only `burn_and_withdraw` and `withdraw_all` are allowed.
+    WithdrawOnly,
+}
+
+/// Unit a payroll member's `rate` is quoted in.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RateUnit {
+    /// Paid once per elapsed `interval`.
+    PerInterval,
+    PerSecond,
+    PerDay,
+    /// Per hour reported via `report_hours`; paid by `run_hourly_payroll_for_member`.
+    PerReportedHour,
+}
+
+/// How per-day pay is rounded to base units; the remainder is carried either way.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoundingMode {
+    Floor,
+    Ceil,
+    Nearest,
+}
+
+impl RoundingMode {
+    /// `numerator / denominator` rounded per the mode; `denominator` must be positive.
+    pub fn div(self, numerator: i128, denominator: i128) -> i128 {
+        match self {
+            RoundingMode::Floor => numerator.div_euclid(denominator),
+            RoundingMode::Ceil => -(-numerator).div_euclid(denominator),
+            RoundingMode::Nearest => (numerator + denominator / 2).div_euclid(denominator),
+        }
+    }
+}
+
+/// Privileged org or payroll action recorded by `AuthorityAction`.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AuthorityActionKind {
+    PausePayroll,
+    ResumePayroll,
+    SetAutoPause,
+    SetRoundingMode,
+    UpdatePayrollInterval,
+    ClosePayroll,
+    UpdatePayrollMembers,
+    SplitMemberRate,
+    SetMemberInterval,
+    SetTimekeeper,
+    SetTreasuryReserve,
+    SetTreasuryLowThreshold,
+    SetCloseApprovers,
+    UpdateMemberWallet,
+    AddSpender,
+    RemoveSpender,
+    SetRunnerAllowance,
+    ResetRunnerAllowance,
+    MigrateOrgMint,
+    ApplyDeduction,
+    ForcePayMember,
+    SetClawbackWindow,
+    Clawback,
+    SetApprovalThreshold,
+    AccrueBonus,
+    SetOrgEncPubkey,
+}
+
+/// Confidential operation a queued computation belongs to.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ComputationKind {
+    InitMintState,
+    InitAccountState,
+    DepositAndMint,
+    BurnAndWithdraw,
+    TransferCvct,
+    WithdrawAll,
+    CheckCollateral,
+    TransferWithFee,
+    CreditFromVault,
+    LockBalance,
+    UnlockBalance,
+    BootstrapMintState,
+    SnapshotBalance,
+    RunPayroll,
+    IsZero,
+    InitAccountStateBatch,
+    CanPayMember,
+    SimulatePayrollRun,
+    IsEqual,
+    BackedSupply,
+    CircuitBreaker,
+    AccruePayroll,
+    ClaimAccrued,
+    OnboardMember,
+    RunHourlyPayroll,
+    LockCollateral,
+    TransferWithReceipt,
+    RevealTo,
+    ApplyDeduction,
+    Clawback,
+    AccrueBonus,
+    ClaimBonus,
+}
+
+#[event]
+pub struct ComputationQueued {
+    /// Offset the Arcium computation PDA is derived from.
+    pub computation_offset: u64,
+    pub kind: ComputationKind,
+    /// Program accounts the callback will update.
+    pub accounts: Vec<Pubkey>,
+}
+
+#[event]
+pub struct ComputationAborted {
+    /// Offset the computation was queued with; 0 if it could not be recovered.
+    pub computation_offset: u64,
+    pub instruction_kind: ComputationKind,
+}
+
+#[event]
+pub struct PayrollRunCompleted {
+    pub payroll_member: Pubkey,
+    /// False when the treasury could not cover the amount owed (nothing moved).
+    pub paid: bool,
+}
+
+#[event]
+pub struct TreasuryLow {
+    /// Org or payroll that owns the treasury.
+    pub owner: Pubkey,
+    pub treasury: Pubkey,
+}
+
+#[event]
+pub struct OrgMigrated {
+    pub org: Pubkey,
+    pub old_cvct_mint: Pubkey,
+    pub new_cvct_mint: Pubkey,
+    pub new_treasury: Pubkey,
+}
+
+#[event]
+pub struct MintUnitsReported {
+    pub cvct_mint: Pubkey,
+    pub backing_mint: Pubkey,
+    /// Base units per display unit are `10^decimals`; matches the backing mint.
+    pub decimals: u8,
 }
 
-#[account]
-pub struct CvctAccount {
-    pub owner: Pubkey,
+#[event]
+pub struct MemberPayable {
+    pub payroll_member: Pubkey,
+    /// Whether the treasury currently covers the member's amount owed.
+    pub can_pay: bool,
+}
+
+#[event]
+pub struct PayrollSimulation {
+    pub payroll_member: Pubkey,
+    /// Whole periods a run would pay right now.
+    pub periods_owed: u64,
+    /// Plaintext amount a run would charge the treasury before deductions.
+    pub amount: u128,
+    /// Whether the treasury covers it; nothing was moved either way.
+    pub would_succeed: bool,
+}
+
+#[event]
+pub struct EqualityChecked {
     pub cvct_mint: Pubkey,
-    /// X25519 pubkey used to encrypt/decrypt this account's balance.
-    pub owner_enc_pubkey: [u8; 32],
-    /// Encrypted balance (1 ciphertext for u128).
-    pub balance: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
-    /// Nonce used with the encrypted balance.
-    pub balance_nonce: u128,
+    /// Whether encrypted total supply plus the unallocated pool equals total locked.
+    pub equal: bool,
 }
 
-impl CvctAccount {
-    pub const LEN: usize = 32 + 32 + 32 + (32 * ENCRYPTED_U128_CIPHERTEXTS) + 16;
+#[event]
+pub struct CircuitBreakerTripped {
+    pub cvct_mint: Pubkey,
 }
 
-#[queue_computation_accounts("init_mint_state", authority)]
+#[event]
+pub struct ZeroPaymentSkipped {
+    pub payroll_member: Pubkey,
+    /// Unchanged paid-through timestamp; the skipped periods remain owed.
+    pub last_paid: i64,
+}
+
+#[event]
+pub struct LargeWithdrawal {
+    pub cvct_account: Pubkey,
+    pub user: Pubkey,
+    /// Backing base units released from the vault.
+    pub amount: u64,
+}
+
+#[event]
+pub struct HealthReport {
+    pub cvct_mint: Pubkey,
+    /// Vault is the mint's PDA and records the same CVCT and backing mints.
+    pub vault_matches_mint: bool,
+    /// Backing token account is the vault's, for the backing mint, owned by the vault.
+    pub backing_account_matches: bool,
+    /// Org is bound to this mint and its treasury is an account of this mint owned by the
+    /// org; `None` when no org was passed.
+    pub org_treasury_matches: Option<bool>,
+    /// Encrypted supply plus the unallocated pool equals encrypted locked; only
+    /// meaningful for 1:1 mints.
+    pub supply_equals_locked: bool,
+}
+
+#[event]
+pub struct MemberOnboarded {
+    pub payroll_member: Pubkey,
+    pub cvct_account: Pubkey,
+    /// False when the treasury could not cover the bonus; the account starts at zero.
+    pub bonus_paid: bool,
+}
+
+#[event]
+pub struct ForcedPayment {
+    pub payroll_member: Pubkey,
+    /// Org authority that forced the payment.
+    pub authority: Pubkey,
+    pub periods: u64,
+    pub amount: u128,
+    /// New `last_paid`; scheduled runs pay nothing until it is reached.
+    pub paid_through: i64,
+}
+
+#[event]
+pub struct BalanceHandle {
+    pub cvct_account: Pubkey,
+    /// Layout version the fields were read from.
+    pub version: u8,
+    pub ciphertexts: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+    pub nonce: u128,
+    /// X25519 pubkey to decrypt with.
+    pub enc_pubkey: [u8; 32],
+}
+
+#[event]
+pub struct TransferResult {
+    pub from_cvct_account: Pubkey,
+    pub to_cvct_account: Pubkey,
+    /// False when the sender couldn't cover any of the amount and nothing moved.
+    pub moved: bool,
+}
+
+#[event]
+pub struct MembersImported {
+    pub payroll: Pubkey,
+    pub count: u32,
+}
+
+#[event]
+pub struct ComputationSwept {
+    pub computation_offset: u64,
+    pub kind: ComputationKind,
+    /// Backing tokens returned to the initiator.
+    pub refunded: u64,
+}
+
+#[event]
+pub struct BalanceRevealed {
+    pub cvct_account: Pubkey,
+    /// Balance encrypted for the viewer key passed to `reveal_to`.
+    pub ciphertexts: [[u8; 32]; ENCRYPTED_U128_CIPHERTEXTS],
+    pub nonce: u128,
+}
+
+#[event]
+pub struct MemberDueStatus {
+    pub payroll_member: Pubkey,
+    /// Whether a run queued now would pay: payroll and member active, the mint `Normal`
+    /// and a nonzero amount owed.
+    pub is_due: bool,
+    /// Periods that run would pay, after `max_periods_per_run`.
+    pub periods_owed: u64,
+    pub next_due_at: i64,
+}
+
+#[event]
+pub struct PaymentQuote {
+    pub payroll_member: Pubkey,
+    /// Time the quote was priced at, as passed by the caller.
+    pub now: i64,
+    /// Periods a run at `now` would pay, after `max_periods_per_run`.
+    pub periods_owed: u64,
+    pub amount: u128,
+    /// Where the member's schedule would move to; unchanged when nothing is owed.
+    pub paid_through: i64,
+}
+
+#[event]
+pub struct DeductionApplied {
+    pub payroll_member: Pubkey,
+}
+
+#[event]
+pub struct PayrollAutoPaused {
+    pub payroll: Pubkey,
+    /// Treasury that ran out; top it up, then `resume_payroll`.
+    pub treasury: Pubkey,
+}
+
+#[event]
+pub struct MemberWalletUpdated {
+    pub payroll_member: Pubkey,
+    pub old_wallet: Pubkey,
+    pub new_wallet: Pubkey,
+}
+
+#[event]
+pub struct RateInBand {
+    pub payroll_member: Pubkey,
+    pub low: u64,
+    pub high: u64,
+    pub in_band: bool,
+}
+
+#[event]
+pub struct AuthorityAction {
+    pub org: Pubkey,
+    pub action_kind: AuthorityActionKind,
+    /// Signer that took the action.
+    pub actor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PaymentClawedBack {
+    pub payroll_member: Pubkey,
+    pub amount: u64,
+    /// False when the member's spendable balance couldn't cover it; nothing moved.
+    pub ok: bool,
+}
+
+#[event]
+pub struct PaymentProposed {
+    pub pending_payment: Pubkey,
+    pub payroll_member: Pubkey,
+    pub proposer: Pubkey,
+    pub amount: u128,
+}
+
+#[event]
+pub struct PaymentApproved {
+    pub pending_payment: Pubkey,
+    pub payroll_member: Pubkey,
+    pub approver: Pubkey,
+    pub amount: u128,
+}
+
+#[event]
+pub struct BonusAccrued {
+    pub payroll_member: Pubkey,
+}
+
+#[event]
+pub struct BonusClaimed {
+    pub payroll_member: Pubkey,
+    /// False when the treasury couldn't cover the bonus; it stays accrued.
+    pub paid: bool,
+}
+
+#[event]
+pub struct Withdrawn {
+    pub cvct_account: Pubkey,
+    /// SPL token account that received the backing tokens.
+    pub recipient_token_account: Pubkey,
+    pub amount: u64,
+}
+
+#[init_computation_definition_accounts("transfer_with_fee", payer)]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct InitializeCvctMint<'info> {
+pub struct InitTransferWithFeeCompDef<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = authority,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    /// Arcium signer PDA used to sign the queued computation.
-    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
-    #[account(address = derive_mxe_pda!())]
-    /// MXE account identifies the Arcium execution environment.
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
     pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_MINT_STATE))]
-    /// On-chain computation definition for `init_mint_state`.
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// Cluster state used for output verification.
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
-    /// Fee pool used by Arcium.
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
-    /// Arcium clock account.
-    pub clock_account: Box<Account<'info, ClockAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("credit_from_vault", payer)]
+#[derive(Accounts)]
+pub struct InitCreditFromVaultCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
     pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("lock_balance", payer)]
+#[derive(Accounts)]
+pub struct InitLockBalanceCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
     pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + CvctMint::LEN,
-        seeds = [b"cvct_mint", authority.key().as_ref()],
-        bump,
-    )]
-    /// CVCT mint metadata (encrypted totals updated by callback).
-    pub cvct_mint: Box<Account<'info, CvctMint>>,
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + Vault::LEN,
-        seeds = [b"vault", cvct_mint.key().as_ref()],
-        bump,
-    )]
-    /// Vault metadata (encrypted total locked updated by callback).
-    pub vault: Box<Account<'info, Vault>>,
-    /// SPL mint that backs CVCT.
-    pub backing_mint: Account<'info, Mint>,
-    #[account(
-        init,
-        payer = authority,
-        associated_token::mint = backing_mint,
-        associated_token::authority = vault,
-    )]
-    /// ATA owned by vault PDA to hold backing SPL tokens.
-    pub vault_token_account: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("unlock_balance", payer)]
+#[derive(Accounts)]
+pub struct InitUnlockBalanceCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("bootstrap_mint_state", payer)]
+#[derive(Accounts)]
+pub struct InitBootstrapMintStateCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("snapshot_balance", payer)]
+#[derive(Accounts)]
+pub struct InitSnapshotBalanceCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("run_payroll", payer)]
+#[derive(Accounts)]
+pub struct InitRunPayrollCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("is_zero", payer)]
+#[derive(Accounts)]
+pub struct InitIsZeroCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("init_account_state_batch", payer)]
+#[derive(Accounts)]
+pub struct InitAccountStateBatchCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[callback_accounts("init_mint_state")]
+#[init_computation_definition_accounts("can_pay_member", payer)]
 #[derive(Accounts)]
-pub struct InitMintStateCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_MINT_STATE))]
-    /// Same computation definition as queued instruction.
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = derive_mxe_pda!())]
-    /// MXE account for this computation.
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// Cluster account used to verify Arcium output signature.
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
+pub struct InitCanPayMemberCompDef<'info> {
     #[account(mut)]
-    /// CVCT mint to update encrypted total supply.
-    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
     #[account(mut)]
-    /// Vault to update encrypted total locked.
-    pub vault: Box<Account<'info, Vault>>,
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[queue_computation_accounts("init_account_state", owner)]
+#[init_computation_definition_accounts("is_equal", payer)]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct InitializeCvctAccount<'info> {
+pub struct InitIsEqualCompDef<'info> {
     #[account(mut)]
-    pub owner: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = owner,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    /// Arcium signer PDA used to sign the queued computation.
-    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
-    #[account(address = derive_mxe_pda!())]
-    /// MXE account identifies the Arcium execution environment.
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
     pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_ACCOUNT_STATE))]
-    /// On-chain computation definition for `init_account_state`.
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// Cluster state used for output verification.
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
-    /// Fee pool used by Arcium.
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
-    /// Arcium clock account.
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
     pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        init,
-        payer = owner,
-        space = 8 + CvctAccount::LEN,
-        seeds = [b"cvct_account", cvct_mint.key().as_ref(), owner.key().as_ref()],
-        bump,
-    )]
-    /// CVCT account metadata (encrypted balance updated by callback).
-    pub cvct_account: Box<Account<'info, CvctAccount>>,
-    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    pub system_program: Program<'info, System>,
 }
 
-#[callback_accounts("init_account_state")]
+#[init_computation_definition_accounts("backed_supply", payer)]
 #[derive(Accounts)]
-pub struct InitAccountStateCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_INIT_ACCOUNT_STATE))]
-    /// Same computation definition as queued instruction.
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = derive_mxe_pda!())]
-    /// MXE account for this computation.
+pub struct InitBackedSupplyCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
     pub mxe_account: Box<Account<'info, MXEAccount>>,
-    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// Cluster account used to verify Arcium output signature.
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
     #[account(mut)]
-    /// CVCT account to update encrypted balance.
-    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[queue_computation_accounts("deposit_and_mint", user)]
+#[init_computation_definition_accounts("circuit_breaker", payer)]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct DepositAndMint<'info> {
+pub struct InitCircuitBreakerCompDef<'info> {
     #[account(mut)]
-    pub user: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = user,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    /// Arcium signer PDA used to sign the queued computation.
-    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
-    #[account(address = derive_mxe_pda!())]
-    /// MXE account identifies the Arcium execution environment.
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
     pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DEPOSIT_AND_MINT))]
-    /// On-chain computation definition for `deposit_and_mint`.
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// Cluster state used for output verification.
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
-    /// Fee pool used by Arcium.
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
-    /// Arcium clock account.
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
     pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        mut,
-        constraint = cvct_mint.authority == user.key() @ ErrorCode::Unauthorized,
-    )]
-    pub cvct_mint: Box<Account<'info, CvctMint>>,
-    #[account(
-        mut,
-        seeds = [b"vault", cvct_mint.key().as_ref()],
-        bump,
-        constraint = vault.cvct_mint == cvct_mint.key() @ ErrorCode::InvalidVault,
-    )]
-    pub vault: Box<Account<'info, Vault>>,
-    #[account(
-        mut,
-        seeds = [b"cvct_account", cvct_mint.key().as_ref(), user.key().as_ref()],
-        bump,
-        constraint = cvct_account.cvct_mint == cvct_mint.key(),
-        constraint = cvct_account.owner == user.key() @ ErrorCode::Unauthorized,
-    )]
-    pub cvct_account: Box<Account<'info, CvctAccount>>,
-    #[account(
-        mut,
-        constraint = user_token_account.mint == cvct_mint.backing_mint,
-        constraint = user_token_account.owner == user.key(),
-    )]
-    pub user_token_account: Account<'info, TokenAccount>,
-    #[account(
-        mut,
-        constraint = vault_token_account.key() == vault.backing_token_account,
-    )]
-    pub vault_token_account: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
-#[callback_accounts("deposit_and_mint")]
+#[init_computation_definition_accounts("accrue_payroll", payer)]
 #[derive(Accounts)]
-pub struct DepositAndMintCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DEPOSIT_AND_MINT))]
-    /// Same computation definition as queued instruction.
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = derive_mxe_pda!())]
-    /// MXE account for this computation.
+pub struct InitAccruePayrollCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
     pub mxe_account: Box<Account<'info, MXEAccount>>,
-    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// Cluster account used to verify Arcium output signature.
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
     #[account(mut)]
-    /// CVCT account to update encrypted balance.
-    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("claim_accrued", payer)]
+#[derive(Accounts)]
+pub struct InitClaimAccruedCompDef<'info> {
     #[account(mut)]
-    /// CVCT mint to update encrypted total supply.
-    pub cvct_mint: Box<Account<'info, CvctMint>>,
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
     #[account(mut)]
-    /// Vault to update encrypted total locked.
-    pub vault: Box<Account<'info, Vault>>,
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[queue_computation_accounts("burn_and_withdraw", user)]
+#[init_computation_definition_accounts("onboard_member", payer)]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct BurnAndWithdraw<'info> {
+pub struct InitOnboardMemberCompDef<'info> {
     #[account(mut)]
-    pub user: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = user,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    /// Arcium signer PDA used to sign the queued computation.
-    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
-    #[account(address = derive_mxe_pda!())]
-    /// MXE account identifies the Arcium execution environment.
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
     pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_BURN_AND_WITHDRAW))]
-    /// On-chain computation definition for `burn_and_withdraw`.
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// Cluster state used for output verification.
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
-    /// Fee pool used by Arcium.
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
-    /// Arcium clock account.
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
-    pub arcium_program: Program<'info, Arcium>,
     #[account(mut)]
-    pub cvct_mint: Box<Account<'info, CvctMint>>,
-    #[account(
-        mut,
-        seeds = [b"vault", cvct_mint.key().as_ref()],
-        bump,
-        constraint = vault.cvct_mint == cvct_mint.key() @ ErrorCode::InvalidVault,
-    )]
-    pub vault: Box<Account<'info, Vault>>,
-    #[account(
-        mut,
-        seeds = [b"cvct_account", cvct_mint.key().as_ref(), user.key().as_ref()],
-        bump,
-        constraint = cvct_account.cvct_mint == cvct_mint.key(),
-        constraint = cvct_account.owner == user.key() @ ErrorCode::Unauthorized,
-    )]
-    pub cvct_account: Box<Account<'info, CvctAccount>>,
-    #[account(
-        mut,
-        constraint = user_token_account.mint == cvct_mint.backing_mint,
-        constraint = user_token_account.owner == user.key(),
-    )]
-    pub user_token_account: Account<'info, TokenAccount>,
-    #[account(
-        mut,
-        constraint = vault_token_account.key() == vault.backing_token_account,
-    )]
-    pub vault_token_account: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[callback_accounts("burn_and_withdraw")]
+#[init_computation_definition_accounts("run_hourly_payroll", payer)]
 #[derive(Accounts)]
-pub struct BurnAndWithdrawCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_BURN_AND_WITHDRAW))]
-    /// Same computation definition as queued instruction.
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = derive_mxe_pda!())]
-    /// MXE account for this computation.
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// Cluster account used to verify Arcium output signature.
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
+pub struct InitRunHourlyPayrollCompDef<'info> {
     #[account(mut)]
-    /// CVCT account to update encrypted balance.
-    pub cvct_account: Box<Account<'info, CvctAccount>>,
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
     #[account(mut)]
-    /// CVCT mint to update encrypted total supply.
-    pub cvct_mint: Box<Account<'info, CvctMint>>,
-    #[account(
-        mut,
-        seeds = [b"vault", cvct_mint.key().as_ref()],
-        bump,
-    )]
-    /// Vault to update encrypted total locked and sign SPL transfer.
-    pub vault: Box<Account<'info, Vault>>,
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+#[init_computation_definition_accounts("lock_collateral", payer)]
+#[derive(Accounts)]
+pub struct InitLockCollateralCompDef<'info> {
     #[account(mut)]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
     #[account(mut)]
-    pub user_token_account: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[queue_computation_accounts("transfer_cvct", user)]
+#[init_computation_definition_accounts("transfer_with_receipt", payer)]
 #[derive(Accounts)]
-#[instruction(computation_offset: u64)]
-pub struct TransferCvct<'info> {
+pub struct InitTransferWithReceiptCompDef<'info> {
     #[account(mut)]
-    pub user: Signer<'info>,
-    #[account(
-        init_if_needed,
-        space = 9,
-        payer = user,
-        seeds = [&SIGN_PDA_SEED],
-        bump,
-        address = derive_sign_pda!(),
-    )]
-    /// Arcium signer PDA used to sign the queued computation.
-    pub sign_pda_account: Box<Account<'info, ArciumSignerAccount>>,
-    #[account(address = derive_mxe_pda!())]
-    /// MXE account identifies the Arcium execution environment.
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
     pub mxe_account: Box<Account<'info, MXEAccount>>,
-    #[account(
-        mut,
-        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// CHECK: mempool_account, checked by the arcium program.
-    pub mempool_account: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// CHECK: executing_pool, checked by the arcium program.
-    pub executing_pool: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// CHECK: computation_account, checked by the arcium program.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TRANSFER_CVCT))]
-    /// On-chain computation definition for `transfer_cvct`.
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(
-        mut,
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// Cluster state used for output verification.
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
-    /// Fee pool used by Arcium.
-    pub pool_account: Box<Account<'info, FeePool>>,
-    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
-    /// Arcium clock account.
-    pub clock_account: Box<Account<'info, ClockAccount>>,
-    pub system_program: Program<'info, System>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
     pub arcium_program: Program<'info, Arcium>,
-    #[account(
-        mut,
-        constraint = from_cvct_account.owner == user.key() @ ErrorCode::Unauthorized,
-    )]
-    pub from_cvct_account: Box<Account<'info, CvctAccount>>,
-    #[account(
-        mut,
-        constraint = to_cvct_account.cvct_mint == from_cvct_account.cvct_mint,
-    )]
-    pub to_cvct_account: Box<Account<'info, CvctAccount>>,
+    pub system_program: Program<'info, System>,
 }
 
-#[callback_accounts("transfer_cvct")]
+#[init_computation_definition_accounts("reveal_to", payer)]
 #[derive(Accounts)]
-pub struct TransferCvctCallback<'info> {
-    pub arcium_program: Program<'info, Arcium>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_TRANSFER_CVCT))]
-    /// Same computation definition as queued instruction.
-    pub comp_def_account: Box<Account<'info, ComputationDefinitionAccount>>,
-    #[account(address = derive_mxe_pda!())]
-    /// MXE account for this computation.
-    pub mxe_account: Box<Account<'info, MXEAccount>>,
-    /// CHECK: computation_account, checked by arcium program via constraints in the callback context.
-    pub computation_account: UncheckedAccount<'info>,
-    #[account(
-        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
-    )]
-    /// Cluster account used to verify Arcium output signature.
-    pub cluster_account: Box<Account<'info, Cluster>>,
-    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
-    /// CHECK: instructions_sysvar, checked by the account constraint
-    pub instructions_sysvar: AccountInfo<'info>,
+pub struct InitRevealToCompDef<'info> {
     #[account(mut)]
-    /// Sender CVCT account to update encrypted balance.
-    pub from_cvct_account: Box<Account<'info, CvctAccount>>,
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    /// MXE account required to initialize comp def.
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
     #[account(mut)]
-    /// Recipient CVCT account to update encrypted balance.
-    pub to_cvct_account: Box<Account<'info, CvctAccount>>,
+    /// CHECK: comp_def_account, checked by arcium program.
+    /// Can't check it here as it's not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-#[init_computation_definition_accounts("init_mint_state", payer)]
+#[init_computation_definition_accounts("apply_deduction", payer)]
 #[derive(Accounts)]
-pub struct InitMintStateCompDef<'info> {
+pub struct InitApplyDeductionCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(mut, address = derive_mxe_pda!())]
@@ -1232,9 +14634,9 @@ pub struct InitMintStateCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[init_computation_definition_accounts("init_account_state", payer)]
+#[init_computation_definition_accounts("simulate_payroll_run", payer)]
 #[derive(Accounts)]
-pub struct InitAccountStateCompDef<'info> {
+pub struct InitSimulatePayrollRunCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(mut, address = derive_mxe_pda!())]
@@ -1248,9 +14650,9 @@ pub struct InitAccountStateCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[init_computation_definition_accounts("deposit_and_mint", payer)]
+#[init_computation_definition_accounts("clawback", payer)]
 #[derive(Accounts)]
-pub struct InitDepositAndMintCompDef<'info> {
+pub struct InitClawbackCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(mut, address = derive_mxe_pda!())]
@@ -1264,9 +14666,9 @@ pub struct InitDepositAndMintCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[init_computation_definition_accounts("burn_and_withdraw", payer)]
+#[init_computation_definition_accounts("accrue_bonus", payer)]
 #[derive(Accounts)]
-pub struct InitBurnAndWithdrawCompDef<'info> {
+pub struct InitAccrueBonusCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(mut, address = derive_mxe_pda!())]
@@ -1280,9 +14682,9 @@ pub struct InitBurnAndWithdrawCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[init_computation_definition_accounts("transfer_cvct", payer)]
+#[init_computation_definition_accounts("claim_bonus", payer)]
 #[derive(Accounts)]
-pub struct InitTransferCvctCompDef<'info> {
+pub struct InitClaimBonusCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(mut, address = derive_mxe_pda!())]
@@ -1310,4 +14712,120 @@ pub enum ErrorCode {
     ZeroAmount,
     #[msg("Invalid amount")]
     InvalidAmount,
+    #[msg("Total locked exceeds the vault token balance")]
+    InvariantViolation,
+    #[msg("Output nonce must be greater than the stored nonce")]
+    NonceReused,
+    #[msg("Emergency withdrawal delay has not elapsed")]
+    EmergencyTimelockActive,
+    #[msg("Mint charges a transfer fee; use transfer_cvct_with_fee")]
+    TransferFeeRequired,
+    #[msg("Transfer fee cannot exceed 10000 bps")]
+    InvalidFeeBps,
+    #[msg("Fee collector must differ from sender and recipient")]
+    InvalidFeeCollector,
+    #[msg("Locked balance cannot be released yet")]
+    BalanceStillLocked,
+    #[msg("A CVCT mint already exists for this backing mint")]
+    MintAlreadyExistsForBacking,
+    #[msg("Too many open balance snapshots for this account")]
+    SnapshotLimitReached,
+    #[msg("Account is not a resizable cvct account")]
+    InvalidAccountSize,
+    #[msg("Payroll interval must be positive")]
+    InvalidInterval,
+    #[msg("Payroll is paused")]
+    PayrollInactive,
+    #[msg("Payroll member is inactive")]
+    MemberInactive,
+    #[msg("No full payroll interval has elapsed")]
+    PaymentNotDue,
+    #[msg("Payroll must be paused before closing")]
+    PayrollStillActive,
+    #[msg("Deposit with this idempotency key was already processed")]
+    DuplicateDeposit,
+    #[msg("Backing mint decimals do not match the CVCT mint")]
+    DecimalsMismatch,
+    #[msg("Account balance must be zero to close")]
+    NonZeroBalance,
+    #[msg("Batch inputs or accounts are malformed")]
+    InvalidBatch,
+    #[msg("Unwrap requires a native SOL backing mint and the user's own token account")]
+    InvalidUnwrap,
+    #[msg("Mint is paused")]
+    MintPaused,
+    #[msg("Exchange rate is zero or not supported by this instruction")]
+    InvalidExchangeRate,
+    #[msg("Account layout version has no upgrade path")]
+    UnsupportedAccountVersion,
+    #[msg("Transfer amount exceeds the mint maximum")]
+    TransferLimitExceeded,
+    #[msg("Payroll pays reported hours and must use the hourly run path, or vice versa")]
+    HourlyPayroll,
+    #[msg("Run would exceed the runner allowance")]
+    RunnerLimitExceeded,
+    #[msg("Amount does not fit in an SPL token amount")]
+    AmountTooLarge,
+    #[msg("Computation has not been pending long enough to sweep")]
+    ComputationNotStale,
+    #[msg("Not enough close approvers signed")]
+    CloseApprovalsMissing,
+    #[msg("Close approvers must be distinct and cover the threshold")]
+    InvalidCloseApprovers,
+    #[msg("Encrypted subtraction would have underflowed")]
+    BalanceUnderflow,
+    #[msg("Rounded backing release exceeds the CVCT burned")]
+    CollateralLeak,
+    #[msg("Payroll has reached its member cap")]
+    MemberLimitReached,
+    #[msg("Spender is already authorized or was never authorized")]
+    InvalidSpender,
+    #[msg("Organization already has the maximum number of spenders")]
+    SpenderLimitReached,
+    #[msg("CVCT mint is not approved in the platform registry")]
+    MintNotApproved,
+    #[msg("Mint registry is full")]
+    MintRegistryFull,
+    #[msg("CVCT mint is already approved")]
+    MintAlreadyApproved,
+    #[msg("Unlock time must be in the future and within the maximum lock duration")]
+    InvalidLockDuration,
+    #[msg("Clawback window closed or disabled")]
+    ClawbackWindowClosed,
+    #[msg("Clawback exceeds the last payment")]
+    ClawbackExceedsPayment,
+    #[msg("Payment exceeds the approval threshold; propose it instead")]
+    ApprovalRequired,
+    #[msg("Payment is within the approval threshold; run it directly")]
+    ApprovalNotRequired,
+    #[msg("Proposer cannot approve their own payment")]
+    ProposerCannotApprove,
+    #[msg("Member was paid since the proposal")]
+    PaymentSuperseded,
+    #[msg("Close open balance snapshots before closing the account")]
+    OpenSnapshots,
+    #[msg("Pending operation has not been failed by sweep_stale_computation")]
+    ComputationNotFailed,
+    #[msg("A run for this member is still in flight")]
+    RunInFlight,
+    #[msg("A payment from this payroll is still in flight")]
+    DisbursementInFlight,
+    #[msg("Computations are still pending")]
+    ComputationsPending,
+    #[msg("Computation offset does not match the account op counter")]
+    InvalidComputationOffset,
+    #[msg("Computation was failed by sweep_stale_computation")]
+    ComputationFailed,
+    #[msg("Timeout must be positive")]
+    InvalidTimeout,
+    #[msg("A deduction for this member is still in flight")]
+    DeductionInFlight,
+    #[msg("Organization encryption key is already set")]
+    OrgEncPubkeyAlreadySet,
+    #[msg("Another computation on this account is still in flight")]
+    AccountUpdateInFlight,
+    #[msg("Sender and recipient must be different accounts")]
+    SelfTransfer,
+    #[msg("Backed supply is out of date; refresh it before checking equality")]
+    StaleBackedSupply,
 }
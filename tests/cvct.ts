@@ -10,7 +10,7 @@ import {
   getOrCreateAssociatedTokenAccount,
   mintTo,
 } from "@solana/spl-token";
-import { randomBytes } from "crypto";
+import { createHash, randomBytes } from "crypto";
 import {
   awaitComputationFinalization,
   buildFinalizeCompDefTx,
@@ -38,6 +38,18 @@ const COMP_DEF_ACCOUNT = "init_account_state";
 const COMP_DEF_DEPOSIT = "deposit_and_mint";
 const COMP_DEF_BURN = "burn_and_withdraw";
 const COMP_DEF_TRANSFER = "transfer_cvct";
+const COMP_DEF_CREDIT_FROM_VAULT = "credit_from_vault";
+const COMP_DEF_LOCK_BALANCE = "lock_balance";
+const COMP_DEF_LOCK_COLLATERAL = "lock_collateral";
+const COMP_DEF_IS_EQUAL = "is_equal";
+const COMP_DEF_BACKED_SUPPLY = "backed_supply";
+const COMP_DEF_RUN_PAYROLL = "run_payroll";
+const COMP_DEF_ONBOARD_MEMBER = "onboard_member";
+const COMP_DEF_SIMULATE_PAYROLL_RUN = "simulate_payroll_run";
+const COMP_DEF_CLAWBACK = "clawback";
+const COMP_DEF_ACCRUE_BONUS = "accrue_bonus";
+const COMP_DEF_CLAIM_BONUS = "claim_bonus";
+const COMP_DEF_APPLY_DEDUCTION = "apply_deduction";
 
 // Helper: produce a random 128-bit nonce as both bytes and BN.
 function randomNonce(): { bytes: Uint8Array; bn: anchor.BN } {
@@ -48,6 +60,48 @@ function randomNonce(): { bytes: Uint8Array; bn: anchor.BN } {
   };
 }
 
+// Helper: next output nonce, strictly above the stored one as the program requires.
+function nextNonce(current: anchor.BN): { bytes: Uint8Array; bn: anchor.BN } {
+  const bn = current.add(new anchor.BN(1)).add(new anchor.BN(randomBytes(4)));
+  return {
+    bytes: Uint8Array.from(bn.toArray("le", 16)),
+    bn,
+  };
+}
+
+// Helper: the offset a queue instruction requires against `account`, derived from
+// its op counter exactly as `computation_offset_for` does on-chain.
+function computationOffsetFor(
+  account: PublicKey,
+  opCounter: anchor.BN,
+): anchor.BN {
+  const hash = createHash("sha256")
+    .update("cvct_op")
+    .update(account.toBuffer())
+    .update(opCounter.toArrayLike(Buffer, "le", 8))
+    .digest();
+  return new anchor.BN(hash.subarray(0, 8), "le");
+}
+
+// Helper: the next offset for a CvctAccount or CvctMint; accounts the instruction
+// is about to create start from counter 0.
+async function nextComputationOffset(
+  program: Program<Cvct>,
+  account: PublicKey,
+  kind: "cvctAccount" | "cvctMint" = "cvctAccount",
+): Promise<anchor.BN> {
+  const state = await program.account[kind].fetchNullable(account);
+  return computationOffsetFor(account, state?.opCounter ?? new anchor.BN(0));
+}
+
+// Helper: PendingOp PDA the program writes while a computation is in flight.
+function pendingOpPda(programId: PublicKey, offset: anchor.BN): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("pending_op"), offset.toArrayLike(Buffer, "le", 8)],
+    programId,
+  )[0];
+}
+
 async function getMXEPublicKeyWithRetry(
   provider: anchor.AnchorProvider,
   programId: PublicKey,
@@ -83,6 +137,72 @@ function decryptSharedU128(
   return cipher.decrypt([Array.from(ciphertext)], nonce)[0];
 }
 
+// Helper: client-side encryption of `value` for an `Enc<Shared, u128>` input.
+function encryptSharedU128(
+  value: bigint,
+  mxePublicKey: Uint8Array,
+): { ciphertext: number[]; publicKey: number[]; nonce: anchor.BN } {
+  const secretKey = x25519.utils.randomSecretKey();
+  const sharedSecret = x25519.getSharedSecret(secretKey, mxePublicKey);
+  const nonce = randomNonce();
+  const [ciphertext] = new RescueCipher(sharedSecret).encrypt(
+    [value],
+    nonce.bytes,
+  );
+  return {
+    ciphertext,
+    publicKey: Array.from(x25519.getPublicKey(secretKey)),
+    nonce: nonce.bn,
+  };
+}
+
+// Helper: Arcium accounts every computation-queueing instruction takes.
+function arciumAccounts(
+  programId: PublicKey,
+  computationOffset: anchor.BN,
+  circuit: string,
+) {
+  const arciumEnv = getArciumEnv();
+  const arciumProgramId = getArciumProgramId();
+  return {
+    mxeAccount: getMXEAccAddress(programId),
+    mempoolAccount: getMempoolAccAddress(arciumEnv.arciumClusterOffset),
+    executingPool: getExecutingPoolAccAddress(arciumEnv.arciumClusterOffset),
+    computationAccount: getComputationAccAddress(
+      arciumEnv.arciumClusterOffset,
+      computationOffset,
+    ),
+    compDefAccount: getCompDefAccAddress(
+      programId,
+      Buffer.from(getCompDefAccOffset(circuit)).readUInt32LE(),
+    ),
+    clusterAccount: getClusterAccAddress(arciumEnv.arciumClusterOffset),
+    poolAccount: PublicKey.findProgramAddressSync(
+      [Buffer.from("FeePool")],
+      arciumProgramId,
+    )[0],
+    clockAccount: PublicKey.findProgramAddressSync(
+      [Buffer.from("ClockAccount")],
+      arciumProgramId,
+    )[0],
+    arciumProgram: arciumProgramId,
+    systemProgram: anchor.web3.SystemProgram.programId,
+  };
+}
+
+// Helper: resolves with the next `name` event the program emits.
+async function awaitEvent<E extends keyof anchor.IdlEvents<Cvct>>(
+  program: Program<Cvct>,
+  name: E,
+): Promise<anchor.IdlEvents<Cvct>[E]> {
+  let listenerId = 0;
+  const event = await new Promise<anchor.IdlEvents<Cvct>[E]>((resolve) => {
+    listenerId = program.addEventListener(name, (event) => resolve(event));
+  });
+  await program.removeEventListener(listenerId);
+  return event;
+}
+
 describe("Cvct", () => {
   // Explicit local RPC connection avoids env/provider issues under `arcium test`.
   const connection = new anchor.web3.Connection("http://127.0.0.1:8899", {
@@ -170,7 +290,11 @@ describe("Cvct", () => {
 
     // Arcium cluster offset and computation identifier.
     const arciumEnv = getArciumEnv();
-    const computationOffset = new anchor.BN(randomBytes(8));
+    const computationOffset = await nextComputationOffset(
+      program,
+      cvctMintPda,
+      "cvctMint",
+    );
 
     const mxePublicKey = await getMXEPublicKeyWithRetry(
       provider,
@@ -205,12 +329,18 @@ describe("Cvct", () => {
           Array.from(authorityPubkey),
           authorityNonce.bn,
           vaultNonce.bn,
+          new anchor.BN(1),
+          new anchor.BN(1),
         )
         .accountsPartial({
           authority: payer.publicKey,
           cvctMint: cvctMintPda,
           vault: vaultPda,
           backingMint,
+          backingRecord: PublicKey.findProgramAddressSync(
+            [Buffer.from("backing"), backingMint.toBuffer()],
+            program.programId,
+          )[0],
           vaultTokenAccount,
           systemProgram: anchor.web3.SystemProgram.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -256,10 +386,14 @@ describe("Cvct", () => {
       program.programId,
     );
 
-    const accountComputationOffset = new anchor.BN(randomBytes(8));
+    const accountComputationOffset = await nextComputationOffset(
+      program,
+      cvctAccountPda,
+    );
     const accountEncKey = x25519.utils.randomSecretKey();
     const accountEncPubkey = x25519.getPublicKey(accountEncKey);
     const accountNonce = randomNonce();
+    const accountLockedNonce = randomNonce();
 
     const accountCompDefOffset = getCompDefAccOffset(COMP_DEF_ACCOUNT);
 
@@ -270,9 +404,11 @@ describe("Cvct", () => {
           accountComputationOffset,
           Array.from(accountEncPubkey),
           accountNonce.bn,
+          accountLockedNonce.bn,
         )
         .accountsPartial({
           owner: payer.publicKey,
+          feePayer: payer.publicKey,
           cvctAccount: cvctAccountPda,
           cvctMint: cvctMintPda,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -324,10 +460,14 @@ describe("Cvct", () => {
       program.programId,
     );
 
-    const recipientComputationOffset = new anchor.BN(randomBytes(8));
+    const recipientComputationOffset = await nextComputationOffset(
+      program,
+      recipientCvctAccountPda,
+    );
     const recipientEncKey = x25519.utils.randomSecretKey();
     const recipientEncPubkey = x25519.getPublicKey(recipientEncKey);
     const recipientNonce = randomNonce();
+    const recipientLockedNonce = randomNonce();
     const recipientCompDefOffset = getCompDefAccOffset(COMP_DEF_ACCOUNT);
 
     console.log("Queuing init_account_state computation for recipient");
@@ -337,9 +477,12 @@ describe("Cvct", () => {
           recipientComputationOffset,
           Array.from(recipientEncPubkey),
           recipientNonce.bn,
+          recipientLockedNonce.bn,
         )
         .accountsPartial({
           owner: recipient.publicKey,
+          // Sponsored: the test wallet covers Arcium fees and rent.
+          feePayer: payer.publicKey,
           cvctAccount: recipientCvctAccountPda,
           cvctMint: cvctMintPda,
           systemProgram: anchor.web3.SystemProgram.programId,
@@ -379,11 +522,35 @@ describe("Cvct", () => {
     const vaultBefore = await program.account.vault.fetch(vaultPda);
     const cvctAccountBefore = await program.account.cvctAccount.fetch(cvctAccountPda);
 
-    const depositComputationOffset = new anchor.BN(randomBytes(8));
+    // Declared space must match what was allocated on-chain.
+    for (const [pda, client] of [
+      [cvctMintPda, program.account.cvctMint],
+      [vaultPda, program.account.vault],
+      [cvctAccountPda, program.account.cvctAccount],
+    ] as const) {
+      const info = await provider.connection.getAccountInfo(pda);
+      expect(info?.data.length).to.equal(client.size);
+    }
+
+    const depositComputationOffset = await nextComputationOffset(
+      program,
+      cvctAccountPda,
+    );
     const depositAmount = 500_000;
-    const newBalanceNonce = randomNonce();
-    const newSupplyNonce = randomNonce();
-    const newLockedNonce = randomNonce();
+    const newBalanceNonce = nextNonce(cvctAccountBefore.balanceNonce);
+    const newSupplyNonce = nextNonce(cvctMintBefore.totalSupplyNonce);
+    const newLockedNonce = nextNonce(vaultBefore.totalLockedNonce);
+
+    // Idempotency receipt so a retried deposit can't move collateral twice.
+    const depositIdempotencyKey = randomBytes(32);
+    const [depositReceiptPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("deposit"),
+        payer.publicKey.toBuffer(),
+        depositIdempotencyKey,
+      ],
+      program.programId,
+    );
 
     const depositCompDefOffset = getCompDefAccOffset(COMP_DEF_DEPOSIT);
 
@@ -393,6 +560,7 @@ describe("Cvct", () => {
         .depositAndMint(
           depositComputationOffset,
           new anchor.BN(depositAmount),
+          Array.from(depositIdempotencyKey),
           Array.from(accountEncPubkey),
           cvctAccountBefore.balanceNonce,
           newBalanceNonce.bn,
@@ -404,13 +572,16 @@ describe("Cvct", () => {
           newLockedNonce.bn,
         )
         .accountsPartial({
+          pendingOp: pendingOpPda(program.programId, depositComputationOffset),
           user: payer.publicKey,
           cvctMint: cvctMintPda,
           vault: vaultPda,
           cvctAccount: cvctAccountPda,
           userTokenAccount: userTokenAccount.address,
+          backingMint,
           vaultTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
+          depositReceipt: depositReceiptPda,
           mxeAccount: getMXEAccAddress(program.programId),
           mempoolAccount: getMempoolAccAddress(arciumEnv.arciumClusterOffset),
           executingPool: getExecutingPoolAccAddress(
@@ -442,6 +613,12 @@ describe("Cvct", () => {
       "confirmed",
     );
 
+    // Callback clears the pending-op marker once it lands.
+    const depositPendingOp = await provider.connection.getAccountInfo(
+      pendingOpPda(program.programId, depositComputationOffset),
+    );
+    expect(depositPendingOp).to.be.null;
+
     const cvctMintAfterDeposit = await program.account.cvctMint.fetch(
       cvctMintPda,
     );
@@ -450,11 +627,20 @@ describe("Cvct", () => {
       cvctAccountPda,
     );
 
-    const burnComputationOffset = new anchor.BN(randomBytes(8));
+    // Wind-down mode: the burn below must still go through.
+    await program.methods
+      .setMintMode({ withdrawOnly: {} })
+      .accountsPartial({ authority: payer.publicKey, cvctMint: cvctMintPda })
+      .rpc({ commitment: "confirmed" });
+
+    const burnComputationOffset = await nextComputationOffset(
+      program,
+      cvctAccountPda,
+    );
     const burnAmount = 200_000;
-    const newBurnBalanceNonce = randomNonce();
-    const newBurnSupplyNonce = randomNonce();
-    const newBurnLockedNonce = randomNonce();
+    const newBurnBalanceNonce = nextNonce(cvctAccountAfterDeposit.balanceNonce);
+    const newBurnSupplyNonce = nextNonce(cvctMintAfterDeposit.totalSupplyNonce);
+    const newBurnLockedNonce = nextNonce(vaultAfterDeposit.totalLockedNonce);
 
     const burnCompDefOffset = getCompDefAccOffset(COMP_DEF_BURN);
 
@@ -464,8 +650,10 @@ describe("Cvct", () => {
         .burnAndWithdraw(
           burnComputationOffset,
           new anchor.BN(burnAmount),
+          false,
           Array.from(accountEncPubkey),
           cvctAccountAfterDeposit.balanceNonce,
+          cvctAccountAfterDeposit.lockedBalanceNonce,
           newBurnBalanceNonce.bn,
           Array.from(authorityPubkey),
           cvctMintAfterDeposit.totalSupplyNonce,
@@ -475,11 +663,14 @@ describe("Cvct", () => {
           newBurnLockedNonce.bn,
         )
         .accountsPartial({
+          pendingOp: pendingOpPda(program.programId, burnComputationOffset),
           user: payer.publicKey,
+          feePayer: payer.publicKey,
           cvctMint: cvctMintPda,
           vault: vaultPda,
           cvctAccount: cvctAccountPda,
           userTokenAccount: userTokenAccount.address,
+          recipientTokenAccount: null,
           vaultTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
           mxeAccount: getMXEAccAddress(program.programId),
@@ -513,6 +704,69 @@ describe("Cvct", () => {
       "confirmed",
     );
 
+    // Deposits stay blocked while the mint is withdraw-only.
+    const blockedDepositOffset = await nextComputationOffset(
+      program,
+      cvctAccountPda,
+    );
+    let blockedDepositError: unknown = null;
+    try {
+      await program.methods
+        .depositAndMint(
+          blockedDepositOffset,
+          new anchor.BN(depositAmount),
+          Array.from(randomBytes(32)),
+          Array.from(accountEncPubkey),
+          cvctAccountAfterDeposit.balanceNonce,
+          nextNonce(newBurnBalanceNonce.bn).bn,
+          Array.from(authorityPubkey),
+          cvctMintAfterDeposit.totalSupplyNonce,
+          nextNonce(newBurnSupplyNonce.bn).bn,
+          Array.from(authorityPubkey),
+          vaultAfterDeposit.totalLockedNonce,
+          nextNonce(newBurnLockedNonce.bn).bn,
+        )
+        .accountsPartial({
+          pendingOp: pendingOpPda(program.programId, blockedDepositOffset),
+          user: payer.publicKey,
+          cvctMint: cvctMintPda,
+          vault: vaultPda,
+          cvctAccount: cvctAccountPda,
+          userTokenAccount: userTokenAccount.address,
+          backingMint,
+          vaultTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          depositReceipt: null,
+          mxeAccount: getMXEAccAddress(program.programId),
+          mempoolAccount: getMempoolAccAddress(arciumEnv.arciumClusterOffset),
+          executingPool: getExecutingPoolAccAddress(
+            arciumEnv.arciumClusterOffset,
+          ),
+          computationAccount: getComputationAccAddress(
+            arciumEnv.arciumClusterOffset,
+            blockedDepositOffset,
+          ),
+          compDefAccount: getCompDefAccAddress(
+            program.programId,
+            Buffer.from(depositCompDefOffset).readUInt32LE(),
+          ),
+          clusterAccount: getClusterAccAddress(arciumEnv.arciumClusterOffset),
+          poolAccount,
+          clockAccount,
+          arciumProgram: arciumProgramId,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .rpc({ commitment: "confirmed" });
+    } catch (err) {
+      blockedDepositError = err;
+    }
+    expect(String(blockedDepositError)).to.include("MintPaused");
+
+    await program.methods
+      .setMintMode({ normal: {} })
+      .accountsPartial({ authority: payer.publicKey, cvctMint: cvctMintPda })
+      .rpc({ commitment: "confirmed" });
+
     // Fetch state after burn to get current nonces for transfer.
     const cvctAccountAfterBurn = await program.account.cvctAccount.fetch(
       cvctAccountPda,
@@ -521,10 +775,13 @@ describe("Cvct", () => {
       recipientCvctAccountPda,
     );
 
-    const transferComputationOffset = new anchor.BN(randomBytes(8));
+    const transferComputationOffset = await nextComputationOffset(
+      program,
+      cvctAccountPda,
+    );
     const transferAmount = 100_000;
-    const newFromNonce = randomNonce();
-    const newToNonce = randomNonce();
+    const newFromNonce = nextNonce(cvctAccountAfterBurn.balanceNonce);
+    const newToNonce = nextNonce(recipientCvctAccountBefore.balanceNonce);
     const transferCompDefOffset = getCompDefAccOffset(COMP_DEF_TRANSFER);
 
     console.log("Queuing transfer_cvct computation");
@@ -533,17 +790,22 @@ describe("Cvct", () => {
         .transferCvct(
           transferComputationOffset,
           new anchor.BN(transferAmount),
+          false,
           Array.from(accountEncPubkey),
           cvctAccountAfterBurn.balanceNonce,
+          cvctAccountAfterBurn.lockedBalanceNonce,
           newFromNonce.bn,
           Array.from(recipientEncPubkey),
           recipientCvctAccountBefore.balanceNonce,
           newToNonce.bn,
         )
         .accountsPartial({
+          pendingOp: pendingOpPda(program.programId, transferComputationOffset),
           user: payer.publicKey,
+          feePayer: payer.publicKey,
           fromCvctAccount: cvctAccountPda,
           toCvctAccount: recipientCvctAccountPda,
+          cvctMint: cvctMintPda,
           systemProgram: anchor.web3.SystemProgram.programId,
           mxeAccount: getMXEAccAddress(program.programId),
           mempoolAccount: getMempoolAccAddress(arciumEnv.arciumClusterOffset),
@@ -586,6 +848,8 @@ describe("Cvct", () => {
     console.log("vault", vault);
     console.log("cvct_account", cvctAccount);
     console.log("recipient_cvct_account", recipientCvctAccount);
+    // Each callback closed its PendingOp, so the sign PDA could be reclaimed.
+    expect(cvctMint.openPendingOps.toNumber()).to.equal(0);
 
     // Decrypt balances to confirm plaintext changes.
     const decryptedBalance = decryptSharedU128(
@@ -643,95 +907,2681 @@ describe("Cvct", () => {
     expect(Number(vaultTokenAfter.amount)).to.equal(
       depositAmount - burnAmount,
     );
-  });
-});
 
-async function initMintStateCompDef(
-  program: Program<Cvct>,
-  payer: anchor.Wallet,
-): Promise<void> {
-  // Compute PDA for computation definition account.
-  const baseSeedCompDefAcc = getArciumAccountBaseSeed(
-    "ComputationDefinitionAccount",
-  );
-  const offset = getCompDefAccOffset(COMP_DEF_MINT);
+    // Underfunded transfer with allow_partial sweeps the whole balance.
+    const sweepComputationOffset = await nextComputationOffset(
+      program,
+      cvctAccountPda,
+    );
+    const sweepFromNonce = nextNonce(cvctAccount.balanceNonce);
+    const sweepToNonce = nextNonce(recipientCvctAccount.balanceNonce);
 
-  const compDefPDA = PublicKey.findProgramAddressSync(
-    [baseSeedCompDefAcc, program.programId.toBuffer(), offset],
-    getArciumProgramId(),
-  )[0];
+    console.log("Queuing partial-fill transfer_cvct computation");
+    await rpcWithLogs(
+      program.methods
+        .transferCvct(
+          sweepComputationOffset,
+          new anchor.BN(depositAmount),
+          true,
+          Array.from(accountEncPubkey),
+          cvctAccount.balanceNonce,
+          cvctAccount.lockedBalanceNonce,
+          sweepFromNonce.bn,
+          Array.from(recipientEncPubkey),
+          recipientCvctAccount.balanceNonce,
+          sweepToNonce.bn,
+        )
+        .accountsPartial({
+          pendingOp: pendingOpPda(program.programId, sweepComputationOffset),
+          user: payer.publicKey,
+          feePayer: payer.publicKey,
+          fromCvctAccount: cvctAccountPda,
+          toCvctAccount: recipientCvctAccountPda,
+          cvctMint: cvctMintPda,
+          systemProgram: anchor.web3.SystemProgram.programId,
+          mxeAccount: getMXEAccAddress(program.programId),
+          mempoolAccount: getMempoolAccAddress(arciumEnv.arciumClusterOffset),
+          executingPool: getExecutingPoolAccAddress(
+            arciumEnv.arciumClusterOffset,
+          ),
+          computationAccount: getComputationAccAddress(
+            arciumEnv.arciumClusterOffset,
+            sweepComputationOffset,
+          ),
+          compDefAccount: getCompDefAccAddress(
+            program.programId,
+            Buffer.from(transferCompDefOffset).readUInt32LE(),
+          ),
+          clusterAccount: getClusterAccAddress(arciumEnv.arciumClusterOffset),
+          poolAccount,
+          clockAccount,
+          arciumProgram: arciumProgramId,
+        })
+        .rpc({ skipPreflight: true, commitment: "confirmed" }),
+      "transferCvct (partial)",
+      provider.connection,
+    );
 
-  // Initialize comp def on-chain (required once per circuit).
-  await rpcWithLogs(
-    program.methods
-      .initMintStateCompDef()
-      .accountsPartial({
-        compDefAccount: compDefPDA,
-        payer: payer.publicKey,
-        mxeAccount: getMXEAccAddress(program.programId),
-        arciumProgram: getArciumProgramId(),
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
-      .signers([payer.payer])
-      .rpc({
-        commitment: "confirmed",
-      }),
-    "initMintStateCompDef",
-    program.provider.connection,
-  );
+    await awaitComputationFinalization(
+      provider,
+      sweepComputationOffset,
+      program.programId,
+      "confirmed",
+    );
 
-  // Finalize comp def (registers compiled circuit).
-  const finalizeTx = await buildFinalizeCompDefTx(
-    program.provider as anchor.AnchorProvider,
-    Buffer.from(offset).readUInt32LE(),
-    program.programId,
-  );
+    const sweptAccount = await program.account.cvctAccount.fetch(
+      cvctAccountPda,
+    );
+    const sweptRecipient = await program.account.cvctAccount.fetch(
+      recipientCvctAccountPda,
+    );
+    const sweptBalance = decryptSharedU128(
+      Uint8Array.from(sweptAccount.balance[0]),
+      Buffer.from(sweptAccount.balanceNonce.toArray("le", 16)),
+      accountEncKey,
+      mxePublicKey,
+    );
+    const sweptRecipientBalance = decryptSharedU128(
+      Uint8Array.from(sweptRecipient.balance[0]),
+      Buffer.from(sweptRecipient.balanceNonce.toArray("le", 16)),
+      recipientEncKey,
+      mxePublicKey,
+    );
 
-  // Retry blockhash fetch to handle early localnet startup.
-  const latestBlockhash = await getLatestBlockhashWithRetry(
-    program.provider.connection,
-  );
-  finalizeTx.recentBlockhash = latestBlockhash.blockhash;
-  finalizeTx.lastValidBlockHeight = latestBlockhash.lastValidBlockHeight;
+    expect(sweptBalance).to.equal(BigInt(0));
+    expect(sweptRecipientBalance).to.equal(BigInt(depositAmount - burnAmount));
+  });
 
-  finalizeTx.sign(payer.payer);
+  it("rounds scaled burns down without leaking collateral", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const mxePublicKey = await getMXEPublicKeyWithRetry(
+      provider,
+      program.programId,
+    );
 
-  await rpcWithLogs(
-    program.provider.sendAndConfirm(finalizeTx),
-    "finalizeCompDef",
-    program.provider.connection,
-  );
-}
+    // 1000 CVCT base units per backing base unit, i.e. three extra decimals.
+    const rateNum = 1_000;
+    const mint = await setupMint(program, payer, rateNum, 1);
+    const owner = mint.authority;
+    const { pda: cvctAccountPda, encKey: accountEncKey } =
+      await initCvctAccount(program, payer, mint, owner);
+    // Deposit 5 backing units, minting 5 * rateNum CVCT.
+    const depositAmount = 5;
+    await depositTo(program, payer, mint, cvctAccountPda, depositAmount);
+    const userTokenAccount = await getAssociatedTokenAddress(
+      mint.backingMint,
+      owner.publicKey,
+    );
 
-async function initAccountStateCompDef(
-  program: Program<Cvct>,
-  payer: anchor.Wallet,
-): Promise<void> {
-  const baseSeedCompDefAcc = getArciumAccountBaseSeed(
-    "ComputationDefinitionAccount",
-  );
-  const offset = getCompDefAccOffset(COMP_DEF_ACCOUNT);
+    const cvctMintBefore = await program.account.cvctMint.fetch(mint.cvctMint);
+    const vaultBefore = await program.account.vault.fetch(mint.vault);
+    const accountBefore = await program.account.cvctAccount.fetch(
+      cvctAccountPda,
+    );
+    const scaledBurn = (computationOffset: anchor.BN, amount: number) =>
+      program.methods
+        .burnAndWithdraw(
+          computationOffset,
+          new anchor.BN(amount),
+          false,
+          Array.from(x25519.getPublicKey(accountEncKey)),
+          accountBefore.balanceNonce,
+          accountBefore.lockedBalanceNonce,
+          nextNonce(accountBefore.balanceNonce).bn,
+          Array.from(mint.authorityPubkey),
+          cvctMintBefore.totalSupplyNonce,
+          nextNonce(cvctMintBefore.totalSupplyNonce).bn,
+          Array.from(mint.authorityPubkey),
+          vaultBefore.totalLockedNonce,
+          nextNonce(vaultBefore.totalLockedNonce).bn,
+        )
+        .accountsPartial({
+          pendingOp: pendingOpPda(program.programId, computationOffset),
+          user: owner.publicKey,
+          feePayer: payer.publicKey,
+          cvctMint: mint.cvctMint,
+          vault: mint.vault,
+          cvctAccount: cvctAccountPda,
+          userTokenAccount,
+          recipientTokenAccount: null,
+          vaultTokenAccount: mint.vaultTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          ...arciumAccounts(
+            program.programId,
+            computationOffset,
+            COMP_DEF_BURN,
+          ),
+        })
+        .signers([owner]);
 
-  const compDefPDA = PublicKey.findProgramAddressSync(
-    [baseSeedCompDefAcc, program.programId.toBuffer(), offset],
-    getArciumProgramId(),
-  )[0];
+    // One base unit short of a whole backing unit releases nothing, so it's rejected.
+    let dustBurnError: unknown = null;
+    const dustBurnOffset = await nextComputationOffset(program, cvctAccountPda);
+    try {
+      await scaledBurn(dustBurnOffset, rateNum - 1).rpc({
+        commitment: "confirmed",
+      });
+    } catch (err) {
+      dustBurnError = err;
+    }
+    expect(String(dustBurnError)).to.include("ZeroAmount");
 
-  await rpcWithLogs(
-    program.methods
-      .initAccountStateCompDef()
-      .accountsPartial({
-        compDefAccount: compDefPDA,
-        payer: payer.publicKey,
-        mxeAccount: getMXEAccAddress(program.programId),
-        arciumProgram: getArciumProgramId(),
-        systemProgram: anchor.web3.SystemProgram.programId,
-      })
-      .signers([payer.payer])
-      .rpc({
+    // Just under two backing units' worth rounds down to one.
+    const burnAmount = 2 * rateNum - 1;
+    const burnComputationOffset = await nextComputationOffset(
+      program,
+      cvctAccountPda,
+    );
+    await rpcWithLogs(
+      scaledBurn(burnComputationOffset, burnAmount).rpc({
+        skipPreflight: true,
         commitment: "confirmed",
       }),
-    "initAccountStateCompDef",
+      "burnAndWithdrawScaled",
+      provider.connection,
+    );
+    await awaitComputationFinalization(
+      provider,
+      burnComputationOffset,
+      program.programId,
+      "confirmed",
+    );
+
+    const cvctMint = await program.account.cvctMint.fetch(mint.cvctMint);
+    const vault = await program.account.vault.fetch(mint.vault);
+    const cvctAccount = await program.account.cvctAccount.fetch(cvctAccountPda);
+    const decryptedBalance = decryptSharedU128(
+      Uint8Array.from(cvctAccount.balance[0]),
+      Buffer.from(cvctAccount.balanceNonce.toArray("le", 16)),
+      accountEncKey,
+      mxePublicKey,
+    );
+    const decryptedSupply = decryptSharedU128(
+      Uint8Array.from(cvctMint.totalSupply[0]),
+      Buffer.from(cvctMint.totalSupplyNonce.toArray("le", 16)),
+      mint.authorityKey,
+      mxePublicKey,
+    );
+    const decryptedLocked = decryptSharedU128(
+      Uint8Array.from(vault.totalLocked[0]),
+      Buffer.from(vault.totalLockedNonce.toArray("le", 16)),
+      mint.authorityKey,
+      mxePublicKey,
+    );
+
+    const expectedSupply = BigInt(depositAmount * rateNum - burnAmount);
+    expect(decryptedBalance).to.equal(expectedSupply);
+    expect(decryptedSupply).to.equal(expectedSupply);
+    expect(decryptedLocked).to.equal(BigInt(depositAmount - 1));
+
+    const userTokenAfter = await getAccount(
+      provider.connection,
+      userTokenAccount,
+    );
+    const vaultTokenAfter = await getAccount(
+      provider.connection,
+      mint.vaultTokenAccount,
+    );
+    expect(Number(userTokenAfter.amount)).to.equal(1);
+    expect(Number(vaultTokenAfter.amount)).to.equal(depositAmount - 1);
+    // The rounding remainder stays in the vault, so outstanding CVCT stays covered.
+    expect(
+      BigInt(vaultTokenAfter.amount) * BigInt(rateNum) >= decryptedSupply,
+    ).to.equal(true);
+  });
+
+  it("mints at rates above and below 1:1, rounding down", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const mxePublicKey = await getMXEPublicKeyWithRetry(
+      provider,
+      program.programId,
+    );
+    await initCompDef(
+      program,
+      payer,
+      COMP_DEF_CREDIT_FROM_VAULT,
+      "initCreditFromVaultCompDef",
+    );
+
+    // 7 backing units: 7 * 3/2 = 10.5 and 7 * 2/3 = 4.67 both round down.
+    const cases = [
+      { rateNum: 3, rateDen: 2, expected: BigInt(10) },
+      { rateNum: 2, rateDen: 3, expected: BigInt(4) },
+    ];
+    for (const { rateNum, rateDen, expected } of cases) {
+      const mint = await setupMint(program, payer, rateNum, rateDen);
+      const holder = await initCvctAccount(
+        program,
+        payer,
+        mint,
+        mint.authority,
+      );
+      await depositTo(program, payer, mint, holder.pda, 7);
+
+      const account = await program.account.cvctAccount.fetch(holder.pda);
+      expect(
+        decryptSharedU128(
+          Uint8Array.from(account.balance[0]),
+          Buffer.from(account.balanceNonce.toArray("le", 16)),
+          holder.encKey,
+          mxePublicKey,
+        ),
+      ).to.equal(expected);
+
+      // Vault credits compare CVCT with backing units, so scaled mints refuse them.
+      const cvctMint = await program.account.cvctMint.fetch(mint.cvctMint);
+      const vault = await program.account.vault.fetch(mint.vault);
+      const computationOffset = computationOffsetFor(
+        holder.pda,
+        account.opCounter,
+      );
+      let creditError: unknown = null;
+      try {
+        await program.methods
+          .creditFromVault(
+            computationOffset,
+            Array.from(randomBytes(32)),
+            Array.from(mint.authorityPubkey),
+            randomNonce().bn,
+            Array.from(x25519.getPublicKey(holder.encKey)),
+            account.balanceNonce,
+            nextNonce(account.balanceNonce).bn,
+            Array.from(mint.authorityPubkey),
+            cvctMint.totalSupplyNonce,
+            nextNonce(cvctMint.totalSupplyNonce).bn,
+            Array.from(mint.authorityPubkey),
+            vault.totalLockedNonce,
+            nextNonce(vault.unallocatedNonce).bn,
+          )
+          .accountsPartial({
+            authority: mint.authority.publicKey,
+            cvctMint: mint.cvctMint,
+            vault: mint.vault,
+            cvctAccount: holder.pda,
+            ...arciumAccounts(
+              program.programId,
+              computationOffset,
+              COMP_DEF_CREDIT_FROM_VAULT,
+            ),
+          })
+          .signers([mint.authority])
+          .rpc({ commitment: "confirmed" });
+      } catch (err) {
+        creditError = err;
+      }
+      expect(String(creditError)).to.include("InvalidExchangeRate");
+    }
+  });
+
+  it("counts the unallocated pool in the supply checks", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const mxePublicKey = await getMXEPublicKeyWithRetry(
+      provider,
+      program.programId,
+    );
+    await initCompDef(
+      program,
+      payer,
+      COMP_DEF_LOCK_COLLATERAL,
+      "initLockCollateralCompDef",
+    );
+    await initCompDef(
+      program,
+      payer,
+      COMP_DEF_CREDIT_FROM_VAULT,
+      "initCreditFromVaultCompDef",
+    );
+    await initCompDef(program, payer, COMP_DEF_IS_EQUAL, "initIsEqualCompDef");
+    await initCompDef(
+      program,
+      payer,
+      COMP_DEF_BACKED_SUPPLY,
+      "initBackedSupplyCompDef",
+    );
+    const mint = await setupMint(program, payer);
+    const holder = await initCvctAccount(program, payer, mint, mint.authority);
+    await depositTo(program, payer, mint, holder.pda, 30);
+
+    // Pool 100 on top of 30 deposited: locked 130, supply 30.
+    const authorityTokenAccount = await getOrCreateAssociatedTokenAccount(
+      provider.connection,
+      payer.payer,
+      mint.backingMint,
+      mint.authority.publicKey,
+    );
+    await mintTo(
+      provider.connection,
+      payer.payer,
+      mint.backingMint,
+      authorityTokenAccount.address,
+      payer.payer,
+      100,
+    );
+    let vault = await program.account.vault.fetch(mint.vault);
+    const lockOffset = await nextComputationOffset(
+      program,
+      mint.cvctMint,
+      "cvctMint",
+    );
+    await program.methods
+      .lockCollateral(
+        lockOffset,
+        new anchor.BN(100),
+        nextNonce(vault.totalLockedNonce).bn,
+        nextNonce(vault.unallocatedNonce).bn,
+      )
+      .accountsPartial({
+        authority: mint.authority.publicKey,
+        cvctMint: mint.cvctMint,
+        vault: mint.vault,
+        authorityTokenAccount: authorityTokenAccount.address,
+        vaultTokenAccount: mint.vaultTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        ...arciumAccounts(
+          program.programId,
+          lockOffset,
+          COMP_DEF_LOCK_COLLATERAL,
+        ),
+      })
+      .signers([mint.authority])
+      .rpc({ commitment: "confirmed" });
+    await awaitComputationFinalization(
+      provider,
+      lockOffset,
+      program.programId,
+      "confirmed",
+    );
+
+    // Credit 40 out of the pool, then try 70 against the 60 left.
+    for (const amount of [BigInt(40), BigInt(70)]) {
+      const account = await program.account.cvctAccount.fetch(holder.pda);
+      const cvctMint = await program.account.cvctMint.fetch(mint.cvctMint);
+      vault = await program.account.vault.fetch(mint.vault);
+      const credit = encryptSharedU128(amount, mxePublicKey);
+      const creditOffset = computationOffsetFor(holder.pda, account.opCounter);
+      await program.methods
+        .creditFromVault(
+          creditOffset,
+          credit.ciphertext,
+          credit.publicKey,
+          credit.nonce,
+          Array.from(x25519.getPublicKey(holder.encKey)),
+          account.balanceNonce,
+          nextNonce(account.balanceNonce).bn,
+          Array.from(mint.authorityPubkey),
+          cvctMint.totalSupplyNonce,
+          nextNonce(cvctMint.totalSupplyNonce).bn,
+          Array.from(mint.authorityPubkey),
+          vault.totalLockedNonce,
+          nextNonce(vault.unallocatedNonce).bn,
+        )
+        .accountsPartial({
+          authority: mint.authority.publicKey,
+          cvctMint: mint.cvctMint,
+          vault: mint.vault,
+          cvctAccount: holder.pda,
+          ...arciumAccounts(
+            program.programId,
+            creditOffset,
+            COMP_DEF_CREDIT_FROM_VAULT,
+          ),
+        })
+        .signers([mint.authority])
+        .rpc({ commitment: "confirmed" });
+      await awaitComputationFinalization(
+        provider,
+        creditOffset,
+        program.programId,
+        "confirmed",
+      );
+    }
+
+    vault = await program.account.vault.fetch(mint.vault);
+    expect(
+      decryptSharedU128(
+        Uint8Array.from(vault.unallocated[0]),
+        Buffer.from(vault.unallocatedNonce.toArray("le", 16)),
+        mint.authorityKey,
+        mxePublicKey,
+      ),
+    ).to.equal(BigInt(60));
+    const cvctMint = await program.account.cvctMint.fetch(mint.cvctMint);
+    expect(
+      decryptSharedU128(
+        Uint8Array.from(cvctMint.totalSupply[0]),
+        Buffer.from(cvctMint.totalSupplyNonce.toArray("le", 16)),
+        mint.authorityKey,
+        mxePublicKey,
+      ),
+    ).to.equal(BigInt(70));
+
+    const checkEqual = async () => {
+      const checkOffset = await nextComputationOffset(
+        program,
+        mint.cvctMint,
+        "cvctMint",
+      );
+      await program.methods
+        .checkEqual(checkOffset)
+        .accountsPartial({
+          authority: mint.authority.publicKey,
+          cvctMint: mint.cvctMint,
+          vault: mint.vault,
+          ...arciumAccounts(program.programId, checkOffset, COMP_DEF_IS_EQUAL),
+        })
+        .signers([mint.authority])
+        .rpc({ commitment: "confirmed" });
+      return checkOffset;
+    };
+
+    // With a pool, supply alone is compared only through a refreshed sum.
+    let staleError: unknown = null;
+    try {
+      await checkEqual();
+    } catch (err) {
+      staleError = err;
+    }
+    expect(String(staleError)).to.include("StaleBackedSupply");
+
+    const refreshOffset = await nextComputationOffset(
+      program,
+      mint.cvctMint,
+      "cvctMint",
+    );
+    await program.methods
+      .refreshBackedSupply(refreshOffset, nextNonce(vault.backedSupplyNonce).bn)
+      .accountsPartial({
+        authority: mint.authority.publicKey,
+        cvctMint: mint.cvctMint,
+        vault: mint.vault,
+        ...arciumAccounts(
+          program.programId,
+          refreshOffset,
+          COMP_DEF_BACKED_SUPPLY,
+        ),
+      })
+      .signers([mint.authority])
+      .rpc({ commitment: "confirmed" });
+    await awaitComputationFinalization(
+      provider,
+      refreshOffset,
+      program.programId,
+      "confirmed",
+    );
+
+    // Supply 70 plus pool 60 equals locked 130.
+    const equalityEvent = awaitEvent(program, "equalityChecked");
+    await awaitComputationFinalization(
+      provider,
+      await checkEqual(),
+      program.programId,
+      "confirmed",
+    );
+    expect((await equalityEvent).equal).to.equal(true);
+  });
+
+  it("serializes concurrent deposits into one account", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const mxePublicKey = await getMXEPublicKeyWithRetry(
+      provider,
+      program.programId,
+    );
+    const mint = await setupMint(program, payer);
+    const holder = await initCvctAccount(program, payer, mint, mint.authority);
+
+    // The second deposit would read the balance the first hasn't written yet.
+    const first = await queueDepositTo(program, payer, mint, holder.pda, 5);
+    let concurrentError: unknown = null;
+    try {
+      await queueDepositTo(program, payer, mint, holder.pda, 7);
+    } catch (err) {
+      concurrentError = err;
+    }
+    expect(String(concurrentError)).to.include("AccountUpdateInFlight");
+
+    await awaitComputationFinalization(
+      provider,
+      first,
+      program.programId,
+      "confirmed",
+    );
+    await depositTo(program, payer, mint, holder.pda, 7);
+
+    // Both deposits are credited, each on top of the other.
+    const account = await program.account.cvctAccount.fetch(holder.pda);
+    expect(account.updateInFlight).to.equal(false);
+    expect(
+      decryptSharedU128(
+        Uint8Array.from(account.balance[0]),
+        Buffer.from(account.balanceNonce.toArray("le", 16)),
+        holder.encKey,
+        mxePublicKey,
+      ),
+    ).to.equal(BigInt(12));
+  });
+
+  it("requires computation offsets derived from the op counter", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    await initCompDef(program, payer, COMP_DEF_IS_EQUAL, "initIsEqualCompDef");
+    const mint = await setupMint(program, payer);
+
+    // initialize_cvct_mint consumed the offset for counter 0.
+    const cvctMint = await program.account.cvctMint.fetch(mint.cvctMint);
+    expect(cvctMint.opCounter.toNumber()).to.equal(1);
+
+    const checkEqual = (computationOffset: anchor.BN) =>
+      program.methods
+        .checkEqual(computationOffset)
+        .accountsPartial({
+          authority: mint.authority.publicKey,
+          cvctMint: mint.cvctMint,
+          vault: mint.vault,
+          ...arciumAccounts(
+            program.programId,
+            computationOffset,
+            COMP_DEF_IS_EQUAL,
+          ),
+        })
+        .signers([mint.authority])
+        .rpc({ commitment: "confirmed" });
+
+    // A stale counter and an ad-hoc offset are both refused.
+    for (const offset of [
+      computationOffsetFor(mint.cvctMint, new anchor.BN(0)),
+      new anchor.BN(randomBytes(8)),
+    ]) {
+      let offsetError: unknown = null;
+      try {
+        await checkEqual(offset);
+      } catch (err) {
+        offsetError = err;
+      }
+      expect(String(offsetError)).to.include("InvalidComputationOffset");
+    }
+
+    const computationOffset = computationOffsetFor(
+      mint.cvctMint,
+      cvctMint.opCounter,
+    );
+    await checkEqual(computationOffset);
+    await awaitComputationFinalization(
+      provider,
+      computationOffset,
+      program.programId,
+      "confirmed",
+    );
+    const after = await program.account.cvctMint.fetch(mint.cvctMint);
+    expect(after.opCounter.toNumber()).to.equal(2);
+  });
+
+  it("fails stale computations only after the mint's timeout", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const mint = await setupMint(program, payer);
+    const authority = mint.authority;
+    const holder = await initCvctAccount(program, payer, mint, authority);
+
+    const setComputationTimeout = (
+      signer: anchor.web3.Keypair,
+      timeout: number,
+    ) =>
+      program.methods
+        .setComputationTimeout(new anchor.BN(timeout))
+        .accountsPartial({
+          authority: signer.publicKey,
+          cvctMint: mint.cvctMint,
+        })
+        .signers([signer])
+        .rpc({ commitment: "confirmed" });
+    const stranger = anchor.web3.Keypair.generate();
+    for (const [signer, timeout, expected] of [
+      [stranger, 3600, "Unauthorized"],
+      [authority, 0, "InvalidTimeout"],
+    ] as const) {
+      let timeoutError: unknown = null;
+      try {
+        await setComputationTimeout(signer, timeout);
+      } catch (err) {
+        timeoutError = err;
+      }
+      expect(String(timeoutError)).to.include(expected);
+    }
+    await setComputationTimeout(authority, 3600);
+    let cvctMint = await program.account.cvctMint.fetch(mint.cvctMint);
+    expect(cvctMint.computationTimeout.toNumber()).to.equal(3600);
+
+    // While the deposit is in flight, it can be neither failed nor closed.
+    const computationOffset = await queueDepositTo(
+      program,
+      payer,
+      mint,
+      holder.pda,
+      10,
+    );
+    const pendingOp = pendingOpPda(program.programId, computationOffset);
+    const initiatorTokenAccount = await getAssociatedTokenAddress(
+      mint.backingMint,
+      authority.publicKey,
+    );
+    let sweepError: unknown = null;
+    try {
+      await program.methods
+        .sweepStaleComputation()
+        .accountsPartial({
+          pendingOp,
+          cvctMint: mint.cvctMint,
+          vault: mint.vault,
+          vaultTokenAccount: mint.vaultTokenAccount,
+          initiatorTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc({ commitment: "confirmed" });
+    } catch (err) {
+      sweepError = err;
+    }
+    expect(String(sweepError)).to.include("ComputationNotStale");
+    let closeError: unknown = null;
+    try {
+      await program.methods
+        .closePendingOp()
+        .accountsPartial({
+          initiator: authority.publicKey,
+          pendingOp,
+          rentPayer: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+    } catch (err) {
+      closeError = err;
+    }
+    expect(String(closeError)).to.include("ComputationNotFailed");
+
+    // The callback still lands and settles the marker itself.
+    await awaitComputationFinalization(
+      provider,
+      computationOffset,
+      program.programId,
+      "confirmed",
+    );
+    const settled = await program.account.pendingOp.fetchNullable(pendingOp);
+    expect(settled).to.equal(null);
+    cvctMint = await program.account.cvctMint.fetch(mint.cvctMint);
+    expect(cvctMint.openPendingOps.toNumber()).to.equal(0);
+  });
+
+  it("caps payroll members at max_members", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, { maxMembers: 2 });
+
+    await addPayrollMember(program, payer, fixture, 100);
+    await addPayrollMember(program, payer, fixture, 100);
+
+    let capError: unknown = null;
+    try {
+      await addPayrollMember(program, payer, fixture, 100);
+    } catch (err) {
+      capError = err;
+    }
+    expect(String(capError)).to.include("MemberLimitReached");
+
+    const payroll = await program.account.payroll.fetch(fixture.payroll);
+    expect(payroll.maxMembers).to.equal(2);
+    expect(payroll.memberCount).to.equal(2);
+    const org = await program.account.organization.fetch(fixture.org);
+    expect(org.payrollCount).to.equal(1);
+  });
+
+  it("imports members even when a member PDA was pre-funded", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer);
+    const authority = fixture.mint.authority;
+
+    const entries = [];
+    for (const rate of [100, 250]) {
+      const recipient = anchor.web3.Keypair.generate();
+      const { pda: wallet } = await initCvctAccount(
+        program,
+        payer,
+        fixture.mint,
+        recipient,
+      );
+      const [member] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("payroll_member"),
+          fixture.payroll.toBuffer(),
+          recipient.publicKey.toBuffer(),
+        ],
+        program.programId,
+      );
+      entries.push({ recipient: recipient.publicKey, rate, wallet, member });
+    }
+    // Lamports sent to a PDA ahead of time must not block its creation.
+    await transferLamports(
+      provider.connection,
+      payer.payer,
+      entries[0].member,
+      1_000_000,
+    );
+
+    const importMembers = () =>
+      program.methods
+        .importMembers(
+          entries.map(({ recipient, rate }) => ({
+            recipient,
+            rate: new anchor.BN(rate),
+            active: true,
+          })),
+        )
+        .accountsPartial({
+          admin: authority.publicKey,
+          org: fixture.org,
+          payroll: fixture.payroll,
+        })
+        .remainingAccounts(
+          entries.flatMap(({ wallet, member }) => [
+            { pubkey: wallet, isSigner: false, isWritable: false },
+            { pubkey: member, isSigner: false, isWritable: true },
+          ]),
+        )
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+    await importMembers();
+
+    for (const { recipient, rate, wallet, member } of entries) {
+      const payrollMember = await program.account.payrollMember.fetch(member);
+      expect(payrollMember.recipient.toBase58()).to.equal(recipient.toBase58());
+      expect(payrollMember.cvctWallet.toBase58()).to.equal(wallet.toBase58());
+      expect(payrollMember.rate.toNumber()).to.equal(rate);
+      expect(payrollMember.active).to.equal(true);
+    }
+    const payroll = await program.account.payroll.fetch(fixture.payroll);
+    expect(payroll.memberCount).to.equal(2);
+
+    // Re-importing an existing member is refused rather than overwriting it.
+    let reimportError: unknown = null;
+    try {
+      await importMembers();
+    } catch (err) {
+      reimportError = err;
+    }
+    expect(String(reimportError)).to.include("InvalidBatch");
+  });
+
+  it("lets only the admin, org spenders and allowance holders run payroll", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, { interval: 2 });
+    const authority = fixture.mint.authority;
+    const member = await addPayrollMember(program, payer, fixture, 100);
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+
+    const [outsider, spender] = [
+      anchor.web3.Keypair.generate(),
+      anchor.web3.Keypair.generate(),
+    ];
+    for (const key of [outsider, spender]) {
+      await transferLamports(
+        provider.connection,
+        payer.payer,
+        key.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL,
+      );
+    }
+
+    // Not listed and no allowance.
+    let outsiderError: unknown = null;
+    try {
+      await runPayroll(program, fixture, member, outsider);
+    } catch (err) {
+      outsiderError = err;
+    }
+    expect(String(outsiderError)).to.include("Unauthorized");
+
+    await program.methods
+      .addSpender(spender.publicKey)
+      .accountsPartial({ authority: authority.publicKey, org: fixture.org })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+    const org = await program.account.organization.fetch(fixture.org);
+    expect(org.authorizedSpenders.map((key) => key.toBase58())).to.deep.equal([
+      spender.publicKey.toBase58(),
+    ]);
+
+    const computationOffset = await runPayroll(
+      program,
+      fixture,
+      member,
+      spender,
+    );
+    await awaitComputationFinalization(
+      provider,
+      computationOffset,
+      program.programId,
+      "confirmed",
+    );
+
+    // Removal revokes the spender's access again.
+    await program.methods
+      .removeSpender(spender.publicKey)
+      .accountsPartial({ authority: authority.publicKey, org: fixture.org })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+    let revokedError: unknown = null;
+    try {
+      await runPayroll(program, fixture, member, spender);
+    } catch (err) {
+      revokedError = err;
+    }
+    expect(String(revokedError)).to.include("Unauthorized");
+  });
+
+  it("charges capped runners and refunds runs that move nothing", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, {
+      interval: 1,
+      maxPeriodsPerRun: 1,
+    });
+    const authority = fixture.mint.authority;
+    const spender = anchor.web3.Keypair.generate();
+    await transferLamports(
+      provider.connection,
+      payer.payer,
+      spender.publicKey,
+      anchor.web3.LAMPORTS_PER_SOL,
+    );
+    await program.methods
+      .addSpender(spender.publicKey)
+      .accountsPartial({ authority: authority.publicKey, org: fixture.org })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+    // Spenders run freely until the authority caps them.
+    await program.methods
+      .setRunnerAllowance(spender.publicKey, new anchor.BN(150))
+      .accountsPartial({ authority: authority.publicKey, org: fixture.org })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+    const [allowancePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("runner_allowance"),
+        fixture.org.toBuffer(),
+        spender.publicKey.toBuffer(),
+      ],
+      program.programId,
+    );
+    const spent = async () => {
+      const allowance =
+        await program.account.runnerAllowance.fetch(allowancePda);
+      return allowance.spent.toNumber();
+    };
+
+    const member = await addPayrollMember(program, payer, fixture, 100);
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+    const pay = async () =>
+      awaitComputationFinalization(
+        provider,
+        await runPayroll(program, fixture, member, spender),
+        program.programId,
+        "confirmed",
+      );
+
+    // The treasury is empty, so the run moves nothing and the charge comes back.
+    await pay();
+    expect(await spent()).to.equal(0);
+
+    await fundTreasury(program, payer, fixture, 1_000_000);
+    await pay();
+    expect(await spent()).to.equal(100);
+
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+    let limitError: unknown = null;
+    try {
+      await runPayroll(program, fixture, member, spender);
+    } catch (err) {
+      limitError = err;
+    }
+    expect(String(limitError)).to.include("RunnerLimitExceeded");
+  });
+
+  it("applies payroll interval changes from the next cycle only", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, { interval: 10 });
+    const authority = fixture.mint.authority;
+    const member = await addPayrollMember(program, payer, fixture, 100);
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+
+    // Shrinking mid-cycle must not turn the elapsed seconds into owed periods.
+    await program.methods
+      .updatePayrollInterval(new anchor.BN(1))
+      .accountsPartial({
+        admin: authority.publicKey,
+        payroll: fixture.payroll,
+      })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+
+    let notDueError: unknown = null;
+    try {
+      await runPayroll(program, fixture, member, authority);
+    } catch (err) {
+      notDueError = err;
+    }
+    expect(String(notDueError)).to.include("PaymentNotDue");
+
+    const payroll = await program.account.payroll.fetch(fixture.payroll);
+    const payrollMember = await program.account.payrollMember.fetch(
+      member.member,
+    );
+    expect(payroll.interval.toNumber()).to.equal(1);
+    expect(payrollMember.cycleInterval.toNumber()).to.equal(10);
+  });
+
+  it("pays a new member's first cycle only once", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, { interval: 5 });
+    const authority = fixture.mint.authority;
+    const member = await addPayrollMember(program, payer, fixture, 100);
+
+    // No bootstrap payment: a member added just now has nothing owed yet.
+    let earlyError: unknown = null;
+    try {
+      await runPayroll(program, fixture, member, authority);
+    } catch (err) {
+      earlyError = err;
+    }
+    expect(String(earlyError)).to.include("PaymentNotDue");
+
+    await new Promise((resolve) => setTimeout(resolve, 6000));
+    await runPayroll(program, fixture, member, authority);
+
+    // A second run straight after the first finds the cycle already taken.
+    let replayError: unknown = null;
+    try {
+      await runPayroll(program, fixture, member, authority);
+    } catch (err) {
+      replayError = err;
+    }
+    expect(String(replayError)).to.include("PaymentNotDue");
+  });
+
+  it("pays a member added just before the first run nothing yet", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, { interval: 60 });
+    const authority = fixture.mint.authority;
+    await fundTreasury(program, payer, fixture, 1_000);
+    const member = await addPayrollMember(program, payer, fixture, 100);
+
+    let runError: unknown = null;
+    try {
+      await runPayroll(program, fixture, member, authority);
+    } catch (err) {
+      runError = err;
+    }
+    expect(String(runError)).to.include("PaymentNotDue");
+    const payrollMember = await program.account.payrollMember.fetch(
+      member.member,
+    );
+    expect(payrollMember.lastPaid.toNumber()).to.equal(0);
+    expect(payrollMember.createdAt.toNumber()).to.be.greaterThan(0);
+  });
+
+  it("pays a member added long before the first run every elapsed cycle", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, { interval: 1 });
+    const authority = fixture.mint.authority;
+    await fundTreasury(program, payer, fixture, 1_000_000);
+    const member = await addPayrollMember(program, payer, fixture, 100);
+    const mxePublicKey = await getMXEPublicKeyWithRetry(
+      provider,
+      program.programId,
+    );
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+
+    await awaitComputationFinalization(
+      provider,
+      await runPayroll(program, fixture, member, authority),
+      program.programId,
+      "confirmed",
+    );
+    // Every whole cycle since the member was added is paid, and only those.
+    const payrollMember = await program.account.payrollMember.fetch(
+      member.member,
+    );
+    const cycles =
+      payrollMember.lastPaid.toNumber() - payrollMember.createdAt.toNumber();
+    expect(cycles).to.be.at.least(3);
+    const wallet = await program.account.cvctAccount.fetch(member.wallet);
+    expect(
+      decryptSharedU128(
+        Uint8Array.from(wallet.balance[0]),
+        Buffer.from(wallet.balanceNonce.toArray("le", 16)),
+        member.walletKey,
+        mxePublicKey,
+      ),
+    ).to.equal(BigInt(cycles * 100));
+  });
+
+  it("keeps whole-period boundaries from the last payment", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, {
+      interval: 2,
+      maxPeriodsPerRun: 1,
+    });
+    await fundTreasury(program, payer, fixture, 1_000_000);
+    const member = await addPayrollMember(program, payer, fixture, 100);
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+    await awaitComputationFinalization(
+      provider,
+      await runPayroll(program, fixture, member, fixture.mint.authority),
+      program.programId,
+      "confirmed",
+    );
+
+    // The run advanced `last_paid` by exactly one interval, not to the run's clock.
+    const payrollMember = await program.account.payrollMember.fetch(
+      member.member,
+    );
+    const lastPaid = payrollMember.lastPaid.toNumber();
+    expect(lastPaid).to.equal(payrollMember.createdAt.toNumber() + 2);
+    const cases = [
+      { offset: 1, periods: 0 },
+      { offset: 2, periods: 1 },
+      { offset: 3, periods: 1 },
+      { offset: 4, periods: 1 },
+    ];
+    for (const testCase of cases) {
+      const simulation = await program.methods
+        .quotePayment(new anchor.BN(lastPaid + testCase.offset))
+        .accountsPartial({
+          payroll: fixture.payroll,
+          payrollMember: member.member,
+        })
+        .simulate();
+      const quote = simulation.events.find(
+        (candidate) => candidate.name === "paymentQuote",
+      ).data as anchor.IdlEvents<Cvct>["paymentQuote"];
+      const label = `${testCase.offset}s after the last payment`;
+      expect(quote.periodsOwed.toNumber(), label).to.equal(testCase.periods);
+      expect(quote.paidThrough.toNumber(), label).to.equal(
+        lastPaid + testCase.periods * 2,
+      );
+    }
+  });
+
+  it("pays a member far behind only max_periods_per_run periods", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, {
+      interval: 1,
+      maxPeriodsPerRun: 2,
+    });
+    await fundTreasury(program, payer, fixture, 1_000_000);
+    const member = await addPayrollMember(program, payer, fixture, 100);
+    const mxePublicKey = await getMXEPublicKeyWithRetry(
+      provider,
+      program.programId,
+    );
+    await new Promise((resolve) => setTimeout(resolve, 4000));
+    await awaitComputationFinalization(
+      provider,
+      await runPayroll(program, fixture, member, fixture.mint.authority),
+      program.programId,
+      "confirmed",
+    );
+
+    // More than two intervals were owed; the run paid the cap and no more.
+    const payrollMember = await program.account.payrollMember.fetch(
+      member.member,
+    );
+    const lastPaid = payrollMember.lastPaid.toNumber();
+    expect(lastPaid).to.equal(payrollMember.createdAt.toNumber() + 2);
+    const wallet = await program.account.cvctAccount.fetch(member.wallet);
+    expect(
+      decryptSharedU128(
+        Uint8Array.from(wallet.balance[0]),
+        Buffer.from(wallet.balanceNonce.toArray("le", 16)),
+        member.walletKey,
+        mxePublicKey,
+      ),
+    ).to.equal(BigInt(200));
+
+    // A thousand intervals behind, a run still pays the cap and moves by it.
+    const simulation = await program.methods
+      .quotePayment(new anchor.BN(lastPaid + 1_000))
+      .accountsPartial({
+        payroll: fixture.payroll,
+        payrollMember: member.member,
+      })
+      .simulate();
+    const quote = simulation.events.find(
+      (candidate) => candidate.name === "paymentQuote",
+    ).data as anchor.IdlEvents<Cvct>["paymentQuote"];
+    expect(quote.periodsOwed.toNumber()).to.equal(2);
+    expect(quote.amount.toNumber()).to.equal(200);
+    expect(quote.paidThrough.toNumber()).to.equal(lastPaid + 2);
+  });
+
+  it("sets an org's encryption key only when it has none", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer);
+    const authority = fixture.mint.authority;
+    const encPubkey = x25519.getPublicKey(x25519.utils.randomSecretKey());
+
+    // Only orgs upgraded from before the key existed can set it.
+    let setError: unknown = null;
+    try {
+      await program.methods
+        .setOrgEncPubkey(Array.from(encPubkey))
+        .accountsPartial({ authority: authority.publicKey, org: fixture.org })
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+    } catch (err) {
+      setError = err;
+    }
+    expect(String(setError)).to.include("OrgEncPubkeyAlreadySet");
+  });
+
+  it("refuses a second run while the first is in flight", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, {
+      interval: 1,
+      maxPeriodsPerRun: 1,
+    });
+    const authority = fixture.mint.authority;
+    const member = await addPayrollMember(program, payer, fixture, 100);
+    await new Promise((resolve) => setTimeout(resolve, 4000));
+
+    // Capped at one period, so more are owed while the first run is queued.
+    const firstOffset = await runPayroll(program, fixture, member, authority);
+    let overlapError: unknown = null;
+    try {
+      await runPayroll(program, fixture, member, authority);
+    } catch (err) {
+      overlapError = err;
+    }
+    expect(String(overlapError)).to.include("RunInFlight");
+
+    await awaitComputationFinalization(
+      provider,
+      firstOffset,
+      program.programId,
+      "confirmed",
+    );
+    const payrollMember = await program.account.payrollMember.fetch(
+      member.member,
+    );
+    expect(payrollMember.runInFlight).to.equal(false);
+    await runPayroll(program, fixture, member, authority);
+  });
+
+  it("totals every payroll's payments on the org and serializes them", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, {
+      interval: 1,
+      maxPeriodsPerRun: 1,
+    });
+    const other = await addPayroll(program, payer, fixture, {
+      interval: 1,
+      maxPeriodsPerRun: 1,
+    });
+    const authority = fixture.mint.authority;
+    await fundTreasury(program, payer, fixture, 1_000_000);
+    const first = await addPayrollMember(program, payer, fixture, 100);
+    const second = await addPayrollMember(program, payer, other, 250);
+    const mxePublicKey = await getMXEPublicKeyWithRetry(
+      provider,
+      program.programId,
+    );
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+
+    // Both payrolls rewrite the org's total, so only one may be queued.
+    const firstOffset = await runPayroll(program, fixture, first, authority);
+    let overlapError: unknown = null;
+    try {
+      await runPayroll(program, other, second, authority);
+    } catch (err) {
+      overlapError = err;
+    }
+    expect(String(overlapError)).to.include("DisbursementInFlight");
+    await awaitComputationFinalization(
+      provider,
+      firstOffset,
+      program.programId,
+      "confirmed",
+    );
+
+    for (const [payroll, member] of [
+      [other, second],
+      [fixture, first],
+      [other, second],
+    ] as [PayrollFixture, MemberFixture][]) {
+      await awaitComputationFinalization(
+        provider,
+        await runPayroll(program, payroll, member, authority),
+        program.programId,
+        "confirmed",
+      );
+    }
+
+    const org = await program.account.organization.fetch(fixture.org);
+    expect(org.disbursementInFlight).to.equal(false);
+    expect(
+      decryptSharedU128(
+        Uint8Array.from(org.totalDisbursed[0]),
+        Buffer.from(org.totalDisbursedNonce.toArray("le", 16)),
+        fixture.mint.authorityKey,
+        mxePublicKey,
+      ),
+    ).to.equal(BigInt(2 * 100 + 2 * 250));
+  });
+
+  it("serializes runs from payrolls sharing the org treasury", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, {
+      interval: 1,
+      maxPeriodsPerRun: 1,
+    });
+    const other = await addPayroll(program, payer, fixture, {
+      interval: 1,
+      maxPeriodsPerRun: 1,
+    });
+    const authority = fixture.mint.authority;
+    await fundTreasury(program, payer, fixture, 1_000_000);
+    const first = await addPayrollMember(program, payer, fixture, 100);
+    const second = await addPayrollMember(program, payer, other, 250);
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+
+    // Each payroll is idle, but both runs debit the one org treasury (and
+    // add to the org's disbursed total, which refuses the second run first).
+    const firstOffset = await runPayroll(program, fixture, first, authority);
+    expect(
+      (await program.account.cvctAccount.fetch(fixture.treasury))
+        .updateInFlight,
+    ).to.equal(true);
+    let overlapError: unknown = null;
+    try {
+      await runPayroll(program, other, second, authority);
+    } catch (err) {
+      overlapError = err;
+    }
+    expect(String(overlapError)).to.include("DisbursementInFlight");
+
+    await awaitComputationFinalization(
+      provider,
+      firstOffset,
+      program.programId,
+      "confirmed",
+    );
+    await awaitComputationFinalization(
+      provider,
+      await runPayroll(program, other, second, authority),
+      program.programId,
+      "confirmed",
+    );
+    const treasury = await program.account.cvctAccount.fetch(fixture.treasury);
+    expect(treasury.updateInFlight).to.equal(false);
+  });
+
+  it("splits a member's rate at the old rate for elapsed time only", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const mxePublicKey = await getMXEPublicKeyWithRetry(
+      provider,
+      program.programId,
+    );
+    const split = async (
+      fixture: PayrollFixture,
+      member: MemberFixture,
+      newRate: number,
+    ) => {
+      const treasury = await program.account.cvctAccount.fetch(
+        fixture.treasury,
+      );
+      const wallet = await program.account.cvctAccount.fetch(member.wallet);
+      const org = await program.account.organization.fetch(fixture.org);
+      const payrollMember = await program.account.payrollMember.fetch(
+        member.member,
+      );
+      const computationOffset = computationOffsetFor(
+        fixture.treasury,
+        treasury.opCounter,
+      );
+      await program.methods
+        .splitMemberRate(
+          computationOffset,
+          new anchor.BN(newRate),
+          nextNonce(treasury.balanceNonce).bn,
+          nextNonce(wallet.balanceNonce).bn,
+          nextNonce(org.totalDisbursedNonce).bn,
+          nextNonce(payrollMember.deductionsNonce).bn,
+        )
+        .accountsPartial({
+          admin: fixture.mint.authority.publicKey,
+          org: fixture.org,
+          payroll: fixture.payroll,
+          payrollMember: member.member,
+          cvctMint: fixture.mint.cvctMint,
+          treasury: fixture.treasury,
+          memberWallet: member.wallet,
+          ...arciumAccounts(
+            program.programId,
+            computationOffset,
+            COMP_DEF_RUN_PAYROLL,
+          ),
+        })
+        .signers([fixture.mint.authority])
+        .rpc({ commitment: "confirmed" });
+      await awaitComputationFinalization(
+        provider,
+        computationOffset,
+        program.programId,
+        "confirmed",
+      );
+    };
+    const walletBalance = async (member: MemberFixture) => {
+      const wallet = await program.account.cvctAccount.fetch(member.wallet);
+      return decryptSharedU128(
+        Uint8Array.from(wallet.balance[0]),
+        Buffer.from(wallet.balanceNonce.toArray("le", 16)),
+        member.walletKey,
+        mxePublicKey,
+      );
+    };
+
+    // An unfunded treasury fails the settlement, which must also undo the reprice.
+    const unfunded = await setupPayroll(program, payer, { interval: 1 });
+    const stuck = await addPayrollMember(program, payer, unfunded, 100);
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+    await split(unfunded, stuck, 300);
+    let payrollMember = await program.account.payrollMember.fetch(stuck.member);
+    expect(payrollMember.rate.toNumber()).to.equal(100);
+    expect(payrollMember.lastPaid.toNumber()).to.equal(0);
+
+    const fixture = await setupPayroll(program, payer, { interval: 1 });
+    await fundTreasury(program, payer, fixture, 1_000_000);
+    const member = await addPayrollMember(program, payer, fixture, 100);
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+    await split(fixture, member, 300);
+
+    // Everything up to the split is paid at the old rate.
+    payrollMember = await program.account.payrollMember.fetch(member.member);
+    const splitAt = payrollMember.lastPaid.toNumber();
+    expect(payrollMember.rate.toNumber()).to.equal(300);
+    expect(await walletBalance(member)).to.equal(
+      BigInt(100 * (splitAt - payrollMember.createdAt.toNumber())),
+    );
+
+    // And everything after it at the new one.
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+    await awaitComputationFinalization(
+      provider,
+      await runPayroll(program, fixture, member, fixture.mint.authority),
+      program.programId,
+      "confirmed",
+    );
+    payrollMember = await program.account.payrollMember.fetch(member.member);
+    expect(await walletBalance(member)).to.equal(
+      BigInt(
+        100 * (splitAt - payrollMember.createdAt.toNumber()) +
+          300 * (payrollMember.lastPaid.toNumber() - splitAt),
+      ),
+    );
+  });
+
+  it("caps lock duration and extends unlock_at only on success", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const mint = await setupMint(program, payer);
+    await initCompDef(
+      program,
+      payer,
+      COMP_DEF_LOCK_BALANCE,
+      "initLockBalanceCompDef",
+    );
+    const holder = anchor.web3.Keypair.generate();
+    const { pda, encKey } = await initCvctAccount(program, payer, mint, holder);
+    const now = Math.floor(Date.now() / 1000);
+
+    const lock = async (unlockAt: number) => {
+      const account = await program.account.cvctAccount.fetch(pda);
+      const computationOffset = computationOffsetFor(pda, account.opCounter);
+      await program.methods
+        .lockBalance(
+          computationOffset,
+          new anchor.BN(1),
+          new anchor.BN(unlockAt),
+          Array.from(x25519.getPublicKey(encKey)),
+          account.balanceNonce,
+          account.lockedBalanceNonce,
+          nextNonce(account.lockedBalanceNonce).bn,
+        )
+        .accountsPartial({
+          authority: mint.authority.publicKey,
+          cvctMint: mint.cvctMint,
+          cvctAccount: pda,
+          ...arciumAccounts(
+            program.programId,
+            computationOffset,
+            COMP_DEF_LOCK_BALANCE,
+          ),
+        })
+        .signers([mint.authority])
+        .rpc({ commitment: "confirmed" });
+      return computationOffset;
+    };
+
+    let tooLongError: unknown = null;
+    try {
+      await lock(now + 5 * 366 * 24 * 60 * 60);
+    } catch (err) {
+      tooLongError = err;
+    }
+    expect(String(tooLongError)).to.include("InvalidLockDuration");
+
+    // The account holds nothing, so locking 1 fails and unlock_at stays put.
+    const offset = await lock(now + 60 * 60);
+    await awaitComputationFinalization(
+      provider,
+      offset,
+      program.programId,
+      "confirmed",
+    );
+    const account = await program.account.cvctAccount.fetch(pda);
+    expect(account.unlockAt.toNumber()).to.equal(0);
+  });
+
+  it("rejects orgs on mints missing from the registry", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const mint = await setupMint(program, payer);
+    const authority = mint.authority;
+    const [org] = PublicKey.findProgramAddressSync(
+      [Buffer.from("org"), authority.publicKey.toBuffer()],
+      program.programId,
+    );
+    const initOrg = () =>
+      program.methods
+        .initOrg(Array.from(mint.authorityPubkey))
+        .accountsPartial({
+          authority: authority.publicKey,
+          org,
+          cvctMint: mint.cvctMint,
+          mintRegistry: mintRegistryPda(program.programId),
+        })
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+
+    // Registering some other mint creates the registry without approving this one.
+    const other = await setupMint(program, payer);
+    await registerMint(program, payer, other.cvctMint);
+    let unapprovedError: unknown = null;
+    try {
+      await initOrg();
+    } catch (err) {
+      unapprovedError = err;
+    }
+    expect(String(unapprovedError)).to.include("MintNotApproved");
+
+    await registerMint(program, payer, mint.cvctMint);
+    await initOrg();
+
+    // Deregistering only blocks new orgs.
+    await program.methods
+      .deregisterMint(mint.cvctMint)
+      .accountsPartial({
+        authority: payer.publicKey,
+        mintRegistry: mintRegistryPda(program.programId),
+      })
+      .rpc({ commitment: "confirmed" });
+    const registry = await program.account.mintRegistry.fetch(
+      mintRegistryPda(program.programId),
+    );
+    expect(registry.mints.map((key) => key.toBase58())).to.not.include(
+      mint.cvctMint.toBase58(),
+    );
+    const orgAccount = await program.account.organization.fetch(org);
+    expect(orgAccount.cvctMint.toBase58()).to.equal(mint.cvctMint.toBase58());
+  });
+
+  it("onboards a member into an account the member owns", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer);
+    const authority = fixture.mint.authority;
+    await initCompDef(
+      program,
+      payer,
+      COMP_DEF_ONBOARD_MEMBER,
+      "initOnboardMemberCompDef",
+    );
+
+    // The recipient has never touched the program: they only sign, the admin pays all rent.
+    const recipient = anchor.web3.Keypair.generate();
+    const [wallet] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("cvct_account"),
+        fixture.mint.cvctMint.toBuffer(),
+        recipient.publicKey.toBuffer(),
+      ],
+      program.programId,
+    );
+    const [member] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payroll_member"),
+        fixture.payroll.toBuffer(),
+        recipient.publicKey.toBuffer(),
+      ],
+      program.programId,
+    );
+    const treasury = await program.account.cvctAccount.fetch(fixture.treasury);
+    const memberKey = x25519.utils.randomSecretKey();
+    const computationOffset = await nextComputationOffset(program, wallet);
+    await program.methods
+      .onboardMember(
+        computationOffset,
+        Array.from(x25519.getPublicKey(memberKey)),
+        randomNonce().bn,
+        randomNonce().bn,
+        new anchor.BN(100),
+        new anchor.BN(0),
+        nextNonce(treasury.balanceNonce).bn,
+      )
+      .accountsPartial({
+        admin: authority.publicKey,
+        org: fixture.org,
+        payroll: fixture.payroll,
+        member: recipient.publicKey,
+        cvctMint: fixture.mint.cvctMint,
+        cvctAccount: wallet,
+        payrollMember: member,
+        treasury: fixture.treasury,
+        ...arciumAccounts(
+          program.programId,
+          computationOffset,
+          COMP_DEF_ONBOARD_MEMBER,
+        ),
+      })
+      .signers([authority, recipient])
+      .rpc({ commitment: "confirmed" });
+    await awaitComputationFinalization(
+      provider,
+      computationOffset,
+      program.programId,
+      "confirmed",
+    );
+
+    const account = await program.account.cvctAccount.fetch(wallet);
+    expect(account.owner.toBase58()).to.equal(recipient.publicKey.toBase58());
+    expect(account.owner.toBase58()).to.not.equal(
+      authority.publicKey.toBase58(),
+    );
+    expect(account.balanceNonce.toString()).to.not.equal("0");
+    const payrollMember = await program.account.payrollMember.fetch(member);
+    expect(payrollMember.cvctWallet.toBase58()).to.equal(wallet.toBase58());
+  });
+
+  it("carries per-day rounding so many runs never drift", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, {
+      interval: 1,
+      rateUnit: { perDay: {} },
+    });
+    const authority = fixture.mint.authority;
+    await fundTreasury(program, payer, fixture, 1_000_000);
+    await program.methods
+      .setRoundingMode({ nearest: {} })
+      .accountsPartial({ admin: authority.publicKey, payroll: fixture.payroll })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+
+    // 100_000 per day is ~1.157 per second, so every run has a fraction to round.
+    const rate = BigInt(100_000);
+    const day = BigInt(86_400);
+    const member = await addPayrollMember(program, payer, fixture, 100_000);
+    for (let run = 0; run < 6; run++) {
+      await new Promise((resolve) => setTimeout(resolve, 1500));
+      const computationOffset = await runPayroll(
+        program,
+        fixture,
+        member,
+        authority,
+      );
+      await awaitComputationFinalization(
+        provider,
+        computationOffset,
+        program.programId,
+        "confirmed",
+      );
+    }
+
+    // Whatever the split across runs, the total is rate × time rounded once.
+    const payrollMember = await program.account.payrollMember.fetch(
+      member.member,
+    );
+    const seconds = BigInt(
+      payrollMember.lastPaid.sub(payrollMember.createdAt).toString(),
+    );
+    const exact = rate * seconds;
+    const expected = (exact + day / BigInt(2)) / day;
+    const wallet = await program.account.cvctAccount.fetch(member.wallet);
+    const paid = decryptSharedU128(
+      Uint8Array.from(wallet.balance[0]),
+      Buffer.from(wallet.balanceNonce.toArray("le", 16)),
+      member.walletKey,
+      await getMXEPublicKeyWithRetry(provider, program.programId),
+    );
+    expect(paid).to.equal(expected);
+    expect(BigInt(payrollMember.roundingRemainder.toString())).to.equal(
+      exact - paid * day,
+    );
+  });
+
+  it("owes the same amount for a rate in any unit", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    // One unit per second, expressed per hourly interval, per second and per day.
+    const setups = [
+      { rateUnit: { perInterval: {} }, rate: 3600 },
+      { rateUnit: { perSecond: {} }, rate: 1 },
+      { rateUnit: { perDay: {} }, rate: 86_400 },
+    ];
+    for (const setup of setups) {
+      const fixture = await setupPayroll(program, payer, {
+        interval: 3600,
+        rateUnit: setup.rateUnit,
+      });
+      const member = await addPayrollMember(
+        program,
+        payer,
+        fixture,
+        setup.rate,
+      );
+      const payrollMember = await program.account.payrollMember.fetch(
+        member.member,
+      );
+      for (const hours of [1, 5]) {
+        const simulation = await program.methods
+          .quotePayment(
+            new anchor.BN(payrollMember.createdAt.toNumber() + hours * 3600),
+          )
+          .accountsPartial({
+            payroll: fixture.payroll,
+            payrollMember: member.member,
+          })
+          .simulate();
+        const quote = simulation.events.find(
+          (candidate) => candidate.name === "paymentQuote",
+        ).data as anchor.IdlEvents<Cvct>["paymentQuote"];
+        const label = `${Object.keys(setup.rateUnit)[0]} after ${hours}h`;
+        expect(quote.periodsOwed.toNumber(), label).to.equal(hours);
+        expect(quote.amount.toNumber(), label).to.equal(hours * 3600);
+      }
+    }
+  });
+
+  it("logs authority actions for the audit trail", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer);
+    const authority = fixture.mint.authority;
+
+    const actionEvent = awaitEvent(program, "authorityAction");
+    await program.methods
+      .pausePayroll()
+      .accountsPartial({ admin: authority.publicKey, payroll: fixture.payroll })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+
+    const action = await actionEvent;
+    expect(action.org.toBase58()).to.equal(fixture.org.toBase58());
+    expect(action.actor.toBase58()).to.equal(authority.publicKey.toBase58());
+    expect(action.actionKind).to.deep.equal({ pausePayroll: {} });
+    expect(action.timestamp.toNumber()).to.be.greaterThan(0);
+  });
+
+  it("simulates a payroll run without paying", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, { interval: 1 });
+    const member = await addPayrollMember(program, payer, fixture, 100);
+    await initCompDef(
+      program,
+      payer,
+      COMP_DEF_SIMULATE_PAYROLL_RUN,
+      "initSimulatePayrollRunCompDef",
+    );
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    const simulationEvent = awaitEvent(program, "payrollSimulation");
+    const computationOffset = await nextComputationOffset(
+      program,
+      fixture.treasury,
+    );
+    await program.methods
+      .simulatePayrollRun(computationOffset)
+      .accountsPartial({
+        payer: payer.publicKey,
+        org: fixture.org,
+        payroll: fixture.payroll,
+        payrollMember: member.member,
+        treasury: fixture.treasury,
+        ...arciumAccounts(
+          program.programId,
+          computationOffset,
+          COMP_DEF_SIMULATE_PAYROLL_RUN,
+        ),
+      })
+      .rpc({ commitment: "confirmed" });
+    await awaitComputationFinalization(
+      provider,
+      computationOffset,
+      program.programId,
+      "confirmed",
+    );
+
+    // The treasury was never funded, so the preview reports a shortfall.
+    const simulation = await simulationEvent;
+    expect(simulation.payrollMember.toBase58()).to.equal(
+      member.member.toBase58(),
+    );
+    expect(simulation.periodsOwed.toNumber()).to.be.greaterThan(0);
+    expect(simulation.amount.toString()).to.equal(
+      simulation.periodsOwed.muln(100).toString(),
+    );
+    expect(simulation.wouldSucceed).to.equal(false);
+
+    const payrollMember = await program.account.payrollMember.fetch(
+      member.member,
+    );
+    expect(payrollMember.lastPaid.toNumber()).to.equal(0);
+  });
+
+  it("claws back a payment only within the window", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, { interval: 1 });
+    const authority = fixture.mint.authority;
+    await fundTreasury(program, payer, fixture, 1_000_000);
+    await initCompDef(program, payer, COMP_DEF_CLAWBACK, "initClawbackCompDef");
+    const setWindow = (seconds: number) =>
+      program.methods
+        .setClawbackWindow(new anchor.BN(seconds))
+        .accountsPartial({
+          admin: authority.publicKey,
+          payroll: fixture.payroll,
+        })
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+    await setWindow(3600);
+
+    const member = await addPayrollMember(program, payer, fixture, 100);
+    const pay = async () => {
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      await awaitComputationFinalization(
+        provider,
+        await runPayroll(program, fixture, member, authority),
+        program.programId,
+        "confirmed",
+      );
+    };
+    const clawback = async (amount: anchor.BN) => {
+      const treasury = await program.account.cvctAccount.fetch(
+        fixture.treasury,
+      );
+      const wallet = await program.account.cvctAccount.fetch(member.wallet);
+      const computationOffset = computationOffsetFor(
+        member.wallet,
+        wallet.opCounter,
+      );
+      await program.methods
+        .clawback(
+          computationOffset,
+          amount,
+          nextNonce(wallet.balanceNonce).bn,
+          nextNonce(treasury.balanceNonce).bn,
+        )
+        .accountsPartial({
+          authority: authority.publicKey,
+          org: fixture.org,
+          payroll: fixture.payroll,
+          payrollMember: member.member,
+          cvctMint: fixture.mint.cvctMint,
+          memberWallet: member.wallet,
+          treasury: fixture.treasury,
+          ...arciumAccounts(
+            program.programId,
+            computationOffset,
+            COMP_DEF_CLAWBACK,
+          ),
+        })
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+      await awaitComputationFinalization(
+        provider,
+        computationOffset,
+        program.programId,
+        "confirmed",
+      );
+    };
+
+    await pay();
+    let payrollMember = await program.account.payrollMember.fetch(
+      member.member,
+    );
+    const paid = payrollMember.clawable;
+    expect(paid.toNumber()).to.be.greaterThan(0);
+
+    // More than the last payment is refused up front.
+    let excessError: unknown = null;
+    try {
+      await clawback(paid.addn(1));
+    } catch (err) {
+      excessError = err;
+    }
+    expect(String(excessError)).to.include("ClawbackExceedsPayment");
+
+    const clawbackEvent = awaitEvent(program, "paymentClawedBack");
+    await clawback(paid);
+    const clawedBack = await clawbackEvent;
+    expect(clawedBack.ok).to.equal(true);
+    expect(clawedBack.amount.toString()).to.equal(paid.toString());
+    const wallet = await program.account.cvctAccount.fetch(member.wallet);
+    expect(
+      decryptSharedU128(
+        Uint8Array.from(wallet.balance[0]),
+        Buffer.from(wallet.balanceNonce.toArray("le", 16)),
+        member.walletKey,
+        await getMXEPublicKeyWithRetry(provider, program.programId),
+      ),
+    ).to.equal(BigInt(0));
+    payrollMember = await program.account.payrollMember.fetch(member.member);
+    expect(payrollMember.clawable.toNumber()).to.equal(0);
+
+    // Once the window has passed, the next payment stays with the member.
+    await pay();
+    await setWindow(1);
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+    let closedError: unknown = null;
+    try {
+      await clawback(new anchor.BN(1));
+    } catch (err) {
+      closedError = err;
+    }
+    expect(String(closedError)).to.include("ClawbackWindowClosed");
+  });
+
+  it("needs a second approver for payments above the threshold", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, { interval: 1 });
+    const authority = fixture.mint.authority;
+    await fundTreasury(program, payer, fixture, 1_000_000);
+    const spender = anchor.web3.Keypair.generate();
+    await transferLamports(
+      provider.connection,
+      payer.payer,
+      spender.publicKey,
+      anchor.web3.LAMPORTS_PER_SOL,
+    );
+    await program.methods
+      .addSpender(spender.publicKey)
+      .accountsPartial({ authority: authority.publicKey, org: fixture.org })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+    await program.methods
+      .setApprovalThreshold(new anchor.BN(50))
+      .accountsPartial({ authority: authority.publicKey, org: fixture.org })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+
+    const member = await addPayrollMember(program, payer, fixture, 100);
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    let directError: unknown = null;
+    try {
+      await runPayroll(program, fixture, member, authority);
+    } catch (err) {
+      directError = err;
+    }
+    expect(String(directError)).to.include("ApprovalRequired");
+
+    const [pendingPayment] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_payment"), member.member.toBuffer()],
+      program.programId,
+    );
+    const proposedEvent = awaitEvent(program, "paymentProposed");
+    await program.methods
+      .proposePayment()
+      .accountsPartial({
+        proposer: authority.publicKey,
+        org: fixture.org,
+        payroll: fixture.payroll,
+        payrollMember: member.member,
+      })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+    const proposed = await proposedEvent;
+    expect(proposed.pendingPayment.toBase58()).to.equal(
+      pendingPayment.toBase58(),
+    );
+    // Proposing moves nothing and leaves the schedule alone.
+    let payrollMember = await program.account.payrollMember.fetch(
+      member.member,
+    );
+    expect(payrollMember.lastPaid.toNumber()).to.equal(0);
+
+    const approve = async (approver: anchor.web3.Keypair) => {
+      const treasury = await program.account.cvctAccount.fetch(
+        fixture.treasury,
+      );
+      const wallet = await program.account.cvctAccount.fetch(member.wallet);
+      const org = await program.account.organization.fetch(fixture.org);
+      const computationOffset = computationOffsetFor(
+        fixture.treasury,
+        treasury.opCounter,
+      );
+      await program.methods
+        .approvePayment(
+          computationOffset,
+          nextNonce(treasury.balanceNonce).bn,
+          nextNonce(wallet.balanceNonce).bn,
+          nextNonce(org.totalDisbursedNonce).bn,
+          nextNonce(payrollMember.deductionsNonce).bn,
+        )
+        .accountsPartial({
+          approver: approver.publicKey,
+          org: fixture.org,
+          payroll: fixture.payroll,
+          payrollMember: member.member,
+          pendingPayment,
+          proposer: authority.publicKey,
+          cvctMint: fixture.mint.cvctMint,
+          treasury: fixture.treasury,
+          memberWallet: member.wallet,
+          ...arciumAccounts(
+            program.programId,
+            computationOffset,
+            COMP_DEF_RUN_PAYROLL,
+          ),
+        })
+        .signers([approver])
+        .rpc({ commitment: "confirmed" });
+      return computationOffset;
+    };
+
+    let selfApproveError: unknown = null;
+    try {
+      await approve(authority);
+    } catch (err) {
+      selfApproveError = err;
+    }
+    expect(String(selfApproveError)).to.include("ProposerCannotApprove");
+
+    const approvedEvent = awaitEvent(program, "paymentApproved");
+    await awaitComputationFinalization(
+      provider,
+      await approve(spender),
+      program.programId,
+      "confirmed",
+    );
+    const approved = await approvedEvent;
+    expect(approved.approver.toBase58()).to.equal(spender.publicKey.toBase58());
+    expect(approved.amount.toString()).to.equal(proposed.amount.toString());
+
+    const wallet = await program.account.cvctAccount.fetch(member.wallet);
+    expect(
+      decryptSharedU128(
+        Uint8Array.from(wallet.balance[0]),
+        Buffer.from(wallet.balanceNonce.toArray("le", 16)),
+        member.walletKey,
+        await getMXEPublicKeyWithRetry(provider, program.programId),
+      ),
+    ).to.equal(BigInt(proposed.amount.toString()));
+    payrollMember = await program.account.payrollMember.fetch(member.member);
+    expect(payrollMember.lastPaid.toNumber()).to.be.greaterThan(0);
+    expect(await provider.connection.getAccountInfo(pendingPayment)).to.equal(
+      null,
+    );
+  });
+
+  it("quotes periods and amounts at any given time", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const uncapped = await setupPayroll(program, payer, { interval: 10 });
+    const capped = await setupPayroll(program, payer, {
+      interval: 10,
+      maxPeriodsPerRun: 3,
+    });
+    const fixtures = [uncapped, capped];
+    const members: MemberFixture[] = [];
+    for (const fixture of fixtures) {
+      members.push(await addPayrollMember(program, payer, fixture, 100));
+    }
+
+    const quote = async (index: number, now: number) => {
+      const simulation = await program.methods
+        .quotePayment(new anchor.BN(now))
+        .accountsPartial({
+          payroll: fixtures[index].payroll,
+          payrollMember: members[index].member,
+        })
+        .simulate();
+      const event = simulation.events.find(
+        (candidate) => candidate.name === "paymentQuote",
+      );
+      return event.data as anchor.IdlEvents<Cvct>["paymentQuote"];
+    };
+
+    // Offsets are seconds after the member was added; `capped` stops at 3 periods.
+    const cases = [
+      { label: "clock behind the start", index: 0, offset: -5, periods: 0 },
+      { label: "at the start", index: 0, offset: 0, periods: 0 },
+      { label: "one second short", index: 0, offset: 9, periods: 0 },
+      { label: "exactly one interval", index: 0, offset: 10, periods: 1 },
+      { label: "just under two", index: 0, offset: 19, periods: 1 },
+      { label: "exactly two", index: 0, offset: 20, periods: 2 },
+      { label: "many intervals", index: 0, offset: 1_000, periods: 100 },
+      { label: "capped below the cap", index: 1, offset: 20, periods: 2 },
+      { label: "capped at the cap", index: 1, offset: 30, periods: 3 },
+      { label: "capped above the cap", index: 1, offset: 1_000, periods: 3 },
+      {
+        label: "a thousand intervals behind",
+        index: 1,
+        offset: 10_000,
+        periods: 3,
+      },
+    ];
+    for (const testCase of cases) {
+      const payrollMember = await program.account.payrollMember.fetch(
+        members[testCase.index].member,
+      );
+      const start = payrollMember.createdAt.toNumber();
+      const result = await quote(testCase.index, start + testCase.offset);
+      expect(result.periodsOwed.toNumber(), testCase.label).to.equal(
+        testCase.periods,
+      );
+      expect(result.amount.toNumber(), testCase.label).to.equal(
+        testCase.periods * 100,
+      );
+      // Only whole periods advance the schedule; the fraction stays owed.
+      expect(result.paidThrough.toNumber(), testCase.label).to.equal(
+        start + testCase.periods * 10,
+      );
+    }
+  });
+
+  it("settles elapsed time before a member interval override", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, { interval: 3600 });
+    const plain = await addPayrollMember(program, payer, fixture, 100);
+    const overridden = await addPayrollMember(program, payer, fixture, 100);
+    // Let the first cycle start before the override lands.
+    await new Promise((resolve) => setTimeout(resolve, 3000));
+    await program.methods
+      .setMemberInterval(new anchor.BN(600))
+      .accountsPartial({
+        admin: fixture.mint.authority.publicKey,
+        payroll: fixture.payroll,
+        payrollMember: overridden.member,
+      })
+      .signers([fixture.mint.authority])
+      .rpc({ commitment: "confirmed" });
+
+    const periodsOwed = async (member: MemberFixture, offset: number) => {
+      const payrollMember = await program.account.payrollMember.fetch(
+        member.member,
+      );
+      const now = payrollMember.createdAt.toNumber() + offset;
+      const simulation = await program.methods
+        .quotePayment(new anchor.BN(now))
+        .accountsPartial({
+          payroll: fixture.payroll,
+          payrollMember: member.member,
+        })
+        .simulate();
+      const quote = simulation.events.find(
+        (candidate) => candidate.name === "paymentQuote",
+      ).data as anchor.IdlEvents<Cvct>["paymentQuote"];
+      return quote.periodsOwed.toNumber();
+    };
+
+    // The cycle under way when the override landed still runs on the hour, not
+    // six ten-minute periods; only the hour after it switches cadence.
+    expect(await periodsOwed(plain, 3600)).to.equal(1);
+    expect(await periodsOwed(overridden, 3600)).to.equal(1);
+    expect(await periodsOwed(plain, 7200)).to.equal(2);
+    expect(await periodsOwed(overridden, 7200)).to.equal(7);
+  });
+
+  it("reports a member due only when a run would pay", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, { interval: 1 });
+    const paid = await addPayrollMember(program, payer, fixture, 100);
+    const unpaid = await addPayrollMember(program, payer, fixture, 0);
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    const dueStatus = async (member: MemberFixture) => {
+      const simulation = await program.methods
+        .memberDueStatus()
+        .accountsPartial({
+          org: fixture.org,
+          payroll: fixture.payroll,
+          payrollMember: member.member,
+          cvctMint: fixture.mint.cvctMint,
+        })
+        .simulate();
+      return simulation.events.find(
+        (candidate) => candidate.name === "memberDueStatus",
+      ).data as anchor.IdlEvents<Cvct>["memberDueStatus"];
+    };
+    const setMode = (mode: Record<string, Record<string, never>>) =>
+      program.methods
+        .setMintMode(mode)
+        .accountsPartial({
+          authority: fixture.mint.authority.publicKey,
+          cvctMint: fixture.mint.cvctMint,
+        })
+        .signers([fixture.mint.authority])
+        .rpc({ commitment: "confirmed" });
+
+    expect((await dueStatus(paid)).isDue).to.equal(true);
+    // Periods are owed, but a zero rate only skips.
+    const zeroRate = await dueStatus(unpaid);
+    expect(zeroRate.periodsOwed.toNumber()).to.be.greaterThan(0);
+    expect(zeroRate.isDue).to.equal(false);
+
+    // Runs refuse while the mint is not Normal.
+    await setMode({ withdrawOnly: {} });
+    expect((await dueStatus(paid)).isDue).to.equal(false);
+    await setMode({ normal: {} });
+    expect((await dueStatus(paid)).isDue).to.equal(true);
+  });
+
+  it("amortizes a deduction larger than one period across runs", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, {
+      interval: 2,
+      maxPeriodsPerRun: 1,
+    });
+    const authority = fixture.mint.authority;
+    await initCompDef(
+      program,
+      payer,
+      COMP_DEF_APPLY_DEDUCTION,
+      "initApplyDeductionCompDef",
+    );
+    await fundTreasury(program, payer, fixture, 1_000_000);
+    const member = await addPayrollMember(program, payer, fixture, 100);
+    const mxePublicKey = await getMXEPublicKeyWithRetry(
+      provider,
+      program.programId,
+    );
+
+    const applyDeduction = async (amount: number) => {
+      const payrollMember = await program.account.payrollMember.fetch(
+        member.member,
+      );
+      const deduction = encryptSharedU128(BigInt(amount), mxePublicKey);
+      const computationOffset = computationOffsetFor(
+        member.member,
+        payrollMember.opCounter,
+      );
+      await program.methods
+        .applyDeduction(
+          computationOffset,
+          deduction.ciphertext,
+          deduction.publicKey,
+          deduction.nonce,
+          nextNonce(payrollMember.deductionsNonce).bn,
+        )
+        .accountsPartial({
+          authority: authority.publicKey,
+          org: fixture.org,
+          payroll: fixture.payroll,
+          payrollMember: member.member,
+          cvctMint: fixture.mint.cvctMint,
+          ...arciumAccounts(
+            program.programId,
+            computationOffset,
+            COMP_DEF_APPLY_DEDUCTION,
+          ),
+        })
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+      return computationOffset;
+    };
+    const setMode = (mode: Record<string, Record<string, never>>) =>
+      program.methods
+        .setMintMode(mode)
+        .accountsPartial({
+          authority: authority.publicKey,
+          cvctMint: fixture.mint.cvctMint,
+        })
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+
+    // Deductions wait for the mint like runs do.
+    await setMode({ withdrawOnly: {} });
+    let pausedError: unknown = null;
+    try {
+      await applyDeduction(250);
+    } catch (err) {
+      pausedError = err;
+    }
+    expect(String(pausedError)).to.include("MintPaused");
+    await setMode({ normal: {} });
+
+    await awaitComputationFinalization(
+      provider,
+      await applyDeduction(250),
+      program.programId,
+      "confirmed",
+    );
+    // Let four periods elapse; each capped run pays one.
+    await new Promise((resolve) => setTimeout(resolve, 8000));
+
+    const expected = [
+      { paid: 0, outstanding: 150 },
+      { paid: 0, outstanding: 50 },
+      { paid: 50, outstanding: 0 },
+      { paid: 150, outstanding: 0 },
+    ];
+    for (const [run, step] of expected.entries()) {
+      await awaitComputationFinalization(
+        provider,
+        await runPayroll(program, fixture, member, authority),
+        program.programId,
+        "confirmed",
+      );
+      const wallet = await program.account.cvctAccount.fetch(member.wallet);
+      const payrollMember = await program.account.payrollMember.fetch(
+        member.member,
+      );
+      const label = `after run ${run + 1}`;
+      expect(
+        decryptSharedU128(
+          Uint8Array.from(wallet.balance[0]),
+          Buffer.from(wallet.balanceNonce.toArray("le", 16)),
+          member.walletKey,
+          mxePublicKey,
+        ),
+        label,
+      ).to.equal(BigInt(step.paid));
+      expect(
+        decryptSharedU128(
+          Uint8Array.from(payrollMember.deductions[0]),
+          Buffer.from(payrollMember.deductionsNonce.toArray("le", 16)),
+          fixture.mint.authorityKey,
+          mxePublicKey,
+        ),
+        label,
+      ).to.equal(BigInt(step.outstanding));
+    }
+  });
+
+  it("rejects a payment that would dip into the reserve", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, {
+      interval: 1,
+      maxPeriodsPerRun: 1,
+    });
+    const authority = fixture.mint.authority;
+    await fundTreasury(program, payer, fixture, 1_000);
+    const mxePublicKey = await getMXEPublicKeyWithRetry(
+      provider,
+      program.programId,
+    );
+
+    // Encrypted for the org authority's key, like every org-wide encrypted value.
+    const reserveNonce = randomNonce();
+    const [reserve] = new RescueCipher(
+      x25519.getSharedSecret(fixture.mint.authorityKey, mxePublicKey),
+    ).encrypt([BigInt(950)], reserveNonce.bytes);
+    await program.methods
+      .setTreasuryReserve(reserve, reserveNonce.bn)
+      .accountsPartial({ authority: authority.publicKey, org: fixture.org })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+
+    const overdraw = await addPayrollMember(program, payer, fixture, 100);
+    const withinBuffer = await addPayrollMember(program, payer, fixture, 50);
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+    for (const member of [overdraw, withinBuffer]) {
+      await awaitComputationFinalization(
+        provider,
+        await runPayroll(program, fixture, member, authority),
+        program.programId,
+        "confirmed",
+      );
+    }
+
+    // 1000 - 100 would leave less than the 950 reserve, so nothing moved.
+    const rejected = await program.account.payrollMember.fetch(overdraw.member);
+    expect(rejected.lastPaid.toNumber()).to.equal(0);
+    const paid = await program.account.payrollMember.fetch(
+      withinBuffer.member,
+    );
+    expect(paid.lastPaid.toNumber()).to.be.greaterThan(0);
+    const treasury = await program.account.cvctAccount.fetch(fixture.treasury);
+    expect(
+      decryptSharedU128(
+        Uint8Array.from(treasury.balance[0]),
+        Buffer.from(treasury.balanceNonce.toArray("le", 16)),
+        fixture.treasuryKey,
+        mxePublicKey,
+      ),
+    ).to.equal(BigInt(950));
+  });
+
+  it("auto-pauses when the treasury can't cover a member", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer, {
+      interval: 1,
+      maxPeriodsPerRun: 1,
+    });
+    const authority = fixture.mint.authority;
+    // Some funds stay spendable, just not enough for one payment.
+    await fundTreasury(program, payer, fixture, 50);
+    const member = await addPayrollMember(program, payer, fixture, 100);
+    await program.methods
+      .setPayrollAutoPause(true)
+      .accountsPartial({ admin: authority.publicKey, payroll: fixture.payroll })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    const pausedEvent = awaitEvent(program, "payrollAutoPaused");
+    await awaitComputationFinalization(
+      provider,
+      await runPayroll(program, fixture, member, authority),
+      program.programId,
+      "confirmed",
+    );
+    const paused = await pausedEvent;
+    expect(paused.payroll.toBase58()).to.equal(fixture.payroll.toBase58());
+    const payroll = await program.account.payroll.fetch(fixture.payroll);
+    expect(payroll.active).to.equal(false);
+    // The refused run leaves the member's periods owed.
+    const payrollMember = await program.account.payrollMember.fetch(
+      member.member,
+    );
+    expect(payrollMember.lastPaid.toNumber()).to.equal(0);
+  });
+
+  it("accrues bonuses and lets the member claim them", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer);
+    const authority = fixture.mint.authority;
+    await fundTreasury(program, payer, fixture, 1_000_000);
+    await initCompDef(
+      program,
+      payer,
+      COMP_DEF_ACCRUE_BONUS,
+      "initAccrueBonusCompDef",
+    );
+    await initCompDef(
+      program,
+      payer,
+      COMP_DEF_CLAIM_BONUS,
+      "initClaimBonusCompDef",
+    );
+    const member = await addPayrollMember(program, payer, fixture, 100);
+    const mxePublicKey = await getMXEPublicKeyWithRetry(
+      provider,
+      program.programId,
+    );
+
+    for (const amount of [200, 300]) {
+      const payrollMember = await program.account.payrollMember.fetch(
+        member.member,
+      );
+      const bonus = encryptSharedU128(BigInt(amount), mxePublicKey);
+      const computationOffset = computationOffsetFor(
+        member.member,
+        payrollMember.opCounter,
+      );
+      await program.methods
+        .accrueBonus(
+          computationOffset,
+          bonus.ciphertext,
+          bonus.publicKey,
+          bonus.nonce,
+          nextNonce(payrollMember.bonusAccruedNonce).bn,
+        )
+        .accountsPartial({
+          authority: authority.publicKey,
+          org: fixture.org,
+          payroll: fixture.payroll,
+          payrollMember: member.member,
+          ...arciumAccounts(
+            program.programId,
+            computationOffset,
+            COMP_DEF_ACCRUE_BONUS,
+          ),
+        })
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+      await awaitComputationFinalization(
+        provider,
+        computationOffset,
+        program.programId,
+        "confirmed",
+      );
+    }
+
+    // Accrued for the org's key; the treasury hasn't paid anything yet.
+    let payrollMember = await program.account.payrollMember.fetch(
+      member.member,
+    );
+    expect(
+      decryptSharedU128(
+        Uint8Array.from(payrollMember.bonusAccrued[0]),
+        Buffer.from(payrollMember.bonusAccruedNonce.toArray("le", 16)),
+        fixture.mint.authorityKey,
+        mxePublicKey,
+      ),
+    ).to.equal(BigInt(500));
+
+    const treasury = await program.account.cvctAccount.fetch(fixture.treasury);
+    const wallet = await program.account.cvctAccount.fetch(member.wallet);
+    const org = await program.account.organization.fetch(fixture.org);
+    const claimedEvent = awaitEvent(program, "bonusClaimed");
+    const computationOffset = computationOffsetFor(
+      fixture.treasury,
+      treasury.opCounter,
+    );
+    await program.methods
+      .claimBonus(
+        computationOffset,
+        nextNonce(treasury.balanceNonce).bn,
+        nextNonce(payrollMember.bonusAccruedNonce).bn,
+        nextNonce(wallet.balanceNonce).bn,
+        nextNonce(org.totalDisbursedNonce).bn,
+      )
+      .accountsPartial({
+        recipient: member.recipient.publicKey,
+        feePayer: member.recipient.publicKey,
+        org: fixture.org,
+        payroll: fixture.payroll,
+        payrollMember: member.member,
+        cvctMint: fixture.mint.cvctMint,
+        treasury: fixture.treasury,
+        memberWallet: member.wallet,
+        ...arciumAccounts(
+          program.programId,
+          computationOffset,
+          COMP_DEF_CLAIM_BONUS,
+        ),
+      })
+      .signers([member.recipient])
+      .rpc({ commitment: "confirmed" });
+    await awaitComputationFinalization(
+      provider,
+      computationOffset,
+      program.programId,
+      "confirmed",
+    );
+    expect((await claimedEvent).paid).to.equal(true);
+
+    const paidWallet = await program.account.cvctAccount.fetch(member.wallet);
+    expect(
+      decryptSharedU128(
+        Uint8Array.from(paidWallet.balance[0]),
+        Buffer.from(paidWallet.balanceNonce.toArray("le", 16)),
+        member.walletKey,
+        mxePublicKey,
+      ),
+    ).to.equal(BigInt(500));
+    payrollMember = await program.account.payrollMember.fetch(member.member);
+    expect(
+      decryptSharedU128(
+        Uint8Array.from(payrollMember.bonusAccrued[0]),
+        Buffer.from(payrollMember.bonusAccruedNonce.toArray("le", 16)),
+        fixture.mint.authorityKey,
+        mxePublicKey,
+      ),
+    ).to.equal(BigInt(0));
+  });
+
+  it("rejects the org treasury as a member wallet", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer);
+    const authority = fixture.mint.authority;
+    const [member] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("payroll_member"),
+        fixture.payroll.toBuffer(),
+        fixture.org.toBuffer(),
+      ],
+      program.programId,
+    );
+
+    // The treasury is owned by the org, so it passes the owner check on its own.
+    let treasuryError: unknown = null;
+    try {
+      await program.methods
+        .addPayrollMember(new anchor.BN(100))
+        .accountsPartial({
+          admin: authority.publicKey,
+          org: fixture.org,
+          payroll: fixture.payroll,
+          recipient: fixture.org,
+          recipientCvctAccount: fixture.treasury,
+          payrollMember: member,
+        })
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+    } catch (err) {
+      treasuryError = err;
+    }
+    expect(String(treasuryError)).to.include("InvalidVault");
+  });
+
+  it("keeps existing members from being re-pointed at the treasury", async () => {
+    const payer = provider.wallet as anchor.Wallet;
+    const fixture = await setupPayroll(program, payer);
+    const authority = fixture.mint.authority;
+    const member = await addPayrollMember(program, payer, fixture, 100);
+
+    let repointError: unknown = null;
+    try {
+      await program.methods
+        .updateMemberWallet()
+        .accountsPartial({
+          authority: authority.publicKey,
+          org: fixture.org,
+          payroll: fixture.payroll,
+          payrollMember: member.member,
+          newWallet: fixture.treasury,
+        })
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+    } catch (err) {
+      repointError = err;
+    }
+    expect(String(repointError)).to.include("InvalidVault");
+
+    const payrollMember = await program.account.payrollMember.fetch(
+      member.member,
+    );
+    expect(payrollMember.cvctWallet.toBase58()).to.equal(
+      member.wallet.toBase58(),
+    );
+  });
+});
+
+async function initMintStateCompDef(
+  program: Program<Cvct>,
+  payer: anchor.Wallet,
+): Promise<void> {
+  // Compute PDA for computation definition account.
+  const baseSeedCompDefAcc = getArciumAccountBaseSeed(
+    "ComputationDefinitionAccount",
+  );
+  const offset = getCompDefAccOffset(COMP_DEF_MINT);
+
+  const compDefPDA = PublicKey.findProgramAddressSync(
+    [baseSeedCompDefAcc, program.programId.toBuffer(), offset],
+    getArciumProgramId(),
+  )[0];
+
+  // Initialize comp def on-chain (required once per circuit).
+  await rpcWithLogs(
+    program.methods
+      .initMintStateCompDef()
+      .accountsPartial({
+        compDefAccount: compDefPDA,
+        payer: payer.publicKey,
+        mxeAccount: getMXEAccAddress(program.programId),
+        arciumProgram: getArciumProgramId(),
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([payer.payer])
+      .rpc({
+        commitment: "confirmed",
+      }),
+    "initMintStateCompDef",
+    program.provider.connection,
+  );
+
+  // Finalize comp def (registers compiled circuit).
+  const finalizeTx = await buildFinalizeCompDefTx(
+    program.provider as anchor.AnchorProvider,
+    Buffer.from(offset).readUInt32LE(),
+    program.programId,
+  );
+
+  // Retry blockhash fetch to handle early localnet startup.
+  const latestBlockhash = await getLatestBlockhashWithRetry(
+    program.provider.connection,
+  );
+  finalizeTx.recentBlockhash = latestBlockhash.blockhash;
+  finalizeTx.lastValidBlockHeight = latestBlockhash.lastValidBlockHeight;
+
+  finalizeTx.sign(payer.payer);
+
+  await rpcWithLogs(
+    program.provider.sendAndConfirm(finalizeTx),
+    "finalizeCompDef",
+    program.provider.connection,
+  );
+}
+
+async function initAccountStateCompDef(
+  program: Program<Cvct>,
+  payer: anchor.Wallet,
+): Promise<void> {
+  const baseSeedCompDefAcc = getArciumAccountBaseSeed(
+    "ComputationDefinitionAccount",
+  );
+  const offset = getCompDefAccOffset(COMP_DEF_ACCOUNT);
+
+  const compDefPDA = PublicKey.findProgramAddressSync(
+    [baseSeedCompDefAcc, program.programId.toBuffer(), offset],
+    getArciumProgramId(),
+  )[0];
+
+  await rpcWithLogs(
+    program.methods
+      .initAccountStateCompDef()
+      .accountsPartial({
+        compDefAccount: compDefPDA,
+        payer: payer.publicKey,
+        mxeAccount: getMXEAccAddress(program.programId),
+        arciumProgram: getArciumProgramId(),
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([payer.payer])
+      .rpc({
+        commitment: "confirmed",
+      }),
+    "initAccountStateCompDef",
     program.provider.connection,
   );
 
@@ -915,6 +3765,610 @@ async function initTransferCvctCompDef(
   );
 }
 
+// Helper: init and finalize the comp def for `circuit` once; later calls are no-ops.
+async function initCompDef(
+  program: Program<Cvct>,
+  payer: anchor.Wallet,
+  circuit: string,
+  method: string,
+): Promise<void> {
+  const offset = getCompDefAccOffset(circuit);
+  const compDefPDA = PublicKey.findProgramAddressSync(
+    [
+      getArciumAccountBaseSeed("ComputationDefinitionAccount"),
+      program.programId.toBuffer(),
+      offset,
+    ],
+    getArciumProgramId(),
+  )[0];
+  if (await program.provider.connection.getAccountInfo(compDefPDA)) {
+    return;
+  }
+
+  const methods = program.methods as unknown as Record<
+    string,
+    () => ReturnType<typeof program.methods.initMintStateCompDef>
+  >;
+  await rpcWithLogs(
+    methods[method]()
+      .accountsPartial({
+        compDefAccount: compDefPDA,
+        payer: payer.publicKey,
+        mxeAccount: getMXEAccAddress(program.programId),
+        arciumProgram: getArciumProgramId(),
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([payer.payer])
+      .rpc({ commitment: "confirmed" }),
+    method,
+    program.provider.connection,
+  );
+
+  const finalizeTx = await buildFinalizeCompDefTx(
+    program.provider as anchor.AnchorProvider,
+    Buffer.from(offset).readUInt32LE(),
+    program.programId,
+  );
+  const latestBlockhash = await getLatestBlockhashWithRetry(
+    program.provider.connection,
+  );
+  finalizeTx.recentBlockhash = latestBlockhash.blockhash;
+  finalizeTx.lastValidBlockHeight = latestBlockhash.lastValidBlockHeight;
+  finalizeTx.sign(payer.payer);
+
+  await rpcWithLogs(
+    program.provider.sendAndConfirm(finalizeTx),
+    `finalize ${circuit}`,
+    program.provider.connection,
+  );
+}
+
+interface MintFixture {
+  authority: anchor.web3.Keypair;
+  // x25519 key the mint totals are encrypted to.
+  authorityKey: Uint8Array;
+  authorityPubkey: Uint8Array;
+  backingMint: PublicKey;
+  cvctMint: PublicKey;
+  vault: PublicKey;
+  vaultTokenAccount: PublicKey;
+}
+
+// Helper: fresh CVCT mint at `rateNum / rateDen`, owned by a funded authority keypair.
+async function setupMint(
+  program: Program<Cvct>,
+  payer: anchor.Wallet,
+  rateNum = 1,
+  rateDen = 1,
+): Promise<MintFixture> {
+  const provider = program.provider as anchor.AnchorProvider;
+  await initCompDef(program, payer, COMP_DEF_MINT, "initMintStateCompDef");
+  await initCompDef(
+    program,
+    payer,
+    COMP_DEF_ACCOUNT,
+    "initAccountStateCompDef",
+  );
+
+  const authority = anchor.web3.Keypair.generate();
+  await transferLamports(
+    provider.connection,
+    payer.payer,
+    authority.publicKey,
+    5 * anchor.web3.LAMPORTS_PER_SOL,
+  );
+  const backingMint = await createMint(
+    provider.connection,
+    payer.payer,
+    payer.publicKey,
+    null,
+    6,
+  );
+  const [cvctMint] = PublicKey.findProgramAddressSync(
+    [Buffer.from("cvct_mint"), authority.publicKey.toBuffer()],
+    program.programId,
+  );
+  const [vault] = PublicKey.findProgramAddressSync(
+    [Buffer.from("vault"), cvctMint.toBuffer()],
+    program.programId,
+  );
+  const vaultTokenAccount = await getAssociatedTokenAddress(
+    backingMint,
+    vault,
+    true,
+  );
+  const authorityKey = x25519.utils.randomSecretKey();
+  const authorityPubkey = x25519.getPublicKey(authorityKey);
+
+  const computationOffset = await nextComputationOffset(
+    program,
+    cvctMint,
+    "cvctMint",
+  );
+  await rpcWithLogs(
+    program.methods
+      .initializeCvctMint(
+        computationOffset,
+        Array.from(authorityPubkey),
+        randomNonce().bn,
+        randomNonce().bn,
+        new anchor.BN(rateNum),
+        new anchor.BN(rateDen),
+      )
+      .accountsPartial({
+        authority: authority.publicKey,
+        cvctMint,
+        vault,
+        backingMint,
+        backingRecord: PublicKey.findProgramAddressSync(
+          [Buffer.from("backing"), backingMint.toBuffer()],
+          program.programId,
+        )[0],
+        vaultTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        ...arciumAccounts(program.programId, computationOffset, COMP_DEF_MINT),
+      })
+      .signers([authority])
+      .rpc({ skipPreflight: true, commitment: "confirmed" }),
+    "initializeCvctMint",
+    provider.connection,
+  );
+  await awaitComputationFinalization(
+    provider,
+    computationOffset,
+    program.programId,
+    "confirmed",
+  );
+
+  return {
+    authority,
+    authorityKey,
+    authorityPubkey,
+    backingMint,
+    cvctMint,
+    vault,
+    vaultTokenAccount,
+  };
+}
+
+// Helper: the owner's CvctAccount for `mint`, with encrypted zero balances.
+async function initCvctAccount(
+  program: Program<Cvct>,
+  payer: anchor.Wallet,
+  mint: MintFixture,
+  owner: anchor.web3.Keypair,
+): Promise<{ pda: PublicKey; encKey: Uint8Array }> {
+  const provider = program.provider as anchor.AnchorProvider;
+  const [pda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("cvct_account"),
+      mint.cvctMint.toBuffer(),
+      owner.publicKey.toBuffer(),
+    ],
+    program.programId,
+  );
+  const encKey = x25519.utils.randomSecretKey();
+  const computationOffset = await nextComputationOffset(program, pda);
+  await rpcWithLogs(
+    program.methods
+      .initializeCvctAccount(
+        computationOffset,
+        Array.from(x25519.getPublicKey(encKey)),
+        randomNonce().bn,
+        randomNonce().bn,
+      )
+      .accountsPartial({
+        owner: owner.publicKey,
+        feePayer: payer.publicKey,
+        cvctAccount: pda,
+        cvctMint: mint.cvctMint,
+        ...arciumAccounts(
+          program.programId,
+          computationOffset,
+          COMP_DEF_ACCOUNT,
+        ),
+      })
+      .signers([owner])
+      .rpc({ skipPreflight: true, commitment: "confirmed" }),
+    "initializeCvctAccount",
+    provider.connection,
+  );
+  await awaitComputationFinalization(
+    provider,
+    computationOffset,
+    program.programId,
+    "confirmed",
+  );
+  return { pda, encKey };
+}
+
+function mintRegistryPda(programId: PublicKey): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("mint_registry")],
+    programId,
+  )[0];
+}
+
+// Helper: approve `cvctMint` for orgs, creating the registry on first use. The local
+// deploy wallet is the program's upgrade authority and the registry authority.
+async function registerMint(
+  program: Program<Cvct>,
+  payer: anchor.Wallet,
+  cvctMint: PublicKey,
+): Promise<void> {
+  const mintRegistry = mintRegistryPda(program.programId);
+  if (!(await program.provider.connection.getAccountInfo(mintRegistry))) {
+    await program.methods
+      .initMintRegistry(payer.publicKey)
+      .accountsPartial({
+        payer: payer.publicKey,
+        mintRegistry,
+        program: program.programId,
+        programData: PublicKey.findProgramAddressSync(
+          [program.programId.toBuffer()],
+          new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"),
+        )[0],
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+  await program.methods
+    .registerMint()
+    .accountsPartial({
+      authority: payer.publicKey,
+      mintRegistry,
+      cvctMint,
+    })
+    .rpc({ commitment: "confirmed" });
+}
+
+interface PayrollFixture {
+  mint: MintFixture;
+  org: PublicKey;
+  treasury: PublicKey;
+  // x25519 key the org treasury balance is encrypted to.
+  treasuryKey: Uint8Array;
+  payroll: PublicKey;
+  // Signs as the payroll's admin.
+  admin: anchor.web3.Keypair;
+}
+
+// Helper: org with an initialized treasury and one payroll, all run by the mint authority.
+async function setupPayroll(
+  program: Program<Cvct>,
+  payer: anchor.Wallet,
+  opts: {
+    interval?: number;
+    maxMembers?: number;
+    maxPeriodsPerRun?: number;
+    rateUnit?: Record<string, Record<string, never>>;
+  } = {},
+): Promise<PayrollFixture> {
+  const provider = program.provider as anchor.AnchorProvider;
+  const mint = await setupMint(program, payer);
+  const authority = mint.authority;
+  await registerMint(program, payer, mint.cvctMint);
+
+  const [org] = PublicKey.findProgramAddressSync(
+    [Buffer.from("org"), authority.publicKey.toBuffer()],
+    program.programId,
+  );
+  await program.methods
+    .initOrg(Array.from(mint.authorityPubkey))
+    .accountsPartial({
+      authority: authority.publicKey,
+      org,
+      cvctMint: mint.cvctMint,
+      mintRegistry: mintRegistryPda(program.programId),
+    })
+    .signers([authority])
+    .rpc({ commitment: "confirmed" });
+
+  const [treasury] = PublicKey.findProgramAddressSync(
+    [Buffer.from("cvct_account"), mint.cvctMint.toBuffer(), org.toBuffer()],
+    program.programId,
+  );
+  const treasuryKey = x25519.utils.randomSecretKey();
+  const treasuryOffset = await nextComputationOffset(program, treasury);
+  await rpcWithLogs(
+    program.methods
+      .initOrgTreasury(
+        treasuryOffset,
+        Array.from(x25519.getPublicKey(treasuryKey)),
+        randomNonce().bn,
+        randomNonce().bn,
+      )
+      .accountsPartial({
+        authority: authority.publicKey,
+        org,
+        cvctMint: mint.cvctMint,
+        treasury,
+        ...arciumAccounts(program.programId, treasuryOffset, COMP_DEF_ACCOUNT),
+      })
+      .signers([authority])
+      .rpc({ skipPreflight: true, commitment: "confirmed" }),
+    "initOrgTreasury",
+    provider.connection,
+  );
+  await awaitComputationFinalization(
+    provider,
+    treasuryOffset,
+    program.programId,
+    "confirmed",
+  );
+
+  await initCompDef(
+    program,
+    payer,
+    COMP_DEF_RUN_PAYROLL,
+    "initRunPayrollCompDef",
+  );
+  const [payroll] = PublicKey.findProgramAddressSync(
+    [Buffer.from("payroll"), org.toBuffer(), authority.publicKey.toBuffer()],
+    program.programId,
+  );
+  await program.methods
+    .createPayroll(
+      new anchor.BN(opts.interval ?? 60),
+      opts.rateUnit ?? { perInterval: {} },
+      opts.maxPeriodsPerRun ?? null,
+      opts.maxMembers ?? null,
+    )
+    .accountsPartial({
+      authority: authority.publicKey,
+      admin: authority.publicKey,
+      org,
+      payroll,
+    })
+    .signers([authority])
+    .rpc({ commitment: "confirmed" });
+
+  return { mint, org, treasury, treasuryKey, payroll, admin: authority };
+}
+
+// Helper: a second payroll in the fixture's org, under a fresh admin.
+async function addPayroll(
+  program: Program<Cvct>,
+  payer: anchor.Wallet,
+  fixture: PayrollFixture,
+  opts: { interval?: number; maxPeriodsPerRun?: number } = {},
+): Promise<PayrollFixture> {
+  const provider = program.provider as anchor.AnchorProvider;
+  const admin = anchor.web3.Keypair.generate();
+  await transferLamports(
+    provider.connection,
+    payer.payer,
+    admin.publicKey,
+    anchor.web3.LAMPORTS_PER_SOL,
+  );
+  const [payroll] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("payroll"),
+      fixture.org.toBuffer(),
+      admin.publicKey.toBuffer(),
+    ],
+    program.programId,
+  );
+  await program.methods
+    .createPayroll(
+      new anchor.BN(opts.interval ?? 60),
+      { perInterval: {} },
+      opts.maxPeriodsPerRun ?? null,
+      null,
+    )
+    .accountsPartial({
+      authority: fixture.mint.authority.publicKey,
+      admin: admin.publicKey,
+      org: fixture.org,
+      payroll,
+    })
+    .signers([fixture.mint.authority, admin])
+    .rpc({ commitment: "confirmed" });
+  return { ...fixture, payroll, admin };
+}
+
+interface MemberFixture {
+  recipient: anchor.web3.Keypair;
+  wallet: PublicKey;
+  // x25519 key the member's wallet balance is encrypted to.
+  walletKey: Uint8Array;
+  member: PublicKey;
+}
+
+// Helper: a funded recipient with a wallet, added to the fixture's payroll at `rate`.
+async function addPayrollMember(
+  program: Program<Cvct>,
+  payer: anchor.Wallet,
+  fixture: PayrollFixture,
+  rate: number,
+): Promise<MemberFixture> {
+  const provider = program.provider as anchor.AnchorProvider;
+  const recipient = anchor.web3.Keypair.generate();
+  await transferLamports(
+    provider.connection,
+    payer.payer,
+    recipient.publicKey,
+    anchor.web3.LAMPORTS_PER_SOL,
+  );
+  const { pda: wallet, encKey: walletKey } = await initCvctAccount(
+    program,
+    payer,
+    fixture.mint,
+    recipient,
+  );
+  const [member] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("payroll_member"),
+      fixture.payroll.toBuffer(),
+      recipient.publicKey.toBuffer(),
+    ],
+    program.programId,
+  );
+  await program.methods
+    .addPayrollMember(new anchor.BN(rate))
+    .accountsPartial({
+      admin: fixture.admin.publicKey,
+      org: fixture.org,
+      payroll: fixture.payroll,
+      recipient: recipient.publicKey,
+      recipientCvctAccount: wallet,
+      payrollMember: member,
+    })
+    .signers([fixture.admin])
+    .rpc({ commitment: "confirmed" });
+  return { recipient, wallet, walletKey, member };
+}
+
+// Helper: mint `amount` backing tokens to the mint authority and deposit them into the
+// fixture's treasury.
+async function fundTreasury(
+  program: Program<Cvct>,
+  payer: anchor.Wallet,
+  fixture: PayrollFixture,
+  amount: number,
+): Promise<void> {
+  await depositTo(program, payer, fixture.mint, fixture.treasury, amount);
+}
+
+// Helper: queue a deposit of `amount` backing units from the mint authority into
+// `beneficiary` without waiting for its callback; returns the offset.
+async function queueDepositTo(
+  program: Program<Cvct>,
+  payer: anchor.Wallet,
+  mint: MintFixture,
+  beneficiary: PublicKey,
+  amount: number,
+): Promise<anchor.BN> {
+  const provider = program.provider as anchor.AnchorProvider;
+  await initCompDef(
+    program,
+    payer,
+    COMP_DEF_DEPOSIT,
+    "initDepositAndMintCompDef",
+  );
+  const depositorTokenAccount = await getOrCreateAssociatedTokenAccount(
+    provider.connection,
+    payer.payer,
+    mint.backingMint,
+    mint.authority.publicKey,
+  );
+  await mintTo(
+    provider.connection,
+    payer.payer,
+    mint.backingMint,
+    depositorTokenAccount.address,
+    payer.payer,
+    amount,
+  );
+
+  const account = await program.account.cvctAccount.fetch(beneficiary);
+  const cvctMint = await program.account.cvctMint.fetch(mint.cvctMint);
+  const vault = await program.account.vault.fetch(mint.vault);
+  const computationOffset = computationOffsetFor(
+    beneficiary,
+    account.opCounter,
+  );
+  await rpcWithLogs(
+    program.methods
+      .depositFor(
+        computationOffset,
+        new anchor.BN(amount),
+        nextNonce(account.balanceNonce).bn,
+        nextNonce(cvctMint.totalSupplyNonce).bn,
+        nextNonce(vault.totalLockedNonce).bn,
+      )
+      .accountsPartial({
+        depositor: mint.authority.publicKey,
+        cvctMint: mint.cvctMint,
+        vault: mint.vault,
+        beneficiaryCvctAccount: beneficiary,
+        depositorTokenAccount: depositorTokenAccount.address,
+        backingMint: mint.backingMint,
+        vaultTokenAccount: mint.vaultTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        pendingOp: pendingOpPda(program.programId, computationOffset),
+        ...arciumAccounts(
+          program.programId,
+          computationOffset,
+          COMP_DEF_DEPOSIT,
+        ),
+      })
+      .signers([mint.authority])
+      .rpc({ skipPreflight: true, commitment: "confirmed" }),
+    "depositFor",
+    provider.connection,
+  );
+  return computationOffset;
+}
+
+// Helper: deposit `amount` backing units from the mint authority into `beneficiary`.
+async function depositTo(
+  program: Program<Cvct>,
+  payer: anchor.Wallet,
+  mint: MintFixture,
+  beneficiary: PublicKey,
+  amount: number,
+): Promise<void> {
+  const provider = program.provider as anchor.AnchorProvider;
+  const computationOffset = await queueDepositTo(
+    program,
+    payer,
+    mint,
+    beneficiary,
+    amount,
+  );
+  await awaitComputationFinalization(
+    provider,
+    computationOffset,
+    program.programId,
+    "confirmed",
+  );
+}
+
+// Helper: queue run_payroll_for_member for `member`, signed by `runner`; returns the offset.
+async function runPayroll(
+  program: Program<Cvct>,
+  fixture: PayrollFixture,
+  member: MemberFixture,
+  runner: anchor.web3.Keypair,
+): Promise<anchor.BN> {
+  const treasury = await program.account.cvctAccount.fetch(fixture.treasury);
+  const wallet = await program.account.cvctAccount.fetch(member.wallet);
+  const org = await program.account.organization.fetch(fixture.org);
+  const payrollMember = await program.account.payrollMember.fetch(
+    member.member,
+  );
+  const computationOffset = computationOffsetFor(
+    fixture.treasury,
+    treasury.opCounter,
+  );
+  await program.methods
+    .runPayrollForMember(
+      computationOffset,
+      nextNonce(treasury.balanceNonce).bn,
+      nextNonce(wallet.balanceNonce).bn,
+      nextNonce(org.totalDisbursedNonce).bn,
+      nextNonce(payrollMember.deductionsNonce).bn,
+    )
+    .accountsPartial({
+      payer: runner.publicKey,
+      org: fixture.org,
+      payroll: fixture.payroll,
+      payrollMember: member.member,
+      cvctMint: fixture.mint.cvctMint,
+      treasury: fixture.treasury,
+      memberWallet: member.wallet,
+      ...arciumAccounts(
+        program.programId,
+        computationOffset,
+        COMP_DEF_RUN_PAYROLL,
+      ),
+    })
+    .signers([runner])
+    .rpc({ commitment: "confirmed" });
+  return computationOffset;
+}
+
 // Simple retry for blockhash fetch (localnet may lag during boot).
 async function getLatestBlockhashWithRetry(
   connection: anchor.web3.Connection,